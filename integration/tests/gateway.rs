@@ -1,5 +1,5 @@
 use k8s_gateway_api::{
-    AllowedRoutes, Gateway, GatewaySpec, GatewayTlsConfig, Listener, RouteGroupKind,
+    AllowedRoutes, Gateway, GatewaySpec, GatewayTlsConfig, Listener, ProtocolType, RouteGroupKind,
     SecretObjectReference,
 };
 use kube::{api::PostParams, core::ObjectMeta};
@@ -20,7 +20,7 @@ async fn round_trip() {
         spec: GatewaySpec {
             gateway_class_name: "acme-lb".to_string(),
             listeners: vec![Listener {
-                protocol: "HTTPS".to_string(),
+                protocol: ProtocolType::Https,
                 port: 443,
                 allowed_routes: Some(AllowedRoutes {
                     namespaces: None,
@@ -40,6 +40,9 @@ async fn round_trip() {
                 hostname: None,
             }],
             addresses: None,
+            infrastructure: None,
+            backend_tls: None,
+            allowed_listeners: None,
         },
         status: Default::default(),
     };
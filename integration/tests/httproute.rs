@@ -1,8 +1,5 @@
-use k8s_gateway_api::{
-    BackendObjectReference, BackendRef, HttpBackendRef, HttpHeaderMatch, HttpRoute, HttpRouteMatch,
-    HttpRouteRule, HttpRouteSpec,
-};
-use kube::{api::PostParams, core::ObjectMeta};
+use k8s_gateway_api::HttpRoute;
+use kube::api::PostParams;
 
 #[tokio::test(flavor = "current_thread")]
 async fn round_trip() {
@@ -12,58 +9,18 @@ async fn round_trip() {
         .expect("failed to initialize k8s client");
     let api = kube::Api::<HttpRoute>::default_namespaced(client);
 
-    let route = HttpRoute {
-        metadata: ObjectMeta {
-            name: Some("bar-route".to_string()),
-            namespace: None,
-            labels: Some([("gateway".to_string(), "external-https-prod".to_string())].into()),
-            ..Default::default()
-        },
-        spec: HttpRouteSpec {
-            hostnames: Some(vec!["bar.example.com".to_string()]),
-            rules: Some(vec![
-                HttpRouteRule {
-                    backend_refs: Some(vec![
-                        HttpBackendRef {
-                            backend_ref: Some(BackendRef {
-                                inner: mk_inner_ref("bar-v1", None, Some(8080)),
-                                weight: Some(90),
-                            }),
-                            filters: None,
-                        },
-                        HttpBackendRef {
-                            backend_ref: Some(BackendRef {
-                                inner: mk_inner_ref("bar-v2", None, Some(8080)),
-                                weight: Some(10),
-                            }),
-                            filters: None,
-                        },
-                    ]),
-                    filters: None,
-                    matches: None,
-                },
-                HttpRouteRule {
-                    matches: Some(vec![HttpRouteMatch {
-                        headers: Some(vec![HttpHeaderMatch::Exact {
-                            name: "env".to_string(),
-                            value: "canary".to_string(),
-                        }]),
-                        ..HttpRouteMatch::default()
-                    }]),
-                    backend_refs: Some(vec![HttpBackendRef {
-                        backend_ref: Some(BackendRef {
-                            inner: mk_inner_ref("bar-v2", None, Some(8080)),
-                            weight: None,
-                        }),
-                        filters: None,
-                    }]),
-                    filters: None,
-                },
-            ]),
-            ..HttpRouteSpec::default()
-        },
-        status: None,
-    };
+    let route = HttpRoute::builder("bar-route")
+        .label("gateway", "external-https-prod")
+        .hostname("bar.example.com")
+        .rule(|r| {
+            r.backend("bar-v1", 8080)
+                .weight(90)
+                .backend("bar-v2", 8080)
+                .weight(10)
+        })
+        .rule(|r| r.header("env", "canary").backend("bar-v2", 8080))
+        .build()
+        .expect("route must be valid");
     let post_params = PostParams {
         field_manager: Some("gateway-api-test".to_string()),
         ..Default::default()
@@ -78,17 +35,3 @@ async fn round_trip() {
         .await
         .expect("failed to delete resource");
 }
-
-fn mk_inner_ref(
-    name: &str,
-    namespace: Option<String>,
-    port: Option<u16>,
-) -> BackendObjectReference {
-    BackendObjectReference {
-        group: None, // core group inferred
-        kind: None,  // defaults to Service when not specified
-        name: name.to_string(),
-        namespace, // defaults to local namespace when unspecified
-        port,
-    }
-}
@@ -0,0 +1,636 @@
+//! Decides which listeners of a Gateway a route attaches to.
+//!
+//! A route attaches to a listener when one of its `parentRefs` selects the
+//! listener (by name, `sectionName` and `port`), the listener's
+//! `allowedRoutes` admit the route's kind and namespace, and the listener's
+//! hostname intersects with the route's hostnames.
+//!
+//! With the `experimental` feature, routes may also attach to the listeners
+//! of a ListenerSet, which in turn attaches to a Gateway whose
+//! `allowedListeners` admit it.
+
+use crate::*;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use std::collections::BTreeMap;
+
+/// A route kind that attaches to Gateways through `parentRefs`.
+pub trait Route: kube::Resource<DynamicType = ()> {
+    /// The parents the route wants to attach to.
+    fn parent_refs(&self) -> &[ParentReference];
+
+    /// The hostnames the route matches. Routes that do not match on hostnames
+    /// (e.g. TCPRoute) return an empty list, which matches every listener.
+    fn hostnames(&self) -> &[Hostname];
+
+    /// The route's status, if it has been written.
+    fn route_status(&self) -> Option<&RouteStatus>;
+}
+
+/// A resource that routes attach to through its listeners.
+pub trait RouteParent: kube::Resource<DynamicType = ()> {
+    fn listeners(&self) -> &[Listener];
+}
+
+/// A Gateway that ListenerSets attach to.
+#[cfg(feature = "experimental")]
+pub trait ListenerSetParent: RouteParent {
+    /// The Gateway's policy for attaching ListenerSets.
+    fn allowed_listeners(&self) -> Option<&AllowedListeners>;
+}
+
+/// The outcome of attaching a route to a parent through one of its
+/// `parentRefs`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParentAttachment {
+    /// The route's reference to the parent.
+    pub parent_ref: ParentReference,
+
+    /// The names of the listeners the route attached to, or the reason the
+    /// route was not accepted by the parent.
+    pub result: Result<Vec<SectionName>, AttachmentRejection>,
+}
+
+/// The reason a route was not accepted by a parent, as reported in the
+/// `Accepted` condition of the route's parent status.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttachmentRejection {
+    /// No listener matches the parentRef's `sectionName` and `port`.
+    NoMatchingParent,
+
+    /// The matching listeners do not allow routes of this kind or from this
+    /// namespace.
+    NotAllowedByListeners,
+
+    /// None of the matching listeners' hostnames intersect with the route's
+    /// hostnames.
+    NoMatchingListenerHostname,
+}
+
+/// Attaches `route` to `parent`.
+///
+/// Returns an attachment for each of the route's `parentRefs` that refers to
+/// `parent`; references to other parents are ignored. `namespace_labels` are
+/// the labels of the route's namespace, which are matched against listeners
+/// that select routes with `from: Selector`.
+pub fn attach<P, R>(
+    parent: &P,
+    route: &R,
+    namespace_labels: &BTreeMap<String, String>,
+) -> Vec<ParentAttachment>
+where
+    P: RouteParent,
+    R: Route,
+{
+    let route_ns = route.meta().namespace.as_deref().unwrap_or_default();
+    let parent_ns = parent.meta().namespace.as_deref().unwrap_or_default();
+    let parent_name = parent.meta().name.as_deref().unwrap_or_default();
+    let route_kind = (R::group(&()), R::kind(&()));
+
+    route
+        .parent_refs()
+        .iter()
+        .filter(|parent_ref| refers_to::<P>(parent_ref, route_ns, parent_ns, parent_name))
+        .map(|parent_ref| {
+            let selected = parent
+                .listeners()
+                .iter()
+                .filter(|l| {
+                    parent_ref
+                        .section_name
+                        .as_ref()
+                        .map_or(true, |s| *s == l.name)
+                })
+                .filter(|l| parent_ref.port.map_or(true, |p| p == l.port))
+                .collect::<Vec<_>>();
+
+            let allowed = selected
+                .iter()
+                .filter(|l| allows_kind(l, (&route_kind.0, &route_kind.1)))
+                .filter(|l| allows_namespace(l, parent_ns, route_ns, namespace_labels))
+                .collect::<Vec<_>>();
+
+            let attached = allowed
+                .iter()
+                .filter(|l| intersects(l.hostname.as_ref(), route.hostnames()))
+                .map(|l| l.name.clone())
+                .collect::<Vec<_>>();
+
+            let result = if selected.is_empty() {
+                Err(AttachmentRejection::NoMatchingParent)
+            } else if allowed.is_empty() {
+                Err(AttachmentRejection::NotAllowedByListeners)
+            } else if attached.is_empty() {
+                Err(AttachmentRejection::NoMatchingListenerHostname)
+            } else {
+                Ok(attached)
+            };
+            ParentAttachment {
+                parent_ref: parent_ref.clone(),
+                result,
+            }
+        })
+        .collect()
+}
+
+/// Returns true if `listener_set` refers to `parent` and is admitted by the
+/// parent's `allowedListeners`.
+///
+/// `namespace_labels` are the labels of the ListenerSet's namespace, which
+/// are matched against Gateways that select ListenerSets with
+/// `from: Selector`. Gateways that do not specify `allowedListeners` admit no
+/// ListenerSets.
+#[cfg(feature = "experimental")]
+pub fn allows_listener_set<P: ListenerSetParent>(
+    parent: &P,
+    listener_set: &ListenerSet,
+    namespace_labels: &BTreeMap<String, String>,
+) -> bool {
+    let set_ns = listener_set
+        .metadata
+        .namespace
+        .as_deref()
+        .unwrap_or_default();
+    let parent_ns = parent.meta().namespace.as_deref().unwrap_or_default();
+    let parent_name = parent.meta().name.as_deref().unwrap_or_default();
+    if !refers_to::<P>(
+        &listener_set.spec.parent_ref,
+        set_ns,
+        parent_ns,
+        parent_name,
+    ) {
+        return false;
+    }
+
+    let namespaces = parent
+        .allowed_listeners()
+        .and_then(|a| a.namespaces.as_ref());
+    let from = namespaces.and_then(|n| n.from.as_ref());
+    let selector = namespaces.and_then(|n| n.selector.as_ref());
+    allows_from(
+        from.unwrap_or(&FromNamespaces::None),
+        selector,
+        parent_ns,
+        set_ns,
+        namespace_labels,
+    )
+}
+
+/// Returns true if `parent_ref`, written in a resource in `child_ns`, refers to
+/// the named `P`.
+fn refers_to<P: kube::Resource<DynamicType = ()>>(
+    parent_ref: &ParentReference,
+    child_ns: &str,
+    parent_ns: &str,
+    parent_name: &str,
+) -> bool {
+    parent_ref
+        .group
+        .as_deref()
+        .unwrap_or("gateway.networking.k8s.io")
+        == P::group(&())
+        && parent_ref.kind.as_deref().unwrap_or("Gateway") == P::kind(&())
+        && parent_ref.namespace.as_deref().unwrap_or(child_ns) == parent_ns
+        && parent_ref.name == parent_name
+}
+
+/// Returns true if the listener allows routes of the given group and kind.
+///
+/// When a listener does not list the kinds it allows, the kinds are selected
+/// based on its protocol.
+fn allows_kind(listener: &Listener, (group, kind): (&str, &str)) -> bool {
+    let kinds = listener
+        .allowed_routes
+        .as_ref()
+        .and_then(|a| a.kinds.as_deref())
+        .unwrap_or_default();
+    if kinds.is_empty() {
+        let defaults: &[&str] = match listener.protocol {
+            ProtocolType::Http | ProtocolType::Https => &["HTTPRoute", "GRPCRoute"],
+            ProtocolType::Tls => &["TLSRoute"],
+            ProtocolType::Tcp => &["TCPRoute"],
+            ProtocolType::Udp => &["UDPRoute"],
+            ProtocolType::Other(_) => &[],
+        };
+        return group == "gateway.networking.k8s.io" && defaults.contains(&kind);
+    }
+
+    kinds.iter().any(|k| {
+        k.group.as_deref().unwrap_or("gateway.networking.k8s.io") == group && k.kind == kind
+    })
+}
+
+/// Returns true if the listener allows routes from the given namespace.
+fn allows_namespace(
+    listener: &Listener,
+    parent_ns: &str,
+    route_ns: &str,
+    labels: &BTreeMap<String, String>,
+) -> bool {
+    let namespaces = listener
+        .allowed_routes
+        .as_ref()
+        .and_then(|a| a.namespaces.as_ref());
+    let from = namespaces.and_then(|n| n.from.as_ref());
+    let selector = namespaces.and_then(|n| n.selector.as_ref());
+    allows_from(
+        from.unwrap_or(&FromNamespaces::Same),
+        selector,
+        parent_ns,
+        route_ns,
+        labels,
+    )
+}
+
+/// Returns true if a parent in `parent_ns` admits a child in `child_ns`, whose
+/// namespace has the given labels.
+fn allows_from(
+    from: &FromNamespaces,
+    selector: Option<&metav1::LabelSelector>,
+    parent_ns: &str,
+    child_ns: &str,
+    labels: &BTreeMap<String, String>,
+) -> bool {
+    match from {
+        FromNamespaces::All => true,
+        FromNamespaces::Same => parent_ns == child_ns,
+        FromNamespaces::Selector => selector.map_or(false, |s| selector_matches(s, labels)),
+        FromNamespaces::None | FromNamespaces::Other(_) => false,
+    }
+}
+
+/// Returns true if `labels` satisfy the label selector. An empty selector
+/// matches every set of labels.
+pub(crate) fn selector_matches(
+    selector: &metav1::LabelSelector,
+    labels: &BTreeMap<String, String>,
+) -> bool {
+    let match_labels = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .all(|(k, v)| labels.get(k) == Some(v));
+
+    let match_expressions = selector.match_expressions.iter().flatten().all(|expr| {
+        let value = labels.get(&expr.key);
+        let values = expr.values.as_deref().unwrap_or_default();
+        match expr.operator.as_str() {
+            "In" => value.map_or(false, |v| values.contains(v)),
+            "NotIn" => value.map_or(true, |v| !values.contains(v)),
+            "Exists" => value.is_some(),
+            "DoesNotExist" => value.is_none(),
+            _ => false,
+        }
+    });
+
+    match_labels && match_expressions
+}
+
+/// Returns true if a listener's hostname intersects with at least one of the
+/// route's hostnames. A listener without a hostname, or a route without
+/// hostnames, matches every hostname.
+fn intersects(listener: Option<&Hostname>, route: &[Hostname]) -> bool {
+    let listener = match listener {
+        Some(h) if !h.is_empty() => h,
+        _ => return true,
+    };
+    route.is_empty() || route.iter().any(|h| listener.intersect(h).is_some())
+}
+
+// === impl Route ===
+
+impl Route for HttpRoute {
+    fn parent_refs(&self) -> &[ParentReference] {
+        self.spec.inner.parent_refs.as_deref().unwrap_or_default()
+    }
+
+    fn hostnames(&self) -> &[Hostname] {
+        self.spec.hostnames.as_deref().unwrap_or_default()
+    }
+
+    fn route_status(&self) -> Option<&RouteStatus> {
+        self.status.as_ref().map(|s| &s.inner)
+    }
+}
+
+impl Route for v1::HttpRoute {
+    fn parent_refs(&self) -> &[ParentReference] {
+        self.spec.inner.parent_refs.as_deref().unwrap_or_default()
+    }
+
+    fn hostnames(&self) -> &[Hostname] {
+        self.spec.hostnames.as_deref().unwrap_or_default()
+    }
+
+    fn route_status(&self) -> Option<&RouteStatus> {
+        self.status.as_ref().map(|s| &s.inner)
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl Route for GrpcRoute {
+    fn parent_refs(&self) -> &[ParentReference] {
+        self.spec.inner.parent_refs.as_deref().unwrap_or_default()
+    }
+
+    fn hostnames(&self) -> &[Hostname] {
+        self.spec.hostnames.as_deref().unwrap_or_default()
+    }
+
+    fn route_status(&self) -> Option<&RouteStatus> {
+        self.status.as_ref().map(|s| &s.inner)
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl Route for TlsRoute {
+    fn parent_refs(&self) -> &[ParentReference] {
+        self.spec.inner.parent_refs.as_deref().unwrap_or_default()
+    }
+
+    fn hostnames(&self) -> &[Hostname] {
+        self.spec.hostnames.as_deref().unwrap_or_default()
+    }
+
+    fn route_status(&self) -> Option<&RouteStatus> {
+        self.status.as_ref().map(|s| &s.inner)
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl Route for TcpRoute {
+    fn parent_refs(&self) -> &[ParentReference] {
+        self.spec.inner.parent_refs.as_deref().unwrap_or_default()
+    }
+
+    fn hostnames(&self) -> &[Hostname] {
+        &[]
+    }
+
+    fn route_status(&self) -> Option<&RouteStatus> {
+        self.status.as_ref().map(|s| &s.inner)
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl Route for UdpRoute {
+    fn parent_refs(&self) -> &[ParentReference] {
+        self.spec.inner.parent_refs.as_deref().unwrap_or_default()
+    }
+
+    fn hostnames(&self) -> &[Hostname] {
+        &[]
+    }
+
+    fn route_status(&self) -> Option<&RouteStatus> {
+        self.status.as_ref().map(|s| &s.inner)
+    }
+}
+
+// === impl RouteParent ===
+
+impl RouteParent for Gateway {
+    fn listeners(&self) -> &[Listener] {
+        &self.spec.listeners
+    }
+}
+
+impl RouteParent for v1::Gateway {
+    fn listeners(&self) -> &[Listener] {
+        &self.spec.listeners
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl RouteParent for ListenerSet {
+    fn listeners(&self) -> &[Listener] {
+        &self.spec.listeners
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl ListenerSetParent for Gateway {
+    fn allowed_listeners(&self) -> Option<&AllowedListeners> {
+        self.spec.allowed_listeners.as_ref()
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl ListenerSetParent for v1::Gateway {
+    fn allowed_listeners(&self) -> Option<&AllowedListeners> {
+        self.spec.allowed_listeners.as_ref()
+    }
+}
+
+// === impl AttachmentRejection ===
+
+impl AttachmentRejection {
+    /// The upstream condition reason for the rejection.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::NoMatchingParent => "NoMatchingParent",
+            Self::NotAllowedByListeners => "NotAllowedByListeners",
+            Self::NoMatchingListenerHostname => "NoMatchingListenerHostname",
+        }
+    }
+}
+
+impl From<AttachmentRejection> for RouteConditionReason {
+    fn from(rejection: AttachmentRejection) -> Self {
+        match rejection {
+            AttachmentRejection::NoMatchingParent => Self::NoMatchingParent,
+            AttachmentRejection::NotAllowedByListeners => Self::NotAllowedByListeners,
+            AttachmentRejection::NoMatchingListenerHostname => Self::NoMatchingListenerHostname,
+        }
+    }
+}
+
+impl std::fmt::Display for AttachmentRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.reason())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gateway() -> Gateway {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "Gateway",
+            "metadata": { "name": "gw", "namespace": "infra" },
+            "spec": {
+                "gatewayClassName": "example",
+                "listeners": [
+                    {
+                        "name": "http",
+                        "protocol": "HTTP",
+                        "port": 80,
+                        "hostname": "*.example.com",
+                        "allowedRoutes": { "namespaces": { "from": "All" } }
+                    },
+                    {
+                        "name": "internal",
+                        "protocol": "HTTP",
+                        "port": 8080,
+                        "allowedRoutes": {
+                            "namespaces": {
+                                "from": "Selector",
+                                "selector": {
+                                    "matchExpressions": [{
+                                        "key": "team",
+                                        "operator": "In",
+                                        "values": ["a", "b"]
+                                    }]
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "name": "tcp",
+                        "protocol": "TCP",
+                        "port": 9000
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn route(parent_refs: serde_json::Value, hostnames: &[&str]) -> HttpRoute {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "HTTPRoute",
+            "metadata": { "name": "route", "namespace": "apps" },
+            "spec": { "parentRefs": parent_refs, "hostnames": hostnames }
+        }))
+        .unwrap()
+    }
+
+    fn results(
+        route: &HttpRoute,
+        labels: &[(&str, &str)],
+    ) -> Vec<Result<Vec<String>, AttachmentRejection>> {
+        let labels = labels
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        attach(&gateway(), route, &labels)
+            .into_iter()
+            .map(|a| a.result)
+            .collect()
+    }
+
+    #[test]
+    fn attaches_to_allowed_listeners() {
+        let route = route(
+            serde_json::json!([
+                { "name": "gw", "namespace": "infra" },
+                { "name": "other", "namespace": "infra" }
+            ]),
+            &["foo.example.com"],
+        );
+        assert_eq!(results(&route, &[]), [Ok(vec!["http".to_string()])]);
+        assert_eq!(
+            results(&route, &[("team", "a")]),
+            [Ok(vec!["http".to_string(), "internal".to_string()])]
+        );
+    }
+
+    #[test]
+    fn reports_rejections() {
+        let by_section = |section: &str, hostnames: &[&str]| {
+            route(
+                serde_json::json!([{ "name": "gw", "namespace": "infra", "sectionName": section }]),
+                hostnames,
+            )
+        };
+        assert_eq!(
+            results(&by_section("https", &[]), &[]),
+            [Err(AttachmentRejection::NoMatchingParent)]
+        );
+        assert_eq!(
+            results(&by_section("internal", &[]), &[("team", "c")]),
+            [Err(AttachmentRejection::NotAllowedByListeners)]
+        );
+        assert_eq!(
+            results(&by_section("tcp", &[]), &[]),
+            [Err(AttachmentRejection::NotAllowedByListeners)]
+        );
+        assert_eq!(
+            results(&by_section("http", &["example.com"]), &[]),
+            [Err(AttachmentRejection::NoMatchingListenerHostname)]
+        );
+
+        let by_port = route(
+            serde_json::json!([{ "name": "gw", "namespace": "infra", "port": 443 }]),
+            &[],
+        );
+        assert_eq!(
+            results(&by_port, &[]),
+            [Err(AttachmentRejection::NoMatchingParent)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "experimental")]
+    fn attaches_through_listener_sets() {
+        let listener_set = |namespace: &str| -> ListenerSet {
+            serde_json::from_value(serde_json::json!({
+                "apiVersion": "gateway.networking.x-k8s.io/v1alpha1",
+                "kind": "XListenerSet",
+                "metadata": { "name": "team", "namespace": namespace },
+                "spec": {
+                    "parentRef": { "name": "gw", "namespace": "infra" },
+                    "listeners": [{
+                        "name": "team-http",
+                        "protocol": "HTTP",
+                        "port": 8081,
+                        "allowedRoutes": { "namespaces": { "from": "All" } }
+                    }]
+                }
+            }))
+            .unwrap()
+        };
+        let no_labels = BTreeMap::new();
+
+        let mut gateway = gateway();
+        assert!(!allows_listener_set(
+            &gateway,
+            &listener_set("infra"),
+            &no_labels
+        ));
+        gateway.spec.allowed_listeners = Some(AllowedListeners {
+            namespaces: Some(ListenerNamespaces {
+                from: Some(FromNamespaces::Same),
+                selector: None,
+            }),
+        });
+        assert!(allows_listener_set(
+            &gateway,
+            &listener_set("infra"),
+            &no_labels
+        ));
+        assert!(!allows_listener_set(
+            &gateway,
+            &listener_set("apps"),
+            &no_labels
+        ));
+
+        let route = route(
+            serde_json::json!([
+                {
+                    "group": "gateway.networking.x-k8s.io",
+                    "kind": "XListenerSet",
+                    "name": "team",
+                    "namespace": "infra"
+                },
+                { "name": "gw", "namespace": "infra", "port": 8081 }
+            ]),
+            &[],
+        );
+        let attachments = attach(&listener_set("infra"), &route, &no_labels);
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].result, Ok(vec!["team-http".to_string()]));
+    }
+}
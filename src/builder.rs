@@ -0,0 +1,964 @@
+//! Fluent builders for Gateway API resources.
+//!
+//! Builders fill in the nested optional lists of a resource so that only the
+//! interesting parts need to be spelled out:
+//!
+//! ```
+//! # use k8s_gateway_api::*;
+//! let route = HttpRoute::builder("bar")
+//!     .parent("gw")
+//!     .hostname("bar.example.com")
+//!     .rule(|r| {
+//!         r.path_prefix("/")
+//!             .backend("bar-v1", 8080)
+//!             .weight(90)
+//!             .backend("bar-v2", 8080)
+//!             .weight(10)
+//!     })
+//!     .build()
+//!     .expect("route must be valid");
+//! # assert_eq!(route.spec.rules.unwrap()[0].backend_refs.as_ref().unwrap().len(), 2);
+//! ```
+//!
+//! Resources are checked with [`Validate`] when they are built, so that
+//! invalid values (e.g. a malformed hostname) are reported with their field
+//! paths.
+
+use crate::*;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use kube::core::ObjectMeta;
+
+/// Builds a [`Gateway`].
+#[derive(Clone, Debug)]
+pub struct GatewayBuilder {
+    metadata: ObjectMeta,
+    spec: GatewaySpec,
+}
+
+/// Builds a [`Listener`].
+#[derive(Clone, Debug)]
+pub struct ListenerBuilder {
+    listener: Listener,
+}
+
+/// Builds an [`HttpRoute`].
+#[derive(Clone, Debug)]
+pub struct HttpRouteBuilder {
+    metadata: ObjectMeta,
+    parent_refs: Vec<ParentReference>,
+    hostnames: Vec<Hostname>,
+    rules: Vec<HttpRouteRule>,
+}
+
+/// Builds an [`HttpRouteRule`].
+///
+/// Match methods (e.g. [`path_prefix`](Self::path_prefix)) refine the rule's
+/// most recently added match, adding one if the rule has none; use
+/// [`route_match`](Self::route_match) to add further matches. Similarly,
+/// [`weight`](Self::weight) applies to the most recently added backend.
+#[derive(Clone, Debug, Default)]
+pub struct HttpRouteRuleBuilder {
+    rule: HttpRouteRule,
+}
+
+/// Builds a [`GrpcRoute`].
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug)]
+pub struct GrpcRouteBuilder {
+    metadata: ObjectMeta,
+    parent_refs: Vec<ParentReference>,
+    hostnames: Vec<Hostname>,
+    rules: Vec<GrpcRouteRule>,
+}
+
+/// Builds a [`GrpcRouteRule`].
+///
+/// As with [`HttpRouteRuleBuilder`], match methods refine the most recently
+/// added match and [`weight`](Self::weight) applies to the most recently added
+/// backend.
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug, Default)]
+pub struct GrpcRouteRuleBuilder {
+    rule: GrpcRouteRule,
+}
+
+/// Builds a [`TcpRoute`].
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug)]
+pub struct TcpRouteBuilder {
+    metadata: ObjectMeta,
+    parent_refs: Vec<ParentReference>,
+    rules: Vec<TcpRouteRule>,
+}
+
+/// Builds a [`TlsRoute`].
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug)]
+pub struct TlsRouteBuilder {
+    metadata: ObjectMeta,
+    parent_refs: Vec<ParentReference>,
+    hostnames: Vec<Hostname>,
+    rules: Vec<TlsRouteRule>,
+}
+
+/// Builds a [`UdpRoute`].
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug)]
+pub struct UdpRouteBuilder {
+    metadata: ObjectMeta,
+    parent_refs: Vec<ParentReference>,
+    rules: Vec<UdpRouteRule>,
+}
+
+/// Builds the backends of a TCPRoute, TLSRoute or UDPRoute rule.
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug, Default)]
+pub struct BackendRefsBuilder {
+    backend_refs: Vec<BackendRef>,
+}
+
+/// Implements the metadata and parent methods shared by route builders.
+macro_rules! route_methods {
+    () => {
+        /// Sets the route's namespace.
+        pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+            self.metadata.namespace = Some(namespace.into());
+            self
+        }
+
+        /// Adds a label to the route.
+        pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+            self.metadata
+                .labels
+                .get_or_insert_with(Default::default)
+                .insert(key.into(), value.into());
+            self
+        }
+
+        /// Attaches the route to the Gateway with the given name in the
+        /// route's namespace.
+        pub fn parent(self, name: impl Into<String>) -> Self {
+            self.parent_ref(ParentReference {
+                group: None,
+                kind: None,
+                namespace: None,
+                name: name.into(),
+                section_name: None,
+                port: None,
+            })
+        }
+
+        /// Attaches the route to a parent.
+        pub fn parent_ref(mut self, parent_ref: ParentReference) -> Self {
+            self.parent_refs.push(parent_ref);
+            self
+        }
+    };
+}
+
+/// Implements the hostname method shared by route builders.
+macro_rules! hostname_method {
+    () => {
+        /// Adds a hostname to the route. The hostname is validated when the
+        /// route is built.
+        pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+            self.hostnames.push(Hostname::new_unchecked(hostname));
+            self
+        }
+    };
+}
+
+fn metadata(name: String) -> ObjectMeta {
+    ObjectMeta {
+        name: Some(name),
+        ..ObjectMeta::default()
+    }
+}
+
+fn backend_ref(name: String, port: PortNumber) -> BackendObjectReference {
+    BackendObjectReference {
+        group: None,
+        kind: None,
+        name,
+        namespace: None,
+        port: Some(port),
+    }
+}
+
+/// Returns `None` for empty lists, which upstream omits.
+fn non_empty<T>(items: Vec<T>) -> Option<Vec<T>> {
+    if items.is_empty() {
+        None
+    } else {
+        Some(items)
+    }
+}
+
+/// Validates a built resource.
+fn validated<T: Validate>(resource: T) -> Result<T, ValidationError> {
+    resource.validate().map(|()| resource)
+}
+
+// === impl GatewayBuilder ===
+
+impl Gateway {
+    /// Builds a Gateway of the given GatewayClass.
+    pub fn builder(
+        name: impl Into<String>,
+        gateway_class_name: impl Into<String>,
+    ) -> GatewayBuilder {
+        GatewayBuilder {
+            metadata: metadata(name.into()),
+            spec: GatewaySpec {
+                gateway_class_name: gateway_class_name.into(),
+                listeners: vec![],
+                addresses: None,
+                infrastructure: None,
+                backend_tls: None,
+                allowed_listeners: None,
+            },
+        }
+    }
+}
+
+impl GatewayBuilder {
+    /// Sets the Gateway's namespace.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.metadata.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Adds a label to the Gateway.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .labels
+            .get_or_insert_with(Default::default)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds a label that should be applied to the resources created for the
+    /// Gateway.
+    pub fn infrastructure_label(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.infrastructure()
+            .labels
+            .get_or_insert_with(Default::default)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds an annotation that should be applied to the resources created for
+    /// the Gateway.
+    pub fn infrastructure_annotation(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.infrastructure()
+            .annotations
+            .get_or_insert_with(Default::default)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds a listener, configured by `f`.
+    pub fn listener(
+        mut self,
+        name: impl Into<String>,
+        protocol: impl Into<ProtocolType>,
+        port: PortNumber,
+        f: impl FnOnce(ListenerBuilder) -> ListenerBuilder,
+    ) -> Self {
+        let builder = f(Listener::builder(name, protocol, port));
+        self.spec.listeners.push(builder.listener);
+        self
+    }
+
+    /// Requests an address for the Gateway.
+    pub fn address(mut self, address: GatewayAddress) -> Self {
+        self.spec
+            .addresses
+            .get_or_insert_with(Vec::new)
+            .push(address);
+        self
+    }
+
+    pub fn build(self) -> Result<Gateway, ValidationError> {
+        validated(Gateway {
+            metadata: self.metadata,
+            spec: self.spec,
+            status: None,
+        })
+    }
+
+    fn infrastructure(&mut self) -> &mut GatewayInfrastructure {
+        self.spec
+            .infrastructure
+            .get_or_insert_with(Default::default)
+    }
+}
+
+// === impl ListenerBuilder ===
+
+impl Listener {
+    pub fn builder(
+        name: impl Into<String>,
+        protocol: impl Into<ProtocolType>,
+        port: PortNumber,
+    ) -> ListenerBuilder {
+        ListenerBuilder {
+            listener: Listener {
+                name: name.into(),
+                hostname: None,
+                port,
+                protocol: protocol.into(),
+                tls: None,
+                allowed_routes: None,
+            },
+        }
+    }
+}
+
+impl ListenerBuilder {
+    /// Sets the listener's hostname. The hostname is validated when the
+    /// listener is built.
+    pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.listener.hostname = Some(Hostname::new_unchecked(hostname));
+        self
+    }
+
+    /// Terminates TLS with the certificate in the given Secret, in the
+    /// Gateway's namespace.
+    pub fn tls_terminate(mut self, secret_name: impl Into<String>) -> Self {
+        let tls = self.listener.tls.get_or_insert_with(Default::default);
+        tls.mode = Some(TlsModeType::Terminate);
+        tls.certificate_refs
+            .get_or_insert_with(Vec::new)
+            .push(SecretObjectReference {
+                group: None,
+                kind: None,
+                name: secret_name.into(),
+                namespace: None,
+            });
+        self
+    }
+
+    /// Passes TLS sessions through to backends.
+    pub fn tls_passthrough(mut self) -> Self {
+        self.listener.tls = Some(GatewayTlsConfig {
+            mode: Some(TlsModeType::Passthrough),
+            ..GatewayTlsConfig::default()
+        });
+        self
+    }
+
+    /// Allows routes from the given namespaces to attach.
+    pub fn allowed_namespaces(mut self, from: FromNamespaces) -> Self {
+        self.route_namespaces().from = Some(from);
+        self
+    }
+
+    /// Allows routes from the namespaces selected by `selector` to attach.
+    pub fn namespace_selector(mut self, selector: metav1::LabelSelector) -> Self {
+        let namespaces = self.route_namespaces();
+        namespaces.from = Some(FromNamespaces::Selector);
+        namespaces.selector = Some(selector);
+        self
+    }
+
+    /// Allows routes of the given `gateway.networking.k8s.io` kind to attach.
+    pub fn allowed_kind(mut self, kind: impl Into<String>) -> Self {
+        self.allowed_routes()
+            .kinds
+            .get_or_insert_with(Vec::new)
+            .push(RouteGroupKind {
+                group: None,
+                kind: kind.into(),
+            });
+        self
+    }
+
+    pub fn build(self) -> Result<Listener, ValidationError> {
+        validated(self.listener)
+    }
+
+    fn allowed_routes(&mut self) -> &mut AllowedRoutes {
+        self.listener.allowed_routes.get_or_insert(AllowedRoutes {
+            namespaces: None,
+            kinds: None,
+        })
+    }
+
+    fn route_namespaces(&mut self) -> &mut RouteNamespaces {
+        self.allowed_routes()
+            .namespaces
+            .get_or_insert(RouteNamespaces {
+                from: None,
+                selector: None,
+            })
+    }
+}
+
+// === impl HttpRouteBuilder ===
+
+impl HttpRoute {
+    pub fn builder(name: impl Into<String>) -> HttpRouteBuilder {
+        HttpRouteBuilder {
+            metadata: metadata(name.into()),
+            parent_refs: vec![],
+            hostnames: vec![],
+            rules: vec![],
+        }
+    }
+}
+
+impl HttpRouteBuilder {
+    route_methods!();
+    hostname_method!();
+
+    /// Adds a rule, configured by `f`.
+    pub fn rule(mut self, f: impl FnOnce(HttpRouteRuleBuilder) -> HttpRouteRuleBuilder) -> Self {
+        self.rules.push(f(HttpRouteRule::builder()).build());
+        self
+    }
+
+    pub fn build(self) -> Result<HttpRoute, ValidationError> {
+        validated(HttpRoute {
+            metadata: self.metadata,
+            spec: HttpRouteSpec {
+                inner: CommonRouteSpec {
+                    parent_refs: non_empty(self.parent_refs),
+                },
+                hostnames: non_empty(self.hostnames),
+                rules: non_empty(self.rules),
+            },
+            status: None,
+        })
+    }
+}
+
+// === impl HttpRouteRuleBuilder ===
+
+impl HttpRouteRule {
+    pub fn builder() -> HttpRouteRuleBuilder {
+        HttpRouteRuleBuilder::default()
+    }
+}
+
+impl HttpRouteRuleBuilder {
+    /// Names the rule.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.rule.name = Some(name.into());
+        self
+    }
+
+    /// Matches requests whose path has the given prefix.
+    pub fn path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.last_match().path = Some(HttpPathMatch::PathPrefix {
+            value: prefix.into(),
+        });
+        self
+    }
+
+    /// Matches requests with the given path.
+    pub fn path_exact(mut self, path: impl Into<String>) -> Self {
+        self.last_match().path = Some(HttpPathMatch::Exact { value: path.into() });
+        self
+    }
+
+    /// Matches requests with the given header value.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.last_match()
+            .headers
+            .get_or_insert_with(Vec::new)
+            .push(HttpHeaderMatch::Exact {
+                name: name.into(),
+                value: value.into(),
+            });
+        self
+    }
+
+    /// Matches requests with the given query parameter value.
+    pub fn query_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.last_match()
+            .query_params
+            .get_or_insert_with(Vec::new)
+            .push(HttpQueryParamMatch::Exact {
+                name: name.into(),
+                value: value.into(),
+            });
+        self
+    }
+
+    /// Matches requests with the given method.
+    pub fn method(mut self, method: impl Into<HttpMethod>) -> Self {
+        self.last_match().method = Some(method.into());
+        self
+    }
+
+    /// Adds a match.
+    pub fn route_match(mut self, route_match: HttpRouteMatch) -> Self {
+        self.rule
+            .matches
+            .get_or_insert_with(Vec::new)
+            .push(route_match);
+        self
+    }
+
+    /// Adds a filter.
+    pub fn filter(mut self, filter: HttpRouteFilter) -> Self {
+        self.rule.filters.get_or_insert_with(Vec::new).push(filter);
+        self
+    }
+
+    /// Forwards requests to the given Service port.
+    pub fn backend(self, name: impl Into<String>, port: PortNumber) -> Self {
+        self.backend_ref(HttpBackendRef {
+            backend_ref: Some(BackendRef {
+                weight: None,
+                inner: backend_ref(name.into(), port),
+            }),
+            filters: None,
+        })
+    }
+
+    /// Forwards requests to a backend.
+    pub fn backend_ref(mut self, backend_ref: HttpBackendRef) -> Self {
+        self.rule
+            .backend_refs
+            .get_or_insert_with(Vec::new)
+            .push(backend_ref);
+        self
+    }
+
+    /// Sets the weight of the most recently added backend.
+    pub fn weight(mut self, weight: u32) -> Self {
+        let last = self.rule.backend_refs.as_mut().and_then(|b| b.last_mut());
+        if let Some(backend_ref) = last.and_then(|b| b.backend_ref.as_mut()) {
+            backend_ref.weight = Some(weight);
+        }
+        self
+    }
+
+    /// Bounds the time taken to respond to a request.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.rule
+            .timeouts
+            .get_or_insert_with(Default::default)
+            .request = Some(timeout);
+        self
+    }
+
+    /// Bounds the time taken by each request to a backend.
+    pub fn backend_request_timeout(mut self, timeout: Duration) -> Self {
+        self.rule
+            .timeouts
+            .get_or_insert_with(Default::default)
+            .backend_request = Some(timeout);
+        self
+    }
+
+    pub fn build(self) -> HttpRouteRule {
+        self.rule
+    }
+
+    fn last_match(&mut self) -> &mut HttpRouteMatch {
+        let matches = self.rule.matches.get_or_insert_with(Vec::new);
+        if matches.is_empty() {
+            matches.push(HttpRouteMatch::default());
+        }
+        matches.last_mut().expect("matches must not be empty")
+    }
+}
+
+// === impl GrpcRouteBuilder ===
+
+#[cfg(feature = "experimental")]
+impl GrpcRoute {
+    pub fn builder(name: impl Into<String>) -> GrpcRouteBuilder {
+        GrpcRouteBuilder {
+            metadata: metadata(name.into()),
+            parent_refs: vec![],
+            hostnames: vec![],
+            rules: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl GrpcRouteBuilder {
+    route_methods!();
+    hostname_method!();
+
+    /// Adds a rule, configured by `f`.
+    pub fn rule(mut self, f: impl FnOnce(GrpcRouteRuleBuilder) -> GrpcRouteRuleBuilder) -> Self {
+        self.rules.push(f(GrpcRouteRule::builder()).build());
+        self
+    }
+
+    pub fn build(self) -> Result<GrpcRoute, ValidationError> {
+        validated(GrpcRoute {
+            metadata: self.metadata,
+            spec: GrpcRouteSpec {
+                inner: CommonRouteSpec {
+                    parent_refs: non_empty(self.parent_refs),
+                },
+                hostnames: non_empty(self.hostnames),
+                rules: non_empty(self.rules),
+            },
+            status: None,
+        })
+    }
+}
+
+// === impl GrpcRouteRuleBuilder ===
+
+#[cfg(feature = "experimental")]
+impl GrpcRouteRule {
+    pub fn builder() -> GrpcRouteRuleBuilder {
+        GrpcRouteRuleBuilder::default()
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl GrpcRouteRuleBuilder {
+    /// Matches calls to the given service.
+    pub fn service(mut self, service: impl Into<String>) -> Self {
+        let (service_match, _) = self.last_method_match();
+        *service_match = Some(service.into());
+        self
+    }
+
+    /// Matches calls to the given method.
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        let (_, method_match) = self.last_method_match();
+        *method_match = Some(method.into());
+        self
+    }
+
+    /// Matches calls with the given metadata value.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.last_match()
+            .headers
+            .get_or_insert_with(Vec::new)
+            .push(GrpcHeaderMatch::Exact {
+                name: name.into(),
+                value: value.into(),
+            });
+        self
+    }
+
+    /// Adds a match.
+    pub fn route_match(mut self, route_match: GrpcRouteMatch) -> Self {
+        self.rule
+            .matches
+            .get_or_insert_with(Vec::new)
+            .push(route_match);
+        self
+    }
+
+    /// Adds a filter.
+    pub fn filter(mut self, filter: GrpcRouteFilter) -> Self {
+        self.rule.filters.get_or_insert_with(Vec::new).push(filter);
+        self
+    }
+
+    /// Forwards calls to the given Service port.
+    pub fn backend(mut self, name: impl Into<String>, port: PortNumber) -> Self {
+        self.rule
+            .backend_refs
+            .get_or_insert_with(Vec::new)
+            .push(GrpcRouteBackendRef {
+                inner: backend_ref(name.into(), port),
+                filters: None,
+                weight: None,
+            });
+        self
+    }
+
+    /// Sets the weight of the most recently added backend.
+    pub fn weight(mut self, weight: u32) -> Self {
+        if let Some(backend_ref) = self.rule.backend_refs.as_mut().and_then(|b| b.last_mut()) {
+            backend_ref.weight = Some(weight);
+        }
+        self
+    }
+
+    pub fn build(self) -> GrpcRouteRule {
+        self.rule
+    }
+
+    fn last_match(&mut self) -> &mut GrpcRouteMatch {
+        let matches = self.rule.matches.get_or_insert_with(Vec::new);
+        if matches.is_empty() {
+            matches.push(GrpcRouteMatch::default());
+        }
+        matches.last_mut().expect("matches must not be empty")
+    }
+
+    /// Returns the service and method of the last match's method match, which
+    /// is made an exact match if it is unset or of an unsupported type.
+    fn last_method_match(&mut self) -> (&mut Option<String>, &mut Option<String>) {
+        let method_match = &mut self.last_match().method;
+        if matches!(method_match, None | Some(GrpcMethodMatch::Unknown { .. })) {
+            *method_match = Some(GrpcMethodMatch::Exact {
+                method: None,
+                service: None,
+            });
+        }
+        match method_match {
+            Some(
+                GrpcMethodMatch::Exact { service, method }
+                | GrpcMethodMatch::RegularExpression { service, method },
+            ) => (service, method),
+            _ => unreachable!("method match must be set"),
+        }
+    }
+}
+
+// === impl TcpRouteBuilder ===
+
+#[cfg(feature = "experimental")]
+impl TcpRoute {
+    pub fn builder(name: impl Into<String>) -> TcpRouteBuilder {
+        TcpRouteBuilder {
+            metadata: metadata(name.into()),
+            parent_refs: vec![],
+            rules: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl TcpRouteBuilder {
+    route_methods!();
+
+    /// Adds a rule that forwards connections to the backends added by `f`.
+    pub fn rule(mut self, f: impl FnOnce(BackendRefsBuilder) -> BackendRefsBuilder) -> Self {
+        let backend_refs = f(BackendRefsBuilder::default()).backend_refs;
+        self.rules.push(TcpRouteRule { backend_refs });
+        self
+    }
+
+    pub fn build(self) -> Result<TcpRoute, ValidationError> {
+        validated(TcpRoute {
+            metadata: self.metadata,
+            spec: TcpRouteSpec {
+                inner: CommonRouteSpec {
+                    parent_refs: non_empty(self.parent_refs),
+                },
+                rules: self.rules,
+            },
+            status: None,
+        })
+    }
+}
+
+// === impl TlsRouteBuilder ===
+
+#[cfg(feature = "experimental")]
+impl TlsRoute {
+    pub fn builder(name: impl Into<String>) -> TlsRouteBuilder {
+        TlsRouteBuilder {
+            metadata: metadata(name.into()),
+            parent_refs: vec![],
+            hostnames: vec![],
+            rules: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl TlsRouteBuilder {
+    route_methods!();
+    hostname_method!();
+
+    /// Adds a rule that forwards connections to the backends added by `f`.
+    pub fn rule(mut self, f: impl FnOnce(BackendRefsBuilder) -> BackendRefsBuilder) -> Self {
+        let backend_refs = f(BackendRefsBuilder::default()).backend_refs;
+        self.rules.push(TlsRouteRule { backend_refs });
+        self
+    }
+
+    pub fn build(self) -> Result<TlsRoute, ValidationError> {
+        validated(TlsRoute {
+            metadata: self.metadata,
+            spec: TlsRouteSpec {
+                inner: CommonRouteSpec {
+                    parent_refs: non_empty(self.parent_refs),
+                },
+                hostnames: non_empty(self.hostnames),
+                rules: self.rules,
+            },
+            status: None,
+        })
+    }
+}
+
+// === impl UdpRouteBuilder ===
+
+#[cfg(feature = "experimental")]
+impl UdpRoute {
+    pub fn builder(name: impl Into<String>) -> UdpRouteBuilder {
+        UdpRouteBuilder {
+            metadata: metadata(name.into()),
+            parent_refs: vec![],
+            rules: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl UdpRouteBuilder {
+    route_methods!();
+
+    /// Adds a rule that forwards packets to the backends added by `f`.
+    pub fn rule(mut self, f: impl FnOnce(BackendRefsBuilder) -> BackendRefsBuilder) -> Self {
+        let backend_refs = f(BackendRefsBuilder::default()).backend_refs;
+        self.rules.push(UdpRouteRule { backend_refs });
+        self
+    }
+
+    pub fn build(self) -> Result<UdpRoute, ValidationError> {
+        validated(UdpRoute {
+            metadata: self.metadata,
+            spec: UdpRouteSpec {
+                inner: CommonRouteSpec {
+                    parent_refs: non_empty(self.parent_refs),
+                },
+                rules: self.rules,
+            },
+            status: None,
+        })
+    }
+}
+
+// === impl BackendRefsBuilder ===
+
+#[cfg(feature = "experimental")]
+impl BackendRefsBuilder {
+    /// Forwards traffic to the given Service port.
+    pub fn backend(self, name: impl Into<String>, port: PortNumber) -> Self {
+        self.backend_ref(BackendRef {
+            weight: None,
+            inner: backend_ref(name.into(), port),
+        })
+    }
+
+    /// Forwards traffic to a backend.
+    pub fn backend_ref(mut self, backend_ref: BackendRef) -> Self {
+        self.backend_refs.push(backend_ref);
+        self
+    }
+
+    /// Sets the weight of the most recently added backend.
+    pub fn weight(mut self, weight: u32) -> Self {
+        if let Some(backend_ref) = self.backend_refs.last_mut() {
+            backend_ref.weight = Some(weight);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn builds_http_route() {
+        let route = HttpRoute::builder("bar")
+            .namespace("apps")
+            .parent("gw")
+            .hostname("bar.example.com")
+            .rule(|r| {
+                r.path_prefix("/")
+                    .backend("bar-v1", 8080)
+                    .weight(90)
+                    .backend("bar-v2", 8080)
+                    .weight(10)
+            })
+            .rule(|r| r.header("env", "canary").backend("bar-v2", 8080))
+            .build()
+            .expect("route must be valid");
+
+        let expected: HttpRoute = serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "HTTPRoute",
+            "metadata": { "name": "bar", "namespace": "apps" },
+            "spec": {
+                "parentRefs": [{ "name": "gw" }],
+                "hostnames": ["bar.example.com"],
+                "rules": [
+                    {
+                        "matches": [{ "path": { "type": "PathPrefix", "value": "/" } }],
+                        "backendRefs": [
+                            { "name": "bar-v1", "port": 8080, "weight": 90 },
+                            { "name": "bar-v2", "port": 8080, "weight": 10 },
+                        ],
+                    },
+                    {
+                        "matches": [{
+                            "headers": [{ "type": "Exact", "name": "env", "value": "canary" }],
+                        }],
+                        "backendRefs": [{ "name": "bar-v2", "port": 8080 }],
+                    },
+                ],
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(route).unwrap(),
+            serde_json::to_value(expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_invalid_values() {
+        let error = Gateway::builder("gw", "example")
+            .listener("https", ProtocolType::Https, 443, |l| {
+                l.hostname("*.Example.com").tls_terminate("cert")
+            })
+            .build()
+            .expect_err("gateway must be invalid");
+        let errors = error
+            .errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                r"spec.listeners[0].hostname: must match ^(\*\.)?[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$"
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "experimental")]
+    fn builds_experimental_routes() {
+        let route = GrpcRoute::builder("grpc")
+            .parent("gw")
+            .rule(|r| r.service("foo.Bar").method("Get").backend("bar", 9090))
+            .build()
+            .expect("route must be valid");
+        let rule = &route.spec.rules.unwrap()[0];
+        assert_eq!(
+            rule.matches.as_ref().unwrap()[0].method,
+            Some(GrpcMethodMatch::Exact {
+                service: Some("foo.Bar".to_string()),
+                method: Some("Get".to_string()),
+            })
+        );
+
+        let route = TcpRoute::builder("tcp")
+            .parent("gw")
+            .rule(|r| r.backend("a", 9000).weight(1).backend("b", 9000))
+            .build()
+            .expect("route must be valid");
+        assert_eq!(route.spec.rules[0].backend_refs.len(), 2);
+    }
+}
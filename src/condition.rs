@@ -0,0 +1,273 @@
+use k8s_openapi::{apimachinery::pkg::apis::meta::v1 as metav1, chrono};
+
+/// A type of status condition, paired with the reasons that may be reported
+/// for it.
+pub trait ConditionType: Into<String> + Sized {
+    type Reason: Into<String>;
+
+    /// Builds a condition of this type that was observed at
+    /// `observed_generation` and transitioned now.
+    fn condition(
+        self,
+        status: impl Into<ConditionStatus>,
+        reason: Self::Reason,
+        message: impl Into<String>,
+        observed_generation: Option<i64>,
+    ) -> metav1::Condition {
+        metav1::Condition {
+            type_: self.into(),
+            status: status.into().into(),
+            reason: reason.into(),
+            message: message.into(),
+            observed_generation,
+            last_transition_time: metav1::Time(chrono::Utc::now()),
+        }
+    }
+}
+
+/// Adds or updates the condition of `condition`'s type, returning true if the
+/// conditions changed.
+///
+/// As with apimachinery's `meta.SetStatusCondition`, an existing condition's
+/// `lastTransitionTime` is only updated when its status changes, while its
+/// reason, message and observed generation are always updated.
+pub(crate) fn set(conditions: &mut Vec<metav1::Condition>, condition: metav1::Condition) -> bool {
+    let Some(existing) = conditions.iter_mut().find(|c| c.type_ == condition.type_) else {
+        conditions.push(condition);
+        return true;
+    };
+
+    let mut changed = false;
+    if existing.status != condition.status {
+        existing.status = condition.status;
+        existing.last_transition_time = condition.last_transition_time;
+        changed = true;
+    }
+    if existing.reason != condition.reason {
+        existing.reason = condition.reason;
+        changed = true;
+    }
+    if existing.message != condition.message {
+        existing.message = condition.message;
+        changed = true;
+    }
+    if existing.observed_generation != condition.observed_generation {
+        existing.observed_generation = condition.observed_generation;
+        changed = true;
+    }
+    changed
+}
+
+/// Removes the condition of the given type, returning true if it was present.
+pub(crate) fn remove(conditions: &mut Vec<metav1::Condition>, type_: &str) -> bool {
+    let len = conditions.len();
+    conditions.retain(|c| c.type_ != type_);
+    conditions.len() != len
+}
+
+pub(crate) fn find<'c>(
+    conditions: &'c [metav1::Condition],
+    type_: &str,
+) -> Option<&'c metav1::Condition> {
+    conditions.iter().find(|c| c.type_ == type_)
+}
+
+pub(crate) fn is_true(conditions: &[metav1::Condition], type_: &str) -> bool {
+    find(conditions, type_).map_or(false, |c| c.status == "True")
+}
+
+/// Implements condition management methods for a status type whose conditions
+/// are of the given type.
+macro_rules! impl_conditions {
+    ($status:ty, $ty:ty, |$s:ident| $conditions:expr, |$m:ident| $conditions_mut:expr) => {
+        impl $status {
+            /// Adds or updates the condition of `condition`'s type, returning
+            /// true if the conditions changed.
+            ///
+            /// As with apimachinery's `meta.SetStatusCondition`, an existing
+            /// condition's `lastTransitionTime` is only updated when its
+            /// status changes, while its reason, message and observed
+            /// generation are always updated.
+            pub fn set_condition(&mut self, condition: metav1::Condition) -> bool {
+                let $m = self;
+                set($conditions_mut, condition)
+            }
+
+            /// Removes the condition of the given type, returning true if it
+            /// was present.
+            pub fn remove_condition(&mut self, type_: impl Into<$ty>) -> bool {
+                let $m = self;
+                remove($conditions_mut, type_.into().as_str())
+            }
+
+            /// Returns the condition of the given type, if it is set.
+            pub fn find_condition(&self, type_: impl Into<$ty>) -> Option<&metav1::Condition> {
+                let $s = self;
+                find($conditions, type_.into().as_str())
+            }
+
+            /// Returns true if the condition of the given type is set with a
+            /// status of `True`.
+            pub fn is_true(&self, type_: impl Into<$ty>) -> bool {
+                let $s = self;
+                is_true($conditions, type_.into().as_str())
+            }
+        }
+    };
+}
+
+impl_conditions!(
+    crate::GatewayClassStatus,
+    crate::GatewayClassConditionType,
+    |s| s.conditions.as_deref().unwrap_or_default(),
+    |s| s.conditions.get_or_insert_with(Vec::new)
+);
+
+impl_conditions!(
+    crate::GatewayStatus,
+    crate::GatewayConditionType,
+    |s| s.conditions.as_deref().unwrap_or_default(),
+    |s| s.conditions.get_or_insert_with(Vec::new)
+);
+
+impl_conditions!(
+    crate::ListenerStatus,
+    crate::ListenerConditionType,
+    |s| &s.conditions,
+    |s| &mut s.conditions
+);
+
+impl_conditions!(
+    crate::RouteParentStatus,
+    crate::RouteConditionType,
+    |s| &s.conditions,
+    |s| &mut s.conditions
+);
+
+string_enum! {
+    /// ConditionStatus is the status of a condition.
+    pub enum ConditionStatus {
+        True = "True",
+        False = "False",
+        Unknown = "Unknown",
+    }
+}
+
+impl From<bool> for ConditionStatus {
+    fn from(status: bool) -> Self {
+        if status {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+impl ConditionType for crate::GatewayClassConditionType {
+    type Reason = crate::GatewayClassConditionReason;
+}
+
+impl ConditionType for crate::GatewayConditionType {
+    type Reason = crate::GatewayConditionReason;
+}
+
+impl ConditionType for crate::ListenerConditionType {
+    type Reason = crate::ListenerConditionReason;
+}
+
+impl ConditionType for crate::RouteConditionType {
+    type Reason = crate::RouteConditionReason;
+}
+
+#[cfg(feature = "experimental")]
+impl ConditionType for crate::ListenerSetConditionType {
+    type Reason = crate::ListenerSetConditionReason;
+}
+
+#[cfg(feature = "experimental")]
+impl ConditionType for crate::PolicyConditionType {
+    type Reason = crate::PolicyConditionReason;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use k8s_openapi::chrono;
+
+    #[test]
+    fn builds_conditions() {
+        let condition = RouteConditionType::Accepted.condition(
+            false,
+            RouteConditionReason::NoMatchingParent,
+            "no listener matches",
+            Some(3),
+        );
+        assert_eq!(condition.type_, "Accepted");
+        assert_eq!(condition.status, "False");
+        assert_eq!(condition.reason, "NoMatchingParent");
+        assert_eq!(condition.message, "no listener matches");
+        assert_eq!(condition.observed_generation, Some(3));
+    }
+
+    #[test]
+    fn sets_conditions() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+
+        let mut status = GatewayStatus {
+            addresses: None,
+            conditions: None,
+            listeners: None,
+        };
+        let mut accepted = GatewayConditionType::Accepted.condition(
+            true,
+            GatewayConditionReason::Accepted,
+            "",
+            Some(1),
+        );
+        let first_transition = accepted.last_transition_time.clone();
+        assert!(status.set_condition(accepted.clone()));
+        assert!(!status.set_condition(accepted.clone()));
+        assert!(status.is_true(GatewayConditionType::Accepted));
+        assert!(!status.is_true("Programmed"));
+
+        // Updating the generation preserves the transition time.
+        accepted.observed_generation = Some(2);
+        accepted.last_transition_time =
+            metav1::Time(first_transition.0 + chrono::Duration::seconds(10));
+        assert!(status.set_condition(accepted.clone()));
+        let condition = status.find_condition("Accepted").unwrap();
+        assert_eq!(condition.observed_generation, Some(2));
+        assert_eq!(condition.last_transition_time, first_transition);
+
+        // Changing the status updates the transition time.
+        accepted.status = "False".to_string();
+        accepted.reason = GatewayConditionReason::Invalid.into();
+        assert!(status.set_condition(accepted.clone()));
+        let condition = status
+            .find_condition(GatewayConditionType::Accepted)
+            .unwrap();
+        assert_eq!(
+            condition.last_transition_time,
+            accepted.last_transition_time
+        );
+        assert!(!status.is_true(GatewayConditionType::Accepted));
+
+        assert!(status.remove_condition(GatewayConditionType::Accepted));
+        assert!(!status.remove_condition(GatewayConditionType::Accepted));
+        assert_eq!(status.conditions, Some(vec![]));
+    }
+
+    #[test]
+    fn preserves_unknown_values() {
+        let reason: ListenerConditionReason = serde_json::from_str(r#""SomethingNew""#).unwrap();
+        assert_eq!(
+            reason,
+            ListenerConditionReason::Other("SomethingNew".to_string())
+        );
+        assert_eq!(serde_json::to_string(&reason).unwrap(), r#""SomethingNew""#);
+
+        let ty: GatewayConditionType = "Programmed".parse().unwrap();
+        assert_eq!(ty, GatewayConditionType::Programmed);
+        assert_eq!(String::from(ty), "Programmed");
+    }
+}
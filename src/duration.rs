@@ -0,0 +1,213 @@
+use crate::validate::formats;
+use std::{fmt, str::FromStr};
+
+/// Duration is a string value representing a duration in time. The format is
+/// as specified in GEP-2257, a strict subset of the syntax parsed by Golang
+/// time.ParseDuration: one to four components, each of which is a number of
+/// up to five digits followed by a unit of `h`, `m`, `s` or `ms` (e.g. `10s`
+/// or `1h30m`).
+///
+/// Durations are validated when they are parsed, but not when they are
+/// deserialized, so that resources written by other clients can always be
+/// read; use [`Validate`](crate::Validate) to check deserialized resources.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[serde(transparent)]
+pub struct Duration(String);
+
+/// Indicates that a string is not a valid [`Duration`], or that a
+/// [`std::time::Duration`] cannot be represented as one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidDuration(String);
+
+// === impl Duration ===
+
+impl Duration {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Returns the duration as a [`std::time::Duration`].
+    pub fn to_std(&self) -> Result<std::time::Duration, InvalidDuration> {
+        if !formats::is_duration(&self.0) {
+            return Err(InvalidDuration(self.0.clone()));
+        }
+
+        let mut total = std::time::Duration::ZERO;
+        let mut rest = self.0.as_str();
+        while !rest.is_empty() {
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            let value = rest[..digits].parse::<u64>().expect("digits must parse");
+            rest = &rest[digits..];
+            let (unit, len) = if rest.starts_with("ms") {
+                (std::time::Duration::from_millis(1), 2)
+            } else {
+                let secs = match rest.as_bytes()[0] {
+                    b'h' => 3600,
+                    b'm' => 60,
+                    _ => 1,
+                };
+                (std::time::Duration::from_secs(secs), 1)
+            };
+            rest = &rest[len..];
+            total += unit * value as u32;
+        }
+        Ok(total)
+    }
+
+    /// Returns true if the duration is zero, which disables a timeout.
+    pub fn is_zero(&self) -> bool {
+        self.to_std().map_or(false, |d| d.is_zero())
+    }
+}
+
+impl FromStr for Duration {
+    type Err = InvalidDuration;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.to_string().try_into()
+    }
+}
+
+impl TryFrom<String> for Duration {
+    type Error = InvalidDuration;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if !formats::is_duration(&s) {
+            return Err(InvalidDuration(s));
+        }
+        Ok(Self(s))
+    }
+}
+
+/// Formats a duration with the largest units possible (e.g. `1h30m`), as
+/// upstream recommends. Durations with sub-millisecond precision, or of
+/// 100000 hours or more, cannot be represented.
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = InvalidDuration;
+
+    fn try_from(duration: std::time::Duration) -> Result<Self, Self::Error> {
+        if duration.subsec_nanos() % 1_000_000 != 0 || duration.as_secs() / 3600 > 99_999 {
+            return Err(InvalidDuration(format!("{duration:?}")));
+        }
+        if duration.is_zero() {
+            return Ok(Self("0s".to_string()));
+        }
+
+        let secs = duration.as_secs();
+        let components = [
+            (secs / 3600, "h"),
+            (secs % 3600 / 60, "m"),
+            (secs % 60, "s"),
+            (u64::from(duration.subsec_millis()), "ms"),
+        ];
+        let mut s = String::new();
+        for (value, unit) in components {
+            if value > 0 {
+                s.push_str(&format!("{value}{unit}"));
+            }
+        }
+        Ok(Self(s))
+    }
+}
+
+impl From<Duration> for String {
+    fn from(Duration(s): Duration) -> Self {
+        s
+    }
+}
+
+impl AsRef<str> for Duration {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Duration {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Duration {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// === impl InvalidDuration ===
+
+impl fmt::Display for InvalidDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid duration: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDuration {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration as StdDuration;
+
+    #[test]
+    fn parses() {
+        let std = |s: &str| s.parse::<Duration>().unwrap().to_std().unwrap();
+        assert_eq!(std("10s"), StdDuration::from_secs(10));
+        assert_eq!(std("1h30m"), StdDuration::from_secs(5400));
+        assert_eq!(std("1m500ms"), StdDuration::from_millis(60_500));
+        assert_eq!(std("0s"), StdDuration::ZERO);
+
+        for invalid in [
+            "",
+            "10",
+            "1.5s",
+            "1d",
+            "100000s",
+            "1h1m1s1ms1h",
+            "-1s",
+            "1us",
+        ] {
+            assert!(invalid.parse::<Duration>().is_err(), "{invalid:?}");
+        }
+        assert!(serde_json::from_str::<Duration>(r#""soon""#)
+            .unwrap()
+            .to_std()
+            .is_err());
+    }
+
+    #[test]
+    fn formats() {
+        let format = |d: StdDuration| Duration::try_from(d).map(String::from);
+        assert_eq!(format(StdDuration::ZERO), Ok("0s".to_string()));
+        assert_eq!(
+            format(StdDuration::from_secs(5400)),
+            Ok("1h30m".to_string())
+        );
+        assert_eq!(
+            format(StdDuration::from_millis(3_600_250)),
+            Ok("1h250ms".to_string())
+        );
+        assert!(format(StdDuration::from_micros(1)).is_err());
+    }
+}
@@ -0,0 +1,353 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+/// BackendTLSPolicy provides a way to configure how a Gateway connects to a
+/// Backend via TLS.
+#[derive(
+    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1alpha3",
+    kind = "BackendTLSPolicy",
+    root = "BackendTlsPolicy",
+    status = "PolicyStatus",
+    namespaced
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BackendTlsPolicySpec {
+    /// TargetRefs identifies an API object to apply the policy to. Only
+    /// Services have Extended support. Implementations MAY support additional
+    /// objects, with Implementation Specific support. Note that this config
+    /// applies to the entire referenced resource by default, but this default
+    /// may change in the future to provide a more granular application of the
+    /// policy.
+    ///
+    /// TargetRefs must be _distinct_. This means either that:
+    ///
+    /// * They select different targets. If this is the case, then targetRef
+    ///   entries are distinct. In terms of fields, this means that the
+    ///   multi-part key defined by `group`, `kind`, and `name` must be unique
+    ///   across all targetRef entries in the BackendTLSPolicy.
+    /// * They select different sectionNames in the same target.
+    ///
+    /// Support: Extended for Kubernetes Service
+    ///
+    /// Support: Implementation-specific for any other resource
+    #[schemars(length(min = 1, max = 16))]
+    #[cel_validate(rule = Rule::new("self.all(p1, self.all(p2, p1.group == p2.group && p1.kind == p2.kind && p1.name == p2.name ? ((!has(p1.sectionName) || p1.sectionName == '') == (!has(p2.sectionName) || p2.sectionName == '')) : true))").message("sectionName must be specified when targetRefs includes 2 or more references to the same target"))]
+    #[cel_validate(rule = Rule::new("self.all(p1, self.exists_one(p2, p1.group == p2.group && p1.kind == p2.kind && p1.name == p2.name && (((!has(p1.sectionName) || p1.sectionName == '') && (!has(p2.sectionName) || p2.sectionName == '')) || (has(p1.sectionName) && has(p2.sectionName) && p1.sectionName == p2.sectionName))))").message("sectionName must be unique when targetRefs includes 2 or more references to the same target"))]
+    pub target_refs: Vec<PolicyTargetReferenceWithSectionName>,
+
+    /// Validation contains backend TLS validation configuration.
+    pub validation: BackendTlsPolicyValidation,
+
+    /// Options are a list of key/value pairs to enable extended TLS
+    /// configuration for each implementation. For example, configuring the
+    /// minimum TLS version or supported cipher suites.
+    ///
+    /// A set of common keys MAY be defined by the API in the future. To
+    /// avoid any ambiguity, implementation-specific definitions MUST use
+    /// domain-prefixed names, such as `example.com/my-custom-option`.
+    /// Un-prefixed names are reserved for key names defined by Gateway API.
+    ///
+    /// Support: Implementation-specific
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::schema::tls_options")]
+    pub options: Option<BTreeMap<AnnotationKey, AnnotationValue>>,
+}
+
+/// BackendTLSPolicyValidation contains backend TLS validation configuration.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
+#[cel_validate(rule = Rule::new("!(has(self.caCertificateRefs) && size(self.caCertificateRefs) > 0 && has(self.wellKnownCACertificates) && self.wellKnownCACertificates != '')").message("must not contain both CACertificateRefs and WellKnownCACertificates"))]
+#[cel_validate(rule = Rule::new("(has(self.caCertificateRefs) && size(self.caCertificateRefs) > 0 || has(self.wellKnownCACertificates) && self.wellKnownCACertificates != '')").message("must specify either CACertificateRefs or WellKnownCACertificates"))]
+#[serde(rename_all = "camelCase")]
+pub struct BackendTlsPolicyValidation {
+    /// CACertificateRefs contains one or more references to Kubernetes
+    /// objects that contain a PEM-encoded TLS CA certificate bundle, which is
+    /// used to validate a TLS handshake between the Gateway and backend Pod.
+    ///
+    /// If CACertificateRefs is empty or unspecified, then
+    /// WellKnownCACertificates must be specified. Only one of
+    /// CACertificateRefs or WellKnownCACertificates may be specified, not
+    /// both. If CACertificateRefs is empty or unspecified, the configuration
+    /// for WellKnownCACertificates MUST be honored instead if supported by
+    /// the implementation.
+    ///
+    /// References to a resource in a different namespace are invalid for the
+    /// moment, although we will revisit this in the future.
+    ///
+    /// A single CACertificateRef to a Kubernetes ConfigMap kind has "Core"
+    /// support. Implementations MAY choose to support attaching multiple
+    /// certificates to a backend, but this behavior is implementation-specific.
+    ///
+    /// Support: Core - An optional single reference to a Kubernetes ConfigMap,
+    /// with the CA certificate in a key named `ca.crt`.
+    ///
+    /// Support: Implementation-specific (More than one reference, or other
+    /// kinds of resources).
+    #[serde(
+        default,
+        rename = "caCertificateRefs",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(length(max = 8))]
+    pub ca_certificate_refs: Option<Vec<LocalObjectReference>>,
+
+    /// WellKnownCACertificates specifies whether system CA certificates may
+    /// be used in the TLS handshake between the gateway and backend pod.
+    ///
+    /// If WellKnownCACertificates is unspecified or empty (""), then
+    /// CACertificateRefs must be specified with at least one entry for a
+    /// valid configuration. Only one of CACertificateRefs or
+    /// WellKnownCACertificates may be specified, not both. If an
+    /// implementation does not support the WellKnownCACertificates field or
+    /// the value supplied is not supported, the Status Conditions on the
+    /// Policy MUST be updated to include an Accepted: False Condition with
+    /// Reason: Invalid.
+    ///
+    /// Support: Implementation-specific
+    #[serde(
+        default,
+        rename = "wellKnownCACertificates",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(schema_with = "crate::schema::well_known_ca_certificates_type")]
+    pub well_known_ca_certificates: Option<WellKnownCaCertificatesType>,
+
+    /// Hostname is used for two purposes in the connection between Gateways
+    /// and backends:
+    ///
+    /// 1. Hostname MUST be used as the SNI to connect to the backend (RFC
+    ///    6066).
+    /// 2. If SubjectAltNames is not specified, Hostname MUST be used for
+    ///    authentication and MUST match the certificate served by the
+    ///    matching backend.
+    ///
+    /// Support: Core
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::PRECISE_HOSTNAME")]
+    pub hostname: PreciseHostname,
+
+    /// SubjectAltNames contains one or more Subject Alternative Names. When
+    /// specified, the certificate served from the backend MUST have at least
+    /// one Subject Alternate Name matching one of the specified
+    /// SubjectAltNames.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 5))]
+    pub subject_alt_names: Option<Vec<SubjectAltName>>,
+}
+
+/// SubjectAltName represents Subject Alternative Name.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
+#[cel_validate(rule = Rule::new("!(self.type == \"Hostname\" && (!has(self.hostname) || self.hostname == \"\"))").message("SubjectAltName element must contain Hostname, if Type is set to Hostname"))]
+#[cel_validate(rule = Rule::new("!(self.type != \"Hostname\" && has(self.hostname) && self.hostname != \"\")").message("SubjectAltName element must not contain Hostname, if Type is not set to Hostname"))]
+#[cel_validate(rule = Rule::new("!(self.type == \"URI\" && (!has(self.uri) || self.uri == \"\"))").message("SubjectAltName element must contain URI, if Type is set to URI"))]
+#[cel_validate(rule = Rule::new("!(self.type != \"URI\" && has(self.uri) && self.uri != \"\")").message("SubjectAltName element must not contain URI, if Type is not set to URI"))]
+pub struct SubjectAltName {
+    /// Type determines the format of the Subject Alternative Name. Always
+    /// required.
+    ///
+    /// Support: Core
+    #[serde(rename = "type")]
+    #[schemars(schema_with = "crate::schema::subject_alt_name_type")]
+    pub type_: SubjectAltNameType,
+
+    /// Hostname contains Subject Alternative Name specified in DNS name
+    /// format. Required when Type is set to Hostname, ignored otherwise.
+    ///
+    /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::HOSTNAME")]
+    pub hostname: Option<Hostname>,
+
+    /// URI contains Subject Alternative Name specified in a full URI format.
+    /// It MUST include both a scheme (e.g., "http" or "ftp") and a scheme
+    /// specific part. Common values include SPIFFE IDs like
+    /// "spiffe://mycluster.example.com/ns/myns/sa/svc1sa". Required when Type
+    /// is set to URI, ignored otherwise.
+    ///
+    /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::ABSOLUTE_URI")]
+    pub uri: Option<String>,
+}
+
+string_enum! {
+    /// WellKnownCACertificatesType is the type of CA certificate that will be
+    /// used when the caCertificateRefs field is unspecified.
+    pub enum WellKnownCaCertificatesType {
+        /// Indicates that well known system CA certificates should be used.
+        System = "System",
+    }
+}
+
+string_enum! {
+    /// SubjectAltNameType is the type of the Subject Alternative Name.
+    pub enum SubjectAltNameType {
+        /// Specifies hostname-based SAN.
+        ///
+        /// Support: Core
+        Hostname = "Hostname",
+
+        /// Specifies URI-based SAN, e.g. SPIFFE id.
+        ///
+        /// Support: Core
+        Uri = "URI",
+    }
+}
+
+// === impl BackendTlsPolicy ===
+
+impl BackendTlsPolicy {
+    /// Returns the target reference through which this policy applies to
+    /// `backend`, or `None` if the policy does not apply to it.
+    ///
+    /// `namespace` is the namespace of the object that holds the backend
+    /// reference (e.g. a route), in which the backend is assumed to live when
+    /// the reference does not name a namespace.
+    ///
+    /// For a Service, a `sectionName` names a port. `port_name` looks up the
+    /// name of the backend's `port` (e.g. in the Service's `spec.ports`),
+    /// returning `None` if the port is unnamed or unknown; a target reference
+    /// with a `sectionName` then applies only when it matches that name.
+    /// Target references with a matching `sectionName` are preferred over
+    /// those without. When the backend reference has no `port`, only target
+    /// references without a `sectionName` apply.
+    ///
+    /// A policy only applies to backends in its own namespace.
+    pub fn target_for(
+        &self,
+        backend: &BackendObjectReference,
+        namespace: &str,
+        port_name: impl FnOnce(PortNumber) -> Option<SectionName>,
+    ) -> Option<&PolicyTargetReferenceWithSectionName> {
+        let target = backend_target(backend, namespace, port_name);
+        if self.metadata.namespace != target.namespace {
+            return None;
+        }
+        self.target_ref_for(&target)
+    }
+}
+
+impl Policy for BackendTlsPolicy {
+    type TargetRef = PolicyTargetReferenceWithSectionName;
+
+    fn target_refs(&self) -> &[PolicyTargetReferenceWithSectionName] {
+        &self.spec.target_refs
+    }
+}
+
+/// Finds the BackendTLSPolicy that applies to `backend` and its port, as
+/// described by [`BackendTlsPolicy::target_for`].
+///
+/// When several policies target the backend, the policy is chosen as
+/// described by [`direct_policy`].
+pub fn find_backend_tls_policy<'p>(
+    policies: impl IntoIterator<Item = &'p BackendTlsPolicy>,
+    backend: &BackendObjectReference,
+    namespace: &str,
+    port_name: impl FnOnce(PortNumber) -> Option<SectionName>,
+) -> Option<&'p BackendTlsPolicy> {
+    let target = backend_target(backend, namespace, port_name);
+    let policies = policies
+        .into_iter()
+        .filter(|p| p.metadata.namespace == target.namespace);
+    direct_policy(policies, &target)
+}
+
+fn backend_target(
+    backend: &BackendObjectReference,
+    namespace: &str,
+    port_name: impl FnOnce(PortNumber) -> Option<SectionName>,
+) -> PolicyTarget {
+    PolicyTarget {
+        group: backend.group.clone().unwrap_or_default(),
+        kind: backend
+            .kind
+            .clone()
+            .unwrap_or_else(|| "Service".to_string()),
+        namespace: Some(
+            backend
+                .namespace
+                .as_deref()
+                .unwrap_or(namespace)
+                .to_string(),
+        ),
+        name: backend.name.clone(),
+        section_name: backend.port.and_then(port_name),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn policy(name: &str, created: &str, section: Option<&str>) -> BackendTlsPolicy {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1alpha3",
+            "kind": "BackendTLSPolicy",
+            "metadata": {
+                "name": name,
+                "namespace": "default",
+                "creationTimestamp": created,
+            },
+            "spec": {
+                "targetRefs": [{
+                    "group": "",
+                    "kind": "Service",
+                    "name": "web",
+                    "sectionName": section,
+                }],
+                "validation": {
+                    "wellKnownCACertificates": "System",
+                    "hostname": "web.example.com",
+                },
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn finds_backend_tls_policy() {
+        let older = policy("older", "2024-01-01T00:00:00Z", None);
+        let newer = policy("newer", "2024-06-01T00:00:00Z", None);
+        let https = policy("https", "2024-09-01T00:00:00Z", Some("https"));
+        let policies = [&newer, &https, &older];
+
+        // The web Service names port 443 "https" and port 80 "http".
+        let port_name = |port: PortNumber| match port {
+            443 => Some("https".to_string()),
+            80 => Some("http".to_string()),
+            _ => None,
+        };
+        let found = |ns: &str, port: Option<PortNumber>| {
+            let backend = BackendObjectReference {
+                group: None,
+                kind: None,
+                name: "web".to_string(),
+                namespace: None,
+                port,
+            };
+            find_backend_tls_policy(policies, &backend, ns, port_name)
+                .and_then(|p| p.metadata.name.as_deref())
+        };
+        assert_eq!(found("default", None), Some("older"));
+        assert_eq!(found("default", Some(80)), Some("older"));
+        assert_eq!(found("default", Some(8080)), Some("older"));
+        assert_eq!(found("default", Some(443)), Some("https"));
+        assert_eq!(found("other", Some(443)), None);
+
+        let backend = BackendObjectReference {
+            group: None,
+            kind: None,
+            name: "web".to_string(),
+            namespace: None,
+            port: Some(80),
+        };
+        let only_https = [&https];
+        assert!(find_backend_tls_policy(only_https, &backend, "default", port_name).is_none());
+        assert!(https.target_for(&backend, "default", port_name).is_none());
+    }
+}
@@ -73,9 +73,14 @@ pub struct GrpcRouteSpec {
     ///
     /// Support: Core
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub hostnames: Option<Vec<String>>,
+    #[schemars(
+        length(max = 16),
+        inner(length(min = 1, max = 253), regex = "crate::schema::HOSTNAME")
+    )]
+    pub hostnames: Option<Vec<Hostname>>,
     /// Rules are a list of Grpc matchers, filters and actions.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub rules: Option<Vec<GrpcRouteRule>>,
 }
 
@@ -97,7 +102,7 @@ impl From<GrpcRouteStatus> for HttpRouteStatus {
 /// conditions (matches), processing it (filters), and forwarding the request to
 /// an API object (backendRefs).
 #[derive(
-    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
 )]
 pub struct GrpcRouteRule {
     /// Filters define the filters that are applied to requests that match
@@ -125,6 +130,9 @@ pub struct GrpcRouteRule {
     ///
     /// Support: Core
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'RequestHeaderModifier').size() <= 1").message("RequestHeaderModifier filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'ResponseHeaderModifier').size() <= 1").message("ResponseHeaderModifier filter cannot be repeated"))]
     pub filters: Option<Vec<GrpcRouteFilter>>,
     /// Matches define conditions used for matching the rule against incoming
     /// gRPC requests. Each match is independent, i.e. this rule will be matched
@@ -176,6 +184,7 @@ pub struct GrpcRouteRule {
     /// matching precedence MUST be granted to the first matching rule meeting
     /// the above criteria.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 8))]
     pub matches: Option<Vec<GrpcRouteMatch>>,
     /// BackendRefs defines the backend(s) where matching requests should be
     /// sent.
@@ -210,6 +219,7 @@ pub struct GrpcRouteRule {
         skip_serializing_if = "Option::is_none",
         rename = "backendRefs"
     )]
+    #[schemars(length(max = 16))]
     pub backend_refs: Option<Vec<GrpcRouteBackendRef>>,
 }
 
@@ -249,6 +259,7 @@ pub struct GrpcRouteMatch {
     /// ANDed together, meaning, a request MUST match all the specified headers
     /// to select the route.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub headers: Option<Vec<GrpcHeaderMatch>>,
 }
 
@@ -268,8 +279,8 @@ pub type GrpcHeaderMatch = crate::httproute::HttpHeaderMatch;
 
 /// Method specifies a gRPC request service/method matcher. If this field is
 /// not specified, all services and methods will match.
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, schemars::JsonSchema)]
-#[serde(tag = "type", rename_all = "PascalCase")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "PascalCase")]
 pub enum GrpcMethodMatch {
     #[serde(rename_all = "camelCase")]
     Exact {
@@ -302,13 +313,27 @@ pub enum GrpcMethodMatch {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         service: Option<String>,
     },
+
+    /// A method match with a type that is not known to this version of the
+    /// library.
+    ///
+    /// `raw` holds the method match as it was read, and is written back
+    /// verbatim.
+    #[serde(skip)]
+    Unknown {
+        type_: String,
+        raw: serde_json::Value,
+    },
 }
 
+tagged_enum_serde!(@serialize GrpcMethodMatch { "Exact", "RegularExpression" });
+
 impl GrpcMethodMatch {
     fn is_empty(&self) -> bool {
         let (method, service) = match self {
             Self::Exact { method, service } => (method, service),
             Self::RegularExpression { method, service } => (method, service),
+            Self::Unknown { .. } => return false,
         };
 
         method.as_deref().map(str::is_empty).unwrap_or(true)
@@ -395,8 +420,22 @@ impl<'de> serde::Deserialize<'de> for GrpcMethodMatch {
             }
         }
 
-        const FIELDS: &[&str] = &["type", "method", "service"];
-        deserializer.deserialize_struct("GrpcMethodMatch", FIELDS, GrpcMethodMatchVisitor)
+        use serde::{de::Error, Deserializer};
+
+        let raw = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+        match raw.get("type").and_then(serde_json::Value::as_str) {
+            Some(type_) if !type_.is_empty() && !Self::KNOWN_TYPES.contains(&type_) => {
+                Ok(Self::Unknown {
+                    type_: type_.to_string(),
+                    raw,
+                })
+            }
+            _ => {
+                const FIELDS: &[&str] = &["type", "method", "service"];
+                raw.deserialize_struct("GrpcMethodMatch", FIELDS, GrpcMethodMatchVisitor)
+                    .map_err(D::Error::custom)
+            }
+        }
     }
 }
 
@@ -406,10 +445,11 @@ impl<'de> serde::Deserialize<'de> for GrpcMethodMatch {
 /// examples include request or response modification, implementing
 /// authentication strategies, rate-limiting, and traffic shaping. API
 /// guarantee/conformance is defined based on the type of the filter.
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
-#[serde(tag = "type", rename_all = "PascalCase")]
+///
+/// Filters with a type that is not known to this version of the library are
+/// read as [`GrpcRouteFilter::Unknown`].
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "PascalCase")]
 pub enum GrpcRouteFilter {
     /// ExtensionRef is an optional, implementation-specific extension to the
     /// "filter" behavior.  For example, resource "myroutefilter" in group
@@ -453,8 +493,24 @@ pub enum GrpcRouteFilter {
     ResponseHeaderModifier {
         response_header_modifier: HttpRequestHeaderFilter,
     },
+
+    /// A filter with a type that is not known to this version of the library.
+    ///
+    /// `raw` holds the filter as it was read, and is written back verbatim.
+    #[serde(skip)]
+    Unknown {
+        type_: String,
+        raw: serde_json::Value,
+    },
 }
 
+tagged_enum_serde!(GrpcRouteFilter {
+    "ResponseHeaderModifier",
+    "RequestHeaderModifier",
+    "RequestMirror",
+    "ExtensionRef",
+});
+
 impl From<GrpcRouteFilter> for HttpRouteFilter {
     fn from(filter: GrpcRouteFilter) -> Self {
         match filter {
@@ -472,6 +528,7 @@ impl From<GrpcRouteFilter> for HttpRouteFilter {
             } => Self::ResponseHeaderModifier {
                 response_header_modifier,
             },
+            GrpcRouteFilter::Unknown { type_, raw } => Self::Unknown { type_, raw },
         }
     }
 }
@@ -500,9 +557,7 @@ impl From<GrpcRouteFilter> for HttpRouteFilter {
 /// "ResolvedRefs" condition to "False" with the "UnsupportedProtocol" reason.
 ///
 /// </gateway:experimental:description>
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
 pub struct GrpcRouteBackendRef {
     /// BackendObjectReference references a Kubernetes object.
     #[serde(flatten)]
@@ -513,6 +568,9 @@ pub struct GrpcRouteBackendRef {
     /// Support: Implementation-specific (For broader support of filters, use the
     /// Filters field in GrpcRouteRule.)
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'RequestHeaderModifier').size() <= 1").message("RequestHeaderModifier filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'ResponseHeaderModifier').size() <= 1").message("ResponseHeaderModifier filter cannot be repeated"))]
     pub filters: Option<Vec<GrpcRouteFilter>>,
     /// Weight specifies the proportion of requests forwarded to the referenced
     /// backend. This is computed as weight/(sum of all weights in this
@@ -528,7 +586,8 @@ pub struct GrpcRouteBackendRef {
     ///
     /// Support for this field varies based on the context where used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub weight: Option<u16>,
+    #[schemars(range(min = 0, max = 1_000_000))]
+    pub weight: Option<u32>,
 }
 
 impl From<GrpcRouteBackendRef> for HttpBackendRef {
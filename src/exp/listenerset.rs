@@ -0,0 +1,144 @@
+use crate::*;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+
+/// ListenerSet defines a set of additional listeners to attach to an existing
+/// Gateway. This resource provides a mechanism to merge multiple listeners
+/// into a single Gateway.
+///
+/// The parent Gateway must explicitly allow ListenerSet attachment through
+/// its `allowedListeners` configuration. By default, Gateways do not allow
+/// ListenerSet attachment.
+///
+/// Routes can attach to a ListenerSet by specifying it as a parentRef, and
+/// can optionally target specific listeners using the sectionName field.
+///
+/// Upstream serves this resource as `XListenerSet` in the
+/// `gateway.networking.x-k8s.io` group while it is experimental.
+#[derive(
+    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[kube(
+    group = "gateway.networking.x-k8s.io",
+    version = "v1alpha1",
+    kind = "XListenerSet",
+    root = "ListenerSet",
+    status = "ListenerSetStatus",
+    namespaced
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ListenerSetSpec {
+    /// ParentRef references the Gateway that the listeners are attached to.
+    pub parent_ref: ParentReference,
+
+    /// Listeners associated with this ListenerSet. Listeners define logical
+    /// endpoints that are bound on this referenced parent Gateway's addresses.
+    ///
+    /// Listeners in a `Gateway` and their attached `ListenerSets` are
+    /// concatenated as a list when programming the underlying infrastructure.
+    /// Each listener name does not need to be unique across the Gateway and
+    /// ListenerSets.
+    ///
+    /// Implementations MUST treat the parent Gateway as having the merged list
+    /// of all listeners from itself and attached ListenerSets using the
+    /// following precedence:
+    ///
+    /// 1. "parent" Gateway
+    /// 2. ListenerSet ordered by creation time (oldest first)
+    /// 3. ListenerSet ordered alphabetically by "{namespace}/{name}".
+    ///
+    /// An implementation MAY reject listeners by setting the listener status's
+    /// `Accepted` condition to False with the Reason `TooManyListeners`.
+    ///
+    /// If a listener has a conflict, this will be reported in the listener
+    /// status by setting the `Conflicted` condition to True.
+    ///
+    /// Implementations SHOULD be cautious about what information from the
+    /// parent or siblings are reported to avoid accidentally leaking sensitive
+    /// information that the child would not otherwise have access to. This
+    /// can include contents of secrets etc.
+    #[schemars(length(min = 1, max = 64))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['HTTPS', 'TLS'] ? has(l.tls) : true)").message("tls must be specified for protocols ['HTTPS', 'TLS']"))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['HTTP', 'TCP', 'UDP'] ? !has(l.tls) : true)").message("tls must not be specified for protocols ['HTTP', 'TCP', 'UDP']"))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['TCP', 'UDP'] ? (!has(l.hostname) || l.hostname == '') : true)").message("hostname must not be specified for protocols ['TCP', 'UDP']"))]
+    #[cel_validate(rule = Rule::new("self.all(l1, self.exists_one(l2, l1.name == l2.name))").message("Listener name must be unique within the Gateway"))]
+    #[cel_validate(rule = Rule::new("self.all(l1, self.exists_one(l2, l1.port == l2.port && l1.protocol == l2.protocol && (has(l1.hostname) && has(l2.hostname) ? l1.hostname == l2.hostname : !has(l1.hostname) && !has(l2.hostname))))").message("Combination of port, protocol and hostname must be unique for each listener"))]
+    pub listeners: Vec<Listener>,
+}
+
+/// ListenerSetStatus defines the observed state of a ListenerSet.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct ListenerSetStatus {
+    /// Conditions describe the current conditions of the ListenerSet.
+    ///
+    /// Known condition types are:
+    ///
+    /// * "Accepted"
+    /// * "Programmed"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 8))]
+    pub conditions: Option<Vec<metav1::Condition>>,
+
+    /// Listeners provide status for each unique listener port defined in the
+    /// Spec.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 64))]
+    pub listeners: Option<Vec<ListenerStatus>>,
+}
+
+string_enum! {
+    /// ListenerSetConditionType is a type of condition associated with a
+    /// ListenerSet. This type should be used with the
+    /// ListenerSetStatus.Conditions field.
+    pub enum ListenerSetConditionType {
+        /// This condition indicates whether a ListenerSet has generated some
+        /// configuration that is assumed to be ready soon in the underlying
+        /// data plane.
+        Programmed = "Programmed",
+
+        /// This condition is true when the controller managing the
+        /// ListenerSet is syntactically and semantically valid enough to
+        /// produce some configuration in the underlying data plane. This does
+        /// not indicate whether or not the configuration has been propagated
+        /// to the data plane.
+        Accepted = "Accepted",
+    }
+}
+
+string_enum! {
+    /// ListenerSetConditionReason defines the set of reasons that explain
+    /// why a particular ListenerSet condition type has been raised.
+    pub enum ListenerSetConditionReason {
+        /// This reason is used with the "Programmed" condition when the
+        /// condition is true.
+        Programmed = "Programmed",
+
+        /// This reason is used with the "Accepted" and "Programmed"
+        /// conditions when the ListenerSet is syntactically or semantically
+        /// invalid.
+        Invalid = "Invalid",
+
+        /// This reason is used with the "Accepted" condition when the
+        /// condition is true.
+        Accepted = "Accepted",
+
+        /// This reason is used with the "Accepted" condition when the parent
+        /// Gateway does not allow the ListenerSet to attach.
+        NotAllowed = "NotAllowed",
+
+        /// This reason is used with the "Accepted" condition when the parent
+        /// Gateway has not been accepted.
+        ParentNotAccepted = "ParentNotAccepted",
+
+        /// This reason is used with the "Accepted" condition to indicate that
+        /// the ListenerSet could not be accepted because one or more
+        /// Listeners was not valid.
+        ListenersNotValid = "ListenersNotValid",
+
+        /// This reason is used with the "Accepted" and "Programmed" conditions
+        /// when the status is "Unknown" and no controller has reconciled the
+        /// ListenerSet.
+        Pending = "Pending",
+    }
+}
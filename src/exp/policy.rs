@@ -1,4 +1,5 @@
 use crate::*;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 
 /// PolicyTargetReference identifies an API object to apply policy to. This
 /// should be used as part of Policy resources that can target Gateway API
@@ -10,17 +11,678 @@ use crate::*;
 )]
 pub struct PolicyTargetReference {
     /// Group is the group of the target resource.
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
     pub group: Group,
 
     /// Kind is kind of the target resource.
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
     pub kind: Kind,
 
     /// Name is the name of the target resource.
+    #[schemars(length(min = 1, max = 253))]
     pub name: ObjectName,
 
     /// Namespace is the namespace of the referent. When unspecified, the local
     /// namespace is inferred. Even when policy targets a resource in a
     /// different namespace, it MUST only apply to traffic originating from the
     /// same namespace as the policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::NAMESPACE")]
     pub namespace: Option<Namespace>,
 }
+
+/// PolicyTargetReferenceWithSectionName identifies an API object to apply a
+/// direct policy to, and optionally a section of that object. Only direct
+/// policies may target sections of an object.
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyTargetReferenceWithSectionName {
+    /// The target resource.
+    #[serde(flatten)]
+    pub inner: PolicyTargetReference,
+
+    /// SectionName is the name of a section within the target resource. When
+    /// unspecified, this targetRef targets the entire resource. In the
+    /// following resources, SectionName is interpreted as the following:
+    ///
+    /// * Gateway: Listener name
+    /// * HTTPRoute: HTTPRouteRule name
+    /// * Service: Port name
+    ///
+    /// If a SectionName is specified, but does not exist on the targeted
+    /// object, the Policy must fail to attach, and the policy implementation
+    /// should record a `ResolvedRefs` or similar Condition in the Policy's
+    /// status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::SECTION_NAME")]
+    pub section_name: Option<SectionName>,
+}
+
+/// LocalPolicyTargetReference identifies an API object to apply a direct or
+/// inherited policy to. This should be used as part of Policy resources that
+/// can target Gateway API resources. Unlike a [`PolicyTargetReference`], the
+/// target is always in the namespace of the policy.
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct LocalPolicyTargetReference {
+    /// Group is the group of the target resource.
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
+    pub group: Group,
+
+    /// Kind is kind of the target resource.
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
+    pub kind: Kind,
+
+    /// Name is the name of the target resource.
+    #[schemars(length(min = 1, max = 253))]
+    pub name: ObjectName,
+}
+
+/// LocalPolicyTargetReferenceWithSectionName identifies an API object to
+/// apply a direct policy to, and optionally a section of that object. This
+/// should be used as part of Policy resources that can target single
+/// resources. For more information on how this policy attachment mode works,
+/// and a sample Policy resource, refer to the policy attachment documentation
+/// for Gateway API.
+///
+/// Note: This should only be used for direct policy attachment when
+/// references to SectionName are actually needed. In all other cases,
+/// [`LocalPolicyTargetReference`] should be used.
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalPolicyTargetReferenceWithSectionName {
+    /// The target resource.
+    #[serde(flatten)]
+    pub inner: LocalPolicyTargetReference,
+
+    /// SectionName is the name of a section within the target resource. When
+    /// unspecified, this targetRef targets the entire resource. In the
+    /// following resources, SectionName is interpreted as the following:
+    ///
+    /// * Gateway: Listener name
+    /// * HTTPRoute: HTTPRouteRule name
+    /// * Service: Port name
+    ///
+    /// If a SectionName is specified, but does not exist on the targeted
+    /// object, the Policy must fail to attach, and the policy implementation
+    /// should record a `ResolvedRefs` or similar Condition in the Policy's
+    /// status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::SECTION_NAME")]
+    pub section_name: Option<SectionName>,
+}
+
+/// PolicyAncestorStatus describes the status of a route with respect to an
+/// associated Ancestor.
+///
+/// Ancestors refer to objects that are either the Target of a policy or above
+/// it in terms of object hierarchy. For example, if a policy targets a
+/// Service, the Policy's Ancestors are, in order, the Service, the HTTPRoute,
+/// the Gateway, and the GatewayClass. Almost always, in this hierarchy, the
+/// Gateway will be the most useful object to place Policy status on, so we
+/// recommend that implementations SHOULD use Gateway as the
+/// PolicyAncestorStatus object unless the designers have a _very_ good reason
+/// otherwise.
+///
+/// In the context of policy attachment, the Ancestor is used to distinguish
+/// which resource results in a distinct application of this policy. For
+/// example, if a policy targets a Service, it may have a distinct result per
+/// attached Gateway.
+///
+/// Policies targeting the same resource may have different effects depending
+/// on the ancestors of those resources. For example, different Gateways
+/// targeting the same Service may have different capabilities, especially if
+/// they have different underlying implementations.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyAncestorStatus {
+    /// AncestorRef corresponds with a ParentRef in the spec that this
+    /// PolicyAncestorStatus struct describes the status of.
+    pub ancestor_ref: ParentReference,
+
+    /// ControllerName is a domain/path string that indicates the name of the
+    /// controller that wrote this status. This corresponds with the
+    /// controllerName field on GatewayClass.
+    ///
+    /// Example: "example.net/gateway-controller".
+    ///
+    /// The format of this field is DOMAIN "/" PATH, where DOMAIN and PATH are
+    /// valid Kubernetes names
+    /// (<https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names>).
+    ///
+    /// Controllers MUST populate this field when writing status. Controllers
+    /// should ensure that entries to status populated with their
+    /// ControllerName are cleaned up when they are no longer necessary.
+    #[schemars(
+        length(min = 1, max = 253),
+        regex = "crate::schema::GATEWAY_CONTROLLER"
+    )]
+    pub controller_name: GatewayController,
+
+    /// Conditions describes the status of the Policy with respect to the
+    /// given Ancestor.
+    #[schemars(length(min = 1, max = 8))]
+    pub conditions: Vec<metav1::Condition>,
+}
+
+/// PolicyStatus defines the common attributes that all Policies should
+/// include within their status.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct PolicyStatus {
+    /// Ancestors is a list of ancestor resources (usually Gateways) that are
+    /// associated with the policy, and the status of the policy with respect
+    /// to each ancestor. When this policy attaches to a parent, the controller
+    /// that manages the parent and the ancestors MUST add an entry to this
+    /// list when the controller first sees the policy and SHOULD update the
+    /// entry as appropriate when the relevant ancestor is modified.
+    ///
+    /// Note that choosing the relevant ancestor is left to the Policy
+    /// designers; an important part of Policy design is designing the right
+    /// object level at which to namespace this status.
+    ///
+    /// Note also that implementations MUST ONLY populate ancestor status for
+    /// the Ancestor resources they are responsible for. Implementations MUST
+    /// use the ControllerName field to uniquely identify the entries in this
+    /// list that they are responsible for.
+    ///
+    /// A maximum of 16 ancestors will be represented in this list. An empty
+    /// list means the Policy is not relevant for any ancestors.
+    ///
+    /// If this slice is full, implementations MUST NOT add further entries.
+    /// Instead they MUST consider the policy unimplementable and signal that
+    /// on any related resources such as the ancestor that would be referenced
+    /// here.
+    #[schemars(length(max = 16))]
+    pub ancestors: Vec<PolicyAncestorStatus>,
+}
+
+string_enum! {
+    /// PolicyConditionType is a type of condition for a policy. This type
+    /// should be used with a Policy resource Status.Conditions field.
+    pub enum PolicyConditionType {
+        /// This condition indicates whether the policy has been accepted or
+        /// rejected by a targeted resource, and why.
+        Accepted = "Accepted",
+    }
+}
+
+string_enum! {
+    /// PolicyConditionReason is a reason for a policy condition.
+    pub enum PolicyConditionReason {
+        /// This reason is used with the "Accepted" condition when the policy
+        /// has been accepted by the targeted resource.
+        Accepted = "Accepted",
+
+        /// This reason is used with the "Accepted" condition when the policy
+        /// has not been accepted by a targeted resource because there is
+        /// another policy that targets the same resource and a merge is not
+        /// possible.
+        Conflicted = "Conflicted",
+
+        /// This reason is used with the "Accepted" condition when the policy
+        /// is syntactically or semantically invalid.
+        Invalid = "Invalid",
+
+        /// This reason is used with the "Accepted" condition when the policy
+        /// is attached to an invalid target resource.
+        TargetNotFound = "TargetNotFound",
+
+        /// This reason is used with the "Accepted" condition when the policy
+        /// has not been accepted because it depends on another policy that
+        /// has not been accepted.
+        Pending = "Pending",
+    }
+}
+
+/// PolicyTargetRef is implemented by the references with which a policy
+/// identifies its targets.
+pub trait PolicyTargetRef {
+    /// Returns the group of the target.
+    fn group(&self) -> &str;
+
+    /// Returns the kind of the target.
+    fn kind(&self) -> &str;
+
+    /// Returns the name of the target.
+    fn name(&self) -> &str;
+
+    /// Returns the namespace of the target, or `None` if the target is in the
+    /// namespace of the policy.
+    fn namespace(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the section of the target, or `None` if the reference targets
+    /// the entire object.
+    fn section_name(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// PolicyTarget identifies an object, or a section of an object, to which
+/// policies may apply.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PolicyTarget {
+    /// Group is the group of the object, e.g. "gateway.networking.k8s.io".
+    pub group: Group,
+
+    /// Kind is the kind of the object, e.g. "Gateway".
+    pub kind: Kind,
+
+    /// Namespace is the namespace of the object, or `None` for cluster-scoped
+    /// objects such as GatewayClasses.
+    pub namespace: Option<Namespace>,
+
+    /// Name is the name of the object.
+    pub name: ObjectName,
+
+    /// SectionName is the name of a section within the object (e.g. a Gateway
+    /// listener or a Service port), or `None` for the entire object.
+    pub section_name: Option<SectionName>,
+}
+
+/// Policy is implemented by policy resources that target Gateway API objects
+/// through a list of `targetRefs`.
+///
+/// A policy uses Direct attachment when it affects only the objects that it
+/// targets; [`direct_policy`] finds the policy that applies to an object.
+/// Policies with Inherited attachment also implement [`InheritedPolicy`].
+pub trait Policy: kube::Resource<DynamicType = ()> {
+    /// The type of the policy's target references.
+    type TargetRef: PolicyTargetRef;
+
+    /// Returns the policy's target references.
+    fn target_refs(&self) -> &[Self::TargetRef];
+
+    /// Returns the target reference through which this policy applies to
+    /// `target`, or `None` if the policy does not apply to it.
+    ///
+    /// A reference to the entire object applies to each of its sections, and
+    /// a reference to the target's section is preferred over it. The
+    /// namespace of a cluster-scoped target is not checked.
+    fn target_ref_for(&self, target: &PolicyTarget) -> Option<&Self::TargetRef> {
+        let policy_ns = self.meta().namespace.as_deref();
+        let mut whole = None;
+        for target_ref in self.target_refs() {
+            if target_ref.group() != target.group
+                || target_ref.kind() != target.kind
+                || target_ref.name() != target.name
+            {
+                continue;
+            }
+            if let Some(ns) = target.namespace.as_deref() {
+                if target_ref.namespace().or(policy_ns) != Some(ns) {
+                    continue;
+                }
+            }
+            match target_ref.section_name() {
+                None => whole = whole.or(Some(target_ref)),
+                Some(section) if Some(section) == target.section_name.as_deref() => {
+                    return Some(target_ref)
+                }
+                Some(_) => {}
+            }
+        }
+        whole
+    }
+
+    /// Returns true if this policy applies to `target`.
+    fn targets(&self, target: &PolicyTarget) -> bool {
+        self.target_ref_for(target).is_some()
+    }
+}
+
+/// InheritedPolicy is implemented by policies with Inherited attachment,
+/// which affect the objects that they target as well as every object below
+/// them in the GatewayClass → Gateway → Route hierarchy.
+///
+/// The configuration of an inherited policy is split into `defaults`, which
+/// policies lower in the hierarchy may override, and `overrides`, which they
+/// may not; [`inherited_policy_config`] merges them into the configuration
+/// that applies to an object.
+pub trait InheritedPolicy: Policy {
+    /// The type of the policy's defaults and overrides.
+    type Config: Clone;
+
+    /// Returns the policy's defaults, if any.
+    fn defaults(&self) -> Option<&Self::Config>;
+
+    /// Returns the policy's overrides, if any.
+    fn overrides(&self) -> Option<&Self::Config>;
+
+    /// Merges `lower`, a configuration with lower precedence, into `config`:
+    /// values that are set in `config` are kept, and values that are unset
+    /// are taken from `lower`.
+    fn merge(config: &mut Self::Config, lower: &Self::Config);
+}
+
+// === impl PolicyTargetReference ===
+
+impl PolicyTargetRef for PolicyTargetReference {
+    fn group(&self) -> &str {
+        &self.group
+    }
+
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+}
+
+impl PolicyTargetRef for PolicyTargetReferenceWithSectionName {
+    fn group(&self) -> &str {
+        &self.inner.group
+    }
+
+    fn kind(&self) -> &str {
+        &self.inner.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        self.inner.namespace.as_deref()
+    }
+
+    fn section_name(&self) -> Option<&str> {
+        self.section_name.as_deref().filter(|s| !s.is_empty())
+    }
+}
+
+// === impl LocalPolicyTargetReference ===
+
+impl PolicyTargetRef for LocalPolicyTargetReference {
+    fn group(&self) -> &str {
+        &self.group
+    }
+
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PolicyTargetRef for LocalPolicyTargetReferenceWithSectionName {
+    fn group(&self) -> &str {
+        &self.inner.group
+    }
+
+    fn kind(&self) -> &str {
+        &self.inner.kind
+    }
+
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    fn section_name(&self) -> Option<&str> {
+        self.section_name.as_deref().filter(|s| !s.is_empty())
+    }
+}
+
+// === impl PolicyTarget ===
+
+impl PolicyTarget {
+    /// Identifies an entire object.
+    pub fn of<K: kube::Resource<DynamicType = ()>>(object: &K) -> Self {
+        let meta = object.meta();
+        Self {
+            group: K::group(&()).into_owned(),
+            kind: K::kind(&()).into_owned(),
+            namespace: meta.namespace.clone(),
+            name: meta.name.clone().unwrap_or_default(),
+            section_name: None,
+        }
+    }
+
+    /// Identifies the named section of this object.
+    pub fn section(self, section_name: impl Into<SectionName>) -> Self {
+        Self {
+            section_name: Some(section_name.into()),
+            ..self
+        }
+    }
+}
+
+/// Finds the policy that applies to `target` with Direct attachment.
+///
+/// When several policies apply, a policy that targets the target's section
+/// takes precedence over a policy that targets the entire object. Remaining
+/// conflicts are resolved in favor of the oldest policy, and then of the
+/// policy that appears first in alphabetical order by "{namespace}/{name}".
+pub fn direct_policy<'p, P: Policy + 'p>(
+    policies: impl IntoIterator<Item = &'p P>,
+    target: &PolicyTarget,
+) -> Option<&'p P> {
+    policies
+        .into_iter()
+        .filter_map(|policy| {
+            let target_ref = policy.target_ref_for(target)?;
+            Some((target_ref.section_name().is_none(), policy))
+        })
+        .min_by_key(|(whole, policy)| (*whole, conflict_order(*policy)))
+        .map(|(_, policy)| policy)
+}
+
+/// Computes the configuration that inherited policies apply to an object.
+///
+/// `hierarchy` lists the object and its ancestors from the top of the
+/// hierarchy down, e.g. `[gateway_class, gateway, route]`. Overrides take
+/// precedence over defaults; overrides on objects higher in the hierarchy
+/// take precedence over those lower in it, while defaults on objects lower in
+/// the hierarchy take precedence over those higher in it. Policies that
+/// target the same object are ordered as in [`direct_policy`].
+///
+/// Returns `None` if no policy with defaults or overrides applies.
+pub fn inherited_policy_config<'p, P: InheritedPolicy + 'p>(
+    policies: impl IntoIterator<Item = &'p P>,
+    hierarchy: &[PolicyTarget],
+) -> Option<P::Config> {
+    let policies = policies.into_iter().collect::<Vec<_>>();
+    let levels = hierarchy
+        .iter()
+        .map(|target| {
+            let mut level = policies
+                .iter()
+                .copied()
+                .filter(|p| p.targets(target))
+                .collect::<Vec<_>>();
+            level.sort_by_key(|p| conflict_order(*p));
+            level
+        })
+        .collect::<Vec<_>>();
+
+    let overrides = levels.iter().flatten().filter_map(|p| p.overrides());
+    let defaults = levels.iter().rev().flatten().filter_map(|p| p.defaults());
+    let mut configs = overrides.chain(defaults);
+    let mut config = configs.next()?.clone();
+    for lower in configs {
+        P::merge(&mut config, lower);
+    }
+    Some(config)
+}
+
+/// Orders conflicting policies from the oldest to the newest, and then
+/// alphabetically by "{namespace}/{name}".
+fn conflict_order<P: kube::Resource>(
+    policy: &P,
+) -> (bool, Option<metav1::Time>, Option<String>, Option<String>) {
+    let meta = policy.meta();
+    (
+        meta.creation_timestamp.is_none(),
+        meta.creation_timestamp.clone(),
+        meta.namespace.clone(),
+        meta.name.clone(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(
+        Clone,
+        Debug,
+        kube::CustomResource,
+        serde::Deserialize,
+        serde::Serialize,
+        schemars::JsonSchema,
+    )]
+    #[kube(
+        group = "example.com",
+        version = "v1",
+        kind = "TimeoutPolicy",
+        namespaced
+    )]
+    #[serde(rename_all = "camelCase")]
+    struct TimeoutPolicySpec {
+        target_refs: Vec<LocalPolicyTargetReference>,
+        defaults: Option<Timeouts>,
+        overrides: Option<Timeouts>,
+    }
+
+    #[derive(
+        Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    )]
+    struct Timeouts {
+        request: Option<String>,
+        idle: Option<String>,
+    }
+
+    impl Policy for TimeoutPolicy {
+        type TargetRef = LocalPolicyTargetReference;
+
+        fn target_refs(&self) -> &[LocalPolicyTargetReference] {
+            &self.spec.target_refs
+        }
+    }
+
+    impl InheritedPolicy for TimeoutPolicy {
+        type Config = Timeouts;
+
+        fn defaults(&self) -> Option<&Timeouts> {
+            self.spec.defaults.as_ref()
+        }
+
+        fn overrides(&self) -> Option<&Timeouts> {
+            self.spec.overrides.as_ref()
+        }
+
+        fn merge(config: &mut Timeouts, lower: &Timeouts) {
+            config.request = config.request.take().or_else(|| lower.request.clone());
+            config.idle = config.idle.take().or_else(|| lower.idle.clone());
+        }
+    }
+
+    fn policy(
+        name: &str,
+        created: &str,
+        target: &PolicyTarget,
+        spec: serde_json::Value,
+    ) -> TimeoutPolicy {
+        let mut spec = spec;
+        spec["targetRefs"] = serde_json::json!([{
+            "group": target.group,
+            "kind": target.kind,
+            "name": target.name,
+        }]);
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "example.com/v1",
+            "kind": "TimeoutPolicy",
+            "metadata": { "name": name, "namespace": "infra", "creationTimestamp": created },
+            "spec": spec,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn merges_inherited_policies() {
+        let class = PolicyTarget {
+            group: "gateway.networking.k8s.io".to_string(),
+            kind: "GatewayClass".to_string(),
+            name: "example".to_string(),
+            ..PolicyTarget::default()
+        };
+        let gateway = PolicyTarget {
+            kind: "Gateway".to_string(),
+            namespace: Some("infra".to_string()),
+            name: "gateway".to_string(),
+            ..class.clone()
+        };
+        let route = PolicyTarget {
+            kind: "HTTPRoute".to_string(),
+            name: "route".to_string(),
+            ..gateway.clone()
+        };
+
+        let old = "2024-01-01T00:00:00Z";
+        let policies = [
+            policy(
+                "class",
+                old,
+                &class,
+                serde_json::json!({ "defaults": { "request": "10s" }, "overrides": { "idle": "1s" } }),
+            ),
+            policy(
+                "gateway",
+                old,
+                &gateway,
+                serde_json::json!({ "defaults": { "request": "5s", "idle": "30s" } }),
+            ),
+            policy(
+                "route",
+                old,
+                &route,
+                serde_json::json!({ "defaults": { "request": "2s" } }),
+            ),
+            policy(
+                "newer-route",
+                "2024-06-01T00:00:00Z",
+                &route,
+                serde_json::json!({ "defaults": { "request": "3s" } }),
+            ),
+        ];
+        let timeouts = |request: &str, idle: &str| Timeouts {
+            request: Some(request.to_string()),
+            idle: Some(idle.to_string()),
+        };
+
+        let hierarchy = [class, gateway, route.clone()];
+        assert_eq!(
+            inherited_policy_config(&policies, &hierarchy),
+            Some(timeouts("2s", "1s"))
+        );
+        assert_eq!(
+            inherited_policy_config(&policies, &hierarchy[..2]),
+            Some(timeouts("5s", "1s"))
+        );
+        assert_eq!(inherited_policy_config(&policies, &[]), None);
+
+        let direct = direct_policy(&policies, &route).and_then(|p| p.metadata.name.as_deref());
+        assert_eq!(direct, Some("route"));
+        let other = PolicyTarget {
+            namespace: Some("apps".to_string()),
+            ..route
+        };
+        assert!(direct_policy(&policies, &other).is_none());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use std::collections::HashMap;
 
 /// ReferenceGrant identifies kinds of resources in other namespaces that are
 /// trusted to reference the specified kinds of resources in the same namespace
@@ -10,14 +11,33 @@ use crate::*;
 ///
 /// All cross-namespace references in Gateway API (with the exception of
 /// cross-namespace Gateway-route attachment) require a ReferenceGrant.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
-pub struct ReferenceGrant {
+///
+/// Upstream does not define a status for ReferenceGrant, so the resource only
+/// has a spec.
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    kube::CustomResource,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1beta1",
+    kind = "ReferenceGrant",
+    namespaced
+)]
+pub struct ReferenceGrantSpec {
     /// From describes the trusted namespaces and kinds that can reference the
     /// resources described in "To". Each entry in this list must be considered
     /// to be an additional place that references can be valid from, or to put
     /// this another way, entries must be combined using OR.
     ///
     /// Support: Core
+    #[schemars(length(min = 1, max = 16))]
     pub from: Vec<ReferenceGrantFrom>,
 
     /// To describes the resources that may be referenced by the resources
@@ -26,17 +46,21 @@ pub struct ReferenceGrant {
     /// way, entries must be combined using OR.
     ///
     /// Support: Core
-    pub to: Vec<ReferenceGrantFrom>,
+    #[schemars(length(min = 1, max = 16))]
+    pub to: Vec<ReferenceGrantTo>,
 }
 
 /// ReferenceGrantFrom describes trusted namespaces and kinds.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
 pub struct ReferenceGrantFrom {
     /// Group is the group of the referent.
     ///
     /// When empty, the Kubernetes core API group is inferred.
     ///
     /// Support: Core
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
     pub group: Group,
 
     /// Kind is the kind of the referent. Although implementations may support
@@ -47,22 +71,27 @@ pub struct ReferenceGrantFrom {
     /// * TCPRoute
     /// * TLSRoute
     /// * UDPRoute
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
     pub kind: Kind,
 
     /// Namespace is the namespace of the referent.
     ///
     /// Support: Core
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::NAMESPACE")]
     pub namespace: Namespace,
 }
 
 /// ReferenceGrantTo describes what Kinds are allowed as targets of the
 /// references.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
 pub struct ReferenceGrantTo {
     /// Group is the group of the referent.
     /// When empty, the Kubernetes core API group is inferred.
     ///
     /// Support: Core
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
     pub group: Group,
 
     /// Kind is the kind of the referent. Although implementations may support
@@ -70,10 +99,370 @@ pub struct ReferenceGrantTo {
     /// level for this field:
     ///
     /// * Service
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
     pub kind: Kind,
 
     /// Name is the name of the referent. When unspecified, this policy
     /// refers to all resources of the specified Group and Kind in the local
     /// namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253))]
     pub name: Option<ObjectName>,
 }
+
+/// The object a cross-namespace reference originates from, e.g. an HTTPRoute
+/// referencing a backend or a Gateway referencing a certificate.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ReferenceFrom {
+    /// Group of the referencing resource.
+    pub group: Group,
+
+    /// Kind of the referencing resource.
+    pub kind: Kind,
+
+    /// Namespace of the referencing resource.
+    pub namespace: Namespace,
+}
+
+/// The object targeted by a cross-namespace reference.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ReferenceTo {
+    /// Group of the referent. The empty string is the core API group.
+    pub group: Group,
+
+    /// Kind of the referent.
+    pub kind: Kind,
+
+    /// Namespace of the referent.
+    pub namespace: Namespace,
+
+    /// Name of the referent.
+    pub name: ObjectName,
+}
+
+/// An index of ReferenceGrants that decides whether cross-namespace references
+/// are permitted.
+///
+/// References within a namespace are always permitted. A reference into
+/// another namespace is permitted when a ReferenceGrant in the target namespace
+/// lists the referencing group, kind and namespace in `from` and the target
+/// group, kind and (optionally) name in `to`.
+///
+/// Grants are indexed by the (from, to) pair they permit, so lookups do not
+/// depend on the number of grants in the index.
+#[derive(Clone, Debug, Default)]
+pub struct ReferenceGrantIndex {
+    /// The names permitted for each (from, to) pair. A `None` name permits
+    /// every object of the target kind.
+    index: HashMap<GrantKey, Vec<(GrantId, Option<ObjectName>)>>,
+
+    /// The keys each grant contributed to the index, so that grants can be
+    /// updated and removed.
+    grants: HashMap<GrantId, Vec<GrantKey>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct GrantId {
+    namespace: Namespace,
+    name: ObjectName,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct GrantKey {
+    from: ReferenceFrom,
+    to_group: Group,
+    to_kind: Kind,
+    to_namespace: Namespace,
+}
+
+// === impl ReferenceFrom ===
+
+impl ReferenceFrom {
+    pub fn new(
+        group: impl Into<Group>,
+        kind: impl Into<Kind>,
+        namespace: impl Into<Namespace>,
+    ) -> Self {
+        Self {
+            group: group.into(),
+            kind: kind.into(),
+            namespace: namespace.into(),
+        }
+    }
+
+    /// Describes references originating from the given resource. Returns
+    /// `None` if the resource has no namespace.
+    pub fn from_resource<K>(resource: &K) -> Option<Self>
+    where
+        K: kube::Resource<DynamicType = ()>,
+    {
+        let namespace = resource.meta().namespace.clone()?;
+        Some(Self::new(K::group(&()), K::kind(&()), namespace))
+    }
+}
+
+// === impl ReferenceGrantIndex ===
+
+impl ReferenceGrantIndex {
+    /// Adds a grant to the index, replacing any previous version of the grant
+    /// with the same namespace and name. Grants without a namespace are
+    /// ignored.
+    pub fn insert(&mut self, grant: &ReferenceGrant) {
+        let Some(namespace) = grant.metadata.namespace.clone() else {
+            return;
+        };
+        let id = GrantId {
+            namespace,
+            name: grant.metadata.name.clone().unwrap_or_default(),
+        };
+        self.remove(&id.namespace, &id.name);
+
+        let mut keys = Vec::new();
+        for from in &grant.spec.from {
+            for to in &grant.spec.to {
+                let key = GrantKey {
+                    from: ReferenceFrom::new(&*from.group, &*from.kind, &*from.namespace),
+                    to_group: to.group.clone(),
+                    to_kind: to.kind.clone(),
+                    to_namespace: id.namespace.clone(),
+                };
+                self.index
+                    .entry(key.clone())
+                    .or_default()
+                    .push((id.clone(), to.name.clone()));
+                keys.push(key);
+            }
+        }
+        self.grants.insert(id, keys);
+    }
+
+    /// Removes the grant with the given namespace and name from the index.
+    pub fn remove(&mut self, namespace: &str, name: &str) {
+        let id = GrantId {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        };
+        let Some(keys) = self.grants.remove(&id) else {
+            return;
+        };
+        for key in keys {
+            if let Some(names) = self.index.get_mut(&key) {
+                names.retain(|(grant, _)| *grant != id);
+                if names.is_empty() {
+                    self.index.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Returns the number of grants in the index.
+    pub fn len(&self) -> usize {
+        self.grants.len()
+    }
+
+    /// Returns true if the index holds no grants.
+    pub fn is_empty(&self) -> bool {
+        self.grants.is_empty()
+    }
+
+    /// Returns true if `from` may reference `to`.
+    pub fn allows(&self, from: &ReferenceFrom, to: &ReferenceTo) -> bool {
+        self.allows_parts(from, &to.group, &to.kind, &to.namespace, &to.name)
+    }
+
+    /// Returns true if `from` may reference the given backend. The backend
+    /// defaults to a core Service in the namespace of `from`.
+    pub fn allows_backend(&self, from: &ReferenceFrom, backend: &BackendObjectReference) -> bool {
+        self.allows_parts(
+            from,
+            backend.group.as_deref().unwrap_or(""),
+            backend.kind.as_deref().unwrap_or("Service"),
+            backend.namespace.as_deref().unwrap_or(&from.namespace),
+            &backend.name,
+        )
+    }
+
+    /// Returns true if `from` may reference the backend of a `BackendRef`.
+    pub fn allows_backend_ref(&self, from: &ReferenceFrom, backend: &BackendRef) -> bool {
+        self.allows_backend(from, &backend.inner)
+    }
+
+    /// Returns true if `from` may reference the backend of an `HTTPBackendRef`.
+    /// An `HTTPBackendRef` without a backend references nothing and is always
+    /// permitted.
+    pub fn allows_http_backend_ref(&self, from: &ReferenceFrom, backend: &HttpBackendRef) -> bool {
+        backend
+            .backend_ref
+            .as_ref()
+            .map_or(true, |backend| self.allows_backend_ref(from, backend))
+    }
+
+    /// Returns true if `from` may reference the backend of a
+    /// `GRPCRouteBackendRef`.
+    pub fn allows_grpc_backend_ref(
+        &self,
+        from: &ReferenceFrom,
+        backend: &GrpcRouteBackendRef,
+    ) -> bool {
+        self.allows_backend(from, &backend.inner)
+    }
+
+    /// Returns true if `from` may reference the given secret. The secret
+    /// defaults to a core Secret in the namespace of `from`.
+    pub fn allows_secret(&self, from: &ReferenceFrom, secret: &SecretObjectReference) -> bool {
+        self.allows_parts(
+            from,
+            secret.group.as_deref().unwrap_or(""),
+            secret.kind.as_deref().unwrap_or("Secret"),
+            secret.namespace.as_deref().unwrap_or(&from.namespace),
+            &secret.name,
+        )
+    }
+
+    /// Returns true if `from` may reference every certificate of a listener's
+    /// TLS configuration.
+    pub fn allows_certificate_refs(&self, from: &ReferenceFrom, tls: &GatewayTlsConfig) -> bool {
+        tls.certificate_refs
+            .iter()
+            .flatten()
+            .all(|secret| self.allows_secret(from, secret))
+    }
+
+    fn allows_parts(
+        &self,
+        from: &ReferenceFrom,
+        group: &str,
+        kind: &str,
+        namespace: &str,
+        name: &str,
+    ) -> bool {
+        if from.namespace == namespace {
+            return true;
+        }
+
+        let key = GrantKey {
+            from: from.clone(),
+            to_group: group.to_string(),
+            to_kind: kind.to_string(),
+            to_namespace: namespace.to_string(),
+        };
+        self.index.get(&key).map_or(false, |names| {
+            names
+                .iter()
+                .any(|(_, n)| n.as_deref().map_or(true, |n| n == name))
+        })
+    }
+}
+
+impl<'a> Extend<&'a ReferenceGrant> for ReferenceGrantIndex {
+    fn extend<I: IntoIterator<Item = &'a ReferenceGrant>>(&mut self, grants: I) {
+        for grant in grants {
+            self.insert(grant);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a ReferenceGrant> for ReferenceGrantIndex {
+    fn from_iter<I: IntoIterator<Item = &'a ReferenceGrant>>(grants: I) -> Self {
+        let mut index = Self::default();
+        index.extend(grants);
+        index
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_reference_grant() {
+        // ref: https://gateway-api.sigs.k8s.io/api-types/referencegrant/
+        let grant: ReferenceGrant = serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "ReferenceGrant",
+            "metadata": { "name": "allow-ns1-gateways-to-ref-secrets", "namespace": "default" },
+            "spec": {
+                "from": [{
+                    "group": "gateway.networking.k8s.io",
+                    "kind": "Gateway",
+                    "namespace": "ns1"
+                }],
+                "to": [{ "group": "", "kind": "Secret" }]
+            }
+        }))
+        .expect("grant must deserialize");
+
+        assert_eq!(
+            grant.spec.to,
+            vec![ReferenceGrantTo {
+                group: "".to_string(),
+                kind: "Secret".to_string(),
+                name: None,
+            }]
+        );
+    }
+
+    fn grant(namespace: &str, name: &str, spec: serde_json::Value) -> ReferenceGrant {
+        let mut grant = ReferenceGrant::new(name, serde_json::from_value(spec).unwrap());
+        grant.metadata.namespace = Some(namespace.to_string());
+        grant
+    }
+
+    fn backend(namespace: &str, name: &str) -> BackendObjectReference {
+        BackendObjectReference {
+            group: None,
+            kind: None,
+            name: name.to_string(),
+            namespace: Some(namespace.to_string()),
+            port: Some(8080),
+        }
+    }
+
+    #[test]
+    fn index_authorizes_references() {
+        let route = ReferenceFrom::new("gateway.networking.k8s.io", "HTTPRoute", "apps");
+        let mut index = [
+            grant(
+                "backends",
+                "routes-to-services",
+                serde_json::json!({
+                    "from": [{ "group": "gateway.networking.k8s.io", "kind": "HTTPRoute", "namespace": "apps" }],
+                    "to": [{ "group": "", "kind": "Service", "name": "api" }]
+                }),
+            ),
+            grant(
+                "certs",
+                "gateways-to-secrets",
+                serde_json::json!({
+                    "from": [{ "group": "gateway.networking.k8s.io", "kind": "Gateway", "namespace": "infra" }],
+                    "to": [{ "group": "", "kind": "Secret" }]
+                }),
+            ),
+        ]
+        .iter()
+        .collect::<ReferenceGrantIndex>();
+        assert_eq!(index.len(), 2);
+
+        // References within a namespace never need a grant.
+        assert!(index.allows_backend(&route, &backend("apps", "anything")));
+        assert!(index.allows_backend(&route, &backend("backends", "api")));
+        assert!(!index.allows_backend(&route, &backend("backends", "db")));
+        assert!(!index.allows_backend(&route, &backend("other", "api")));
+
+        let gateway = ReferenceFrom::new("gateway.networking.k8s.io", "Gateway", "infra");
+        let tls = GatewayTlsConfig {
+            certificate_refs: Some(vec![SecretObjectReference {
+                name: "wildcard".to_string(),
+                namespace: Some("certs".to_string()),
+                ..SecretObjectReference::default()
+            }]),
+            ..GatewayTlsConfig::default()
+        };
+        assert!(index.allows_certificate_refs(&gateway, &tls));
+        assert!(!index.allows_certificate_refs(&route, &tls));
+
+        index.remove("backends", "routes-to-services");
+        assert!(!index.allows_backend(&route, &backend("backends", "api")));
+        assert_eq!(index.len(), 1);
+    }
+}
@@ -20,6 +20,7 @@ pub struct TcpRouteSpec {
     pub inner: CommonRouteSpec,
 
     /// Rules are a list of TCP matchers and actions.
+    #[schemars(length(min = 1, max = 16))]
     pub rules: Vec<TcpRouteRule>,
 }
 
@@ -48,5 +49,6 @@ pub struct TcpRouteRule {
     /// Support: Custom for any other resource
     ///
     /// Support for weight: Extended
+    #[schemars(length(min = 1, max = 16))]
     pub backend_refs: Vec<BackendRef>,
 }
@@ -55,9 +55,15 @@ pub struct TlsRouteSpec {
     /// `False` in the corresponding RouteParentStatus.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        length(max = 16),
+        inner(length(min = 1, max = 253), regex = "crate::schema::HOSTNAME")
+    )]
     pub hostnames: Option<Vec<Hostname>>,
 
     /// Rules are a list of TLS matchers and actions.
+    #[schemars(length(min = 1, max = 16))]
     pub rules: Vec<TlsRouteRule>,
 }
 
@@ -89,5 +95,6 @@ pub struct TlsRouteRule {
     /// Support: Custom for any other resource
     ///
     /// Support for weight: Extended
+    #[schemars(length(min = 1, max = 16))]
     pub backend_refs: Vec<BackendRef>,
 }
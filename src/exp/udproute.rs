@@ -15,6 +15,7 @@ pub struct UdpRouteSpec {
     #[serde(flatten)]
     pub inner: CommonRouteSpec,
 
+    #[schemars(length(min = 1, max = 16))]
     pub rules: Vec<UdpRouteRule>,
 }
 
@@ -29,5 +30,6 @@ pub struct UdpRouteStatus {
 )]
 #[serde(rename_all = "camelCase")]
 pub struct UdpRouteRule {
+    #[schemars(length(min = 1, max = 16))]
     pub backend_refs: Vec<BackendRef>,
 }
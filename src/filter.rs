@@ -0,0 +1,498 @@
+//! Applies HTTPRoute filters to requests, following the Gateway API's filter
+//! semantics, so that the configuration generated for a data plane can be
+//! checked against a reference implementation.
+
+use crate::*;
+
+/// The result of applying a rule's filters to a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpFilterOutcome {
+    /// Whether the request is forwarded or redirected.
+    pub action: HttpFilterAction,
+
+    /// The mirror filters that apply to the request, in order. A request is
+    /// mirrored to each filter's backend with the probability given by
+    /// [`HttpRequestMirrorFilter::mirrored_fraction`].
+    pub mirrors: Vec<HttpRequestMirrorFilter>,
+}
+
+/// What happens to a request once its filters have been applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HttpFilterAction {
+    /// The request is forwarded to the rule's backends, as modified by the
+    /// filters.
+    Forward(HttpRequest),
+
+    /// The request is answered with a redirect.
+    Redirect(HttpRedirect),
+}
+
+/// A redirect response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpRedirect {
+    /// The response's status code.
+    pub status_code: u16,
+
+    /// The scheme of the `Location` header.
+    pub scheme: String,
+
+    /// The hostname of the `Location` header.
+    pub hostname: String,
+
+    /// The port of the `Location` header, or `None` if it is omitted because
+    /// it is unknown or is the well-known port of the scheme.
+    pub port: Option<PortNumber>,
+
+    /// The path and query string of the `Location` header.
+    pub path_and_query: String,
+}
+
+/// Indicates that a rule's filters cannot be applied to a request.
+///
+/// Upstream requires that requests handled by such a rule receive an error
+/// response (e.g. a 500) rather than skipping the filter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HttpFilterError {
+    /// The filter refers to an implementation-specific extension.
+    ExtensionRef(LocalObjectReference),
+
+    /// The filter delegates authorization to an external service, whose
+    /// decision cannot be predicted here.
+    ExternalAuth(Box<HttpExternalAuthFilter>),
+
+    /// The filter, or its path modifier, has a type that is not known to this
+    /// version of the library.
+    Unknown(String),
+
+    /// A `ReplacePrefixMatch` path modifier was used by a rule that did not
+    /// select the request with a `PathPrefix` match.
+    NoPrefixMatch,
+}
+
+/// Applies `filters` to `request`, where `route_match` is the match that
+/// selected the request's rule (or `None` if the rule has no matches).
+///
+/// Filters are applied in order. Within a `RequestHeaderModifier`, headers are
+/// set, then added, then removed; header names are compared
+/// case-insensitively. A `RequestRedirect` filter answers the request, so that
+/// the filters that follow it are not applied. `ResponseHeaderModifier` and
+/// `CORS` filters do not affect the forwarded request and are ignored.
+///
+/// A `ReplacePrefixMatch` modifier replaces the path prefix matched by the
+/// rule, ignoring trailing slashes, so that with a `/foo` prefix match,
+/// `/foo/bar` is rewritten with `/xyz` or `/xyz/` to `/xyz/bar`, and with an
+/// empty replacement to `/bar`; the resulting path is never empty.
+///
+/// A rule without matches is handled as a `/` path prefix match.
+pub fn apply_http_filters(
+    filters: &[HttpRouteFilter],
+    route_match: Option<&HttpRouteMatch>,
+    request: &HttpRequest,
+) -> Result<HttpFilterOutcome, HttpFilterError> {
+    let mut request = request.clone();
+    let mut mirrors = Vec::new();
+    for filter in filters {
+        match filter {
+            HttpRouteFilter::RequestHeaderModifier {
+                request_header_modifier,
+            } => modify_headers(&mut request.headers, request_header_modifier),
+
+            HttpRouteFilter::ResponseHeaderModifier { .. } => {}
+
+            HttpRouteFilter::RequestMirror { request_mirror } => {
+                mirrors.push(request_mirror.clone());
+            }
+
+            HttpRouteFilter::RequestRedirect { request_redirect } => {
+                let redirect = redirect(&request, route_match, request_redirect)?;
+                return Ok(HttpFilterOutcome {
+                    action: HttpFilterAction::Redirect(redirect),
+                    mirrors,
+                });
+            }
+
+            HttpRouteFilter::URLRewrite { url_rewrite } => {
+                if let Some(hostname) = &url_rewrite.hostname {
+                    request.host = hostname.to_string();
+                }
+                if let Some(modifier) = &url_rewrite.path {
+                    request.path = modify_path(&request.path, route_match, modifier)?;
+                }
+            }
+
+            HttpRouteFilter::ExtensionRef { extension_ref } => {
+                return Err(HttpFilterError::ExtensionRef(extension_ref.clone()));
+            }
+
+            HttpRouteFilter::CORS { .. } => {}
+
+            HttpRouteFilter::ExternalAuth { external_auth } => {
+                return Err(HttpFilterError::ExternalAuth(Box::new(
+                    external_auth.clone(),
+                )));
+            }
+
+            HttpRouteFilter::Unknown { type_, .. } => {
+                return Err(HttpFilterError::Unknown(type_.clone()));
+            }
+        }
+    }
+
+    Ok(HttpFilterOutcome {
+        action: HttpFilterAction::Forward(request),
+        mirrors,
+    })
+}
+
+// === impl HttpRequestMirrorFilter ===
+
+impl HttpRequestMirrorFilter {
+    /// Returns the fraction of requests that are mirrored, which is all
+    /// requests unless `percent` or `fraction` is set.
+    pub fn mirrored_fraction(&self) -> Fraction {
+        if let Some(fraction) = &self.fraction {
+            return Fraction {
+                numerator: fraction.numerator,
+                denominator: Some(fraction.denominator.unwrap_or(100)),
+            };
+        }
+        Fraction {
+            numerator: self.percent.unwrap_or(100),
+            denominator: Some(100),
+        }
+    }
+}
+
+fn modify_headers(headers: &mut Vec<(HttpHeaderName, String)>, filter: &HttpRequestHeaderFilter) {
+    for HttpHeader { name, value } in filter.set.iter().flatten() {
+        // Replace the first value of the header and drop the rest.
+        let mut found = false;
+        headers.retain_mut(|(n, v)| {
+            if !n.eq_ignore_ascii_case(name) {
+                return true;
+            }
+            if found {
+                return false;
+            }
+            found = true;
+            *v = value.clone();
+            true
+        });
+        if !found {
+            headers.push((name.clone(), value.clone()));
+        }
+    }
+    for HttpHeader { name, value } in filter.add.iter().flatten() {
+        headers.push((name.clone(), value.clone()));
+    }
+    for name in filter.remove.iter().flatten() {
+        headers.retain(|(n, _)| !n.eq_ignore_ascii_case(name));
+    }
+}
+
+fn redirect(
+    request: &HttpRequest,
+    route_match: Option<&HttpRouteMatch>,
+    filter: &HttpRequestRedirectFilter,
+) -> Result<HttpRedirect, HttpFilterError> {
+    let scheme = filter
+        .scheme
+        .clone()
+        .unwrap_or_else(|| request.scheme.clone());
+
+    // When the scheme is changed, the port defaults to the scheme's well-known
+    // port rather than the port on which the request was received.
+    let port = match (filter.port, &filter.scheme) {
+        (Some(port), _) => Some(port),
+        (None, Some(scheme)) => well_known_port(scheme).or(request.port),
+        (None, None) => request.port,
+    };
+    let port = port.filter(|p| Some(*p) != well_known_port(&scheme));
+
+    let mut path_and_query = request.path_and_query();
+    if let Some(modifier) = &filter.path {
+        let query = &path_and_query[request.path.len()..];
+        path_and_query = modify_path(&request.path, route_match, modifier)? + query;
+    }
+
+    Ok(HttpRedirect {
+        status_code: filter.status_code.unwrap_or(302),
+        scheme,
+        hostname: filter
+            .hostname
+            .as_ref()
+            .map_or_else(|| request.host.clone(), ToString::to_string),
+        port,
+        path_and_query,
+    })
+}
+
+fn modify_path(
+    path: &str,
+    route_match: Option<&HttpRouteMatch>,
+    modifier: &HttpPathModifier,
+) -> Result<String, HttpFilterError> {
+    let replace_prefix_match = match modifier {
+        HttpPathModifier::ReplaceFullPath { replace_full_path } => {
+            return Ok(replace_full_path.clone())
+        }
+        HttpPathModifier::ReplacePrefixMatch {
+            replace_prefix_match,
+        } => replace_prefix_match,
+        HttpPathModifier::Unknown { type_, .. } => {
+            return Err(HttpFilterError::Unknown(type_.clone()))
+        }
+    };
+
+    let prefix = match route_match.and_then(|m| m.path.as_ref()) {
+        None => "/",
+        Some(HttpPathMatch::PathPrefix { value }) => value,
+        Some(_) => return Err(HttpFilterError::NoPrefixMatch),
+    };
+    let rest = path
+        .strip_prefix(prefix.trim_end_matches('/'))
+        .ok_or(HttpFilterError::NoPrefixMatch)?;
+
+    let mut path = replace_prefix_match.trim_end_matches('/').to_string();
+    path.push_str(rest);
+    if !path.starts_with('/') {
+        path.insert(0, '/');
+    }
+    Ok(path)
+}
+
+fn well_known_port(scheme: &str) -> Option<PortNumber> {
+    match scheme {
+        "http" => Some(80),
+        "https" => Some(443),
+        _ => None,
+    }
+}
+
+// === impl HttpRouteSelection ===
+
+impl<'r, R> HttpRouteSelection<'r, R> {
+    /// Applies the selected rule's filters to `request`.
+    ///
+    /// See [`apply_http_filters`].
+    pub fn apply_filters(
+        &self,
+        request: &HttpRequest,
+    ) -> Result<HttpFilterOutcome, HttpFilterError> {
+        apply_http_filters(
+            self.rule.filters.as_deref().unwrap_or_default(),
+            self.route_match,
+            request,
+        )
+    }
+}
+
+// === impl HttpRedirect ===
+
+impl HttpRedirect {
+    /// Returns the value of the `Location` header.
+    pub fn location(&self) -> String {
+        match self.port {
+            Some(port) => format!(
+                "{}://{}:{}{}",
+                self.scheme, self.hostname, port, self.path_and_query
+            ),
+            None => format!("{}://{}{}", self.scheme, self.hostname, self.path_and_query),
+        }
+    }
+}
+
+// === impl HttpFilterError ===
+
+impl std::fmt::Display for HttpFilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExtensionRef(ext) => write!(
+                f,
+                "unsupported extension filter {}/{} {}",
+                ext.group, ext.kind, ext.name
+            ),
+            Self::ExternalAuth(auth) => write!(
+                f,
+                "external authorization by {} is required",
+                auth.backend_ref.name
+            ),
+            Self::Unknown(type_) => write!(f, "unsupported filter type {type_}"),
+            Self::NoPrefixMatch => {
+                f.write_str("ReplacePrefixMatch requires a matching PathPrefix match")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HttpFilterError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn filters(filters: serde_json::Value) -> Vec<HttpRouteFilter> {
+        serde_json::from_value(filters).unwrap()
+    }
+
+    fn prefix(value: &str) -> HttpRouteMatch {
+        HttpRouteMatch {
+            path: Some(HttpPathMatch::PathPrefix {
+                value: value.to_string(),
+            }),
+            ..HttpRouteMatch::default()
+        }
+    }
+
+    fn forwarded(outcome: HttpFilterOutcome) -> HttpRequest {
+        match outcome.action {
+            HttpFilterAction::Forward(request) => request,
+            action => panic!("request must be forwarded: {action:?}"),
+        }
+    }
+
+    #[test]
+    fn replaces_prefix_match() {
+        let cases = [
+            ("/foo/bar", "/foo", "/xyz", "/xyz/bar"),
+            ("/foo/bar", "/foo", "/xyz/", "/xyz/bar"),
+            ("/foo/bar", "/foo/", "/xyz", "/xyz/bar"),
+            ("/foo/bar", "/foo/", "/xyz/", "/xyz/bar"),
+            ("/foo", "/foo", "/xyz", "/xyz"),
+            ("/foo/", "/foo", "/xyz", "/xyz/"),
+            ("/foo/bar", "/foo", "", "/bar"),
+            ("/foo/", "/foo", "", "/"),
+            ("/foo", "/foo", "", "/"),
+            ("/foo/", "/foo", "/", "/"),
+            ("/foo", "/foo", "/", "/"),
+        ];
+        for (path, matched, replacement, expected) in cases {
+            let filters = filters(serde_json::json!([{
+                "type": "URLRewrite",
+                "urlRewrite": {
+                    "path": { "type": "ReplacePrefixMatch", "replacePrefixMatch": replacement },
+                },
+            }]));
+            let request = HttpRequest::new("GET", "example.com", path);
+            let outcome = apply_http_filters(&filters, Some(&prefix(matched)), &request).unwrap();
+            assert_eq!(
+                forwarded(outcome).path,
+                expected,
+                "{path} with prefix {matched:?} replaced by {replacement:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn modifies_headers_and_mirrors() {
+        let filters = filters(serde_json::json!([
+            {
+                "type": "RequestHeaderModifier",
+                "requestHeaderModifier": {
+                    "set": [{ "name": "X-Set", "value": "set" }],
+                    "add": [{ "name": "x-add", "value": "added" }],
+                    "remove": ["x-remove"],
+                },
+            },
+            {
+                "type": "RequestMirror",
+                "requestMirror": {
+                    "backendRef": { "name": "mirror", "port": 80 },
+                    "percent": 25,
+                },
+            },
+            {
+                "type": "URLRewrite",
+                "urlRewrite": {
+                    "hostname": "internal.example.com",
+                    "path": { "type": "ReplaceFullPath", "replaceFullPath": "/v2" },
+                },
+            },
+        ]));
+        let request = HttpRequest::new("GET", "example.com", "/v1?q=1")
+            .header("x-set", "a")
+            .header("x-add", "a")
+            .header("x-set", "b")
+            .header("X-Remove", "a");
+
+        let outcome = apply_http_filters(&filters, None, &request).unwrap();
+        assert_eq!(outcome.mirrors.len(), 1);
+        assert_eq!(outcome.mirrors[0].backend_ref.name, "mirror");
+        assert_eq!(
+            outcome.mirrors[0].mirrored_fraction(),
+            Fraction {
+                numerator: 25,
+                denominator: Some(100),
+            }
+        );
+        let request = forwarded(outcome);
+        assert_eq!(request.host, "internal.example.com");
+        assert_eq!(request.path_and_query(), "/v2?q=1");
+        assert_eq!(
+            request.headers,
+            [
+                ("x-set".to_string(), "set".to_string()),
+                ("x-add".to_string(), "a".to_string()),
+                ("x-add".to_string(), "added".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn redirects() {
+        let redirect = |filter: serde_json::Value, request: &HttpRequest| {
+            let filters = filters(serde_json::json!([{
+                "type": "RequestRedirect",
+                "requestRedirect": filter,
+            }]));
+            match apply_http_filters(&filters, Some(&prefix("/api")), request)
+                .unwrap()
+                .action
+            {
+                HttpFilterAction::Redirect(redirect) => redirect,
+                action => panic!("request must be redirected: {action:?}"),
+            }
+        };
+
+        let request = HttpRequest::new("GET", "example.com:8080", "/api/users?page=2");
+        let r = redirect(serde_json::json!({ "scheme": "https" }), &request);
+        assert_eq!(r.status_code, 302);
+        assert_eq!(r.location(), "https://example.com/api/users?page=2");
+
+        let r = redirect(
+            serde_json::json!({
+                "hostname": "other.example.com",
+                "statusCode": 301,
+                "path": { "type": "ReplacePrefixMatch", "replacePrefixMatch": "/v2" },
+            }),
+            &request,
+        );
+        assert_eq!(r.status_code, 301);
+        assert_eq!(
+            r.location(),
+            "http://other.example.com:8080/v2/users?page=2"
+        );
+
+        let request = HttpRequest::new("GET", "example.com:80", "/api").scheme("http");
+        let r = redirect(
+            serde_json::json!({ "port": 8443, "scheme": "https" }),
+            &request,
+        );
+        assert_eq!(r.location(), "https://example.com:8443/api");
+        let r = redirect(serde_json::json!({}), &request);
+        assert_eq!(r.location(), "http://example.com/api");
+    }
+
+    #[test]
+    fn rejects_unsupported_filters() {
+        let filters = filters(serde_json::json!([{
+            "type": "ExtensionRef",
+            "extensionRef": { "group": "example.com", "kind": "Filter", "name": "f" },
+        }]));
+        let request = HttpRequest::new("GET", "example.com", "/");
+        assert!(matches!(
+            apply_http_filters(&filters, None, &request),
+            Err(HttpFilterError::ExtensionRef(_))
+        ));
+    }
+}
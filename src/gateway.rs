@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 /// Gateway represents an instance of a service-traffic handling infrastructure
 /// by binding Listeners to a set of IP addresses.
 #[derive(
-    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, kube::CELSchema,
 )]
 #[kube(
     group = "gateway.networking.k8s.io",
@@ -18,6 +18,7 @@ use std::collections::BTreeMap;
 pub struct GatewaySpec {
     /// GatewayClassName used for this Gateway. This is the name of a
     /// GatewayClass resource.
+    #[schemars(length(min = 1, max = 253))]
     pub gateway_class_name: ObjectName,
 
     /// Listeners associated with this Gateway. Listeners define logical
@@ -56,6 +57,12 @@ pub struct GatewaySpec {
     /// condition in the Listener status.
     ///
     /// Support: Core
+    #[schemars(length(min = 1, max = 64))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['HTTPS', 'TLS'] ? has(l.tls) : true)").message("tls must be specified for protocols ['HTTPS', 'TLS']"))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['HTTP', 'TCP', 'UDP'] ? !has(l.tls) : true)").message("tls must not be specified for protocols ['HTTP', 'TCP', 'UDP']"))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['TCP', 'UDP'] ? (!has(l.hostname) || l.hostname == '') : true)").message("hostname must not be specified for protocols ['TCP', 'UDP']"))]
+    #[cel_validate(rule = Rule::new("self.all(l1, self.exists_one(l2, l1.name == l2.name))").message("Listener name must be unique within the Gateway"))]
+    #[cel_validate(rule = Rule::new("self.all(l1, self.exists_one(l2, l1.port == l2.port && l1.protocol == l2.protocol && (has(l1.hostname) && has(l2.hostname) ? l1.hostname == l2.hostname : !has(l1.hostname) && !has(l2.hostname))))").message("Combination of port, protocol and hostname must be unique for each listener"))]
     pub listeners: Vec<Listener>,
 
     /// Addresses requested for this Gateway. This is optional and behavior can
@@ -81,7 +88,195 @@ pub struct GatewaySpec {
     /// GatewayStatus.Addresses.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
+    #[cel_validate(rule = Rule::new("self.all(a1, a1.type == 'IPAddress' ? self.exists_one(a2, a2.type == a1.type && a2.value == a1.value) : true )").message("IPAddress values must be unique"))]
+    #[cel_validate(rule = Rule::new("self.all(a1, a1.type == 'Hostname' ? self.exists_one(a2, a2.type == a1.type && a2.value == a1.value) : true )").message("Hostname values must be unique"))]
     pub addresses: Option<Vec<GatewayAddress>>,
+
+    /// Infrastructure defines infrastructure level attributes about this
+    /// Gateway instance.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub infrastructure: Option<GatewayInfrastructure>,
+
+    /// BackendTLS configures TLS settings for when this Gateway is connecting
+    /// to backends with TLS.
+    ///
+    /// Support: Core
+    #[serde(
+        default,
+        rename = "backendTLS",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub backend_tls: Option<GatewayBackendTls>,
+
+    /// AllowedListeners defines which ListenerSets can be attached to this
+    /// Gateway. While this feature is experimental, the default value is to
+    /// allow no ListenerSets.
+    ///
+    /// gateway:experimental
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_listeners: Option<AllowedListeners>,
+}
+
+/// GatewayInfrastructure defines infrastructure level attributes about a
+/// Gateway instance.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayInfrastructure {
+    /// Labels that SHOULD be applied to any resources created in response to
+    /// this Gateway.
+    ///
+    /// For implementations creating other Kubernetes objects, this should be
+    /// the `metadata.labels` field on resources. For other implementations,
+    /// this refers to any relevant (implementation specific) "labels" concepts.
+    ///
+    /// An implementation may chose to add additional implementation-specific
+    /// labels as they see fit.
+    ///
+    /// If an implementation maps these labels to Pods, or any other resource
+    /// that would need to be recreated when labels change, it SHOULD clearly
+    /// warn about this behavior in documentation.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::schema::infrastructure_labels")]
+    #[cel_validate(rule = Rule::new(r#"self.all(key, key.matches(r"""^([a-zA-Z0-9]([-a-zA-Z0-9]*[a-zA-Z0-9])?([.][a-zA-Z0-9]([-a-zA-Z0-9]*[a-zA-Z0-9])?)*/)?[A-Za-z0-9]([-A-Za-z0-9_.]{0,61}[A-Za-z0-9])?$"""))"#).message("Label keys must be in the form of an optional DNS subdomain prefix followed by a required name segment of up to 63 characters."))]
+    #[cel_validate(rule = Rule::new(r#"self.all(key, key.split("/")[0].size() < 253)"#).message("If specified, the label key's prefix must be a DNS subdomain not longer than 253 characters in total."))]
+    pub labels: Option<BTreeMap<LabelKey, LabelValue>>,
+
+    /// Annotations that SHOULD be applied to any resources created in response
+    /// to this Gateway.
+    ///
+    /// For implementations creating other Kubernetes objects, this should be
+    /// the `metadata.annotations` field on resources. For other
+    /// implementations, this refers to any relevant (implementation specific)
+    /// "annotations" concepts.
+    ///
+    /// An implementation may chose to add additional implementation-specific
+    /// annotations as they see fit.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::schema::infrastructure_annotations")]
+    #[cel_validate(rule = Rule::new(r#"self.all(key, key.matches(r"""^(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9]/)?[A-Za-z0-9]([-A-Za-z0-9_.]{0,61}[A-Za-z0-9])?$"""))"#).message("Annotation keys must be in the form of an optional DNS subdomain prefix followed by a required name segment of up to 63 characters."))]
+    #[cel_validate(rule = Rule::new(r#"self.all(key, key.split("/")[0].size() < 253)"#).message("If specified, the annotation key's prefix must be a DNS subdomain not longer than 253 characters in total."))]
+    pub annotations: Option<BTreeMap<AnnotationKey, AnnotationValue>>,
+
+    /// ParametersRef is a reference to a resource that contains the
+    /// configuration parameters corresponding to the Gateway. This is optional
+    /// if the controller does not require any additional configuration.
+    ///
+    /// This follows the same semantics as GatewayClass's `parametersRef`, but
+    /// on a per-Gateway basis.
+    ///
+    /// The Gateway's GatewayClass may provide its own `parametersRef`. When
+    /// both are specified, the merging behavior is implementation specific.
+    /// It is generally recommended that GatewayClass provides defaults that
+    /// can be overridden by a Gateway.
+    ///
+    /// If the referent cannot be found, refers to an unsupported kind, or when
+    /// the data within that resource is malformed, the Gateway SHOULD be
+    /// rejected with the "Accepted" status condition set to "False" and an
+    /// "InvalidParameters" reason.
+    ///
+    /// Support: Implementation-specific
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters_ref: Option<LocalParametersReference>,
+}
+
+/// LocalParametersReference identifies an API object containing
+/// controller-specific configuration resource within the namespace.
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct LocalParametersReference {
+    /// Group is the group of the referent.
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
+    pub group: Group,
+
+    /// Kind is kind of the referent.
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
+    pub kind: Kind,
+
+    /// Name is the name of the referent.
+    #[schemars(length(min = 1, max = 253))]
+    pub name: String,
+}
+
+/// GatewayBackendTLS describes backend TLS configuration for a Gateway.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayBackendTls {
+    /// ClientCertificateRef is a reference to an object that contains a Client
+    /// Certificate and the associated private key.
+    ///
+    /// References to a resource in different namespace are invalid UNLESS
+    /// there is a ReferenceGrant in the target namespace that allows the
+    /// certificate to be attached. If a ReferenceGrant does not allow this
+    /// reference, the "ResolvedRefs" condition MUST be set to False for this
+    /// listener with the "RefNotPermitted" reason.
+    ///
+    /// ClientCertificateRef can reference to standard Kubernetes resources,
+    /// i.e. Secret, or implementation-specific custom resources.
+    ///
+    /// This setting can be overridden on the service level by use of
+    /// BackendTLSPolicy.
+    ///
+    /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_certificate_ref: Option<SecretObjectReference>,
+}
+
+/// AllowedListeners defines which ListenerSets can be attached to a Gateway.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct AllowedListeners {
+    /// Namespaces defines which namespaces ListenerSets can be attached to
+    /// this Gateway. While this feature is experimental, the default value is
+    /// to allow no ListenerSets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(default = "crate::schema::no_listener_namespaces")]
+    pub namespaces: Option<ListenerNamespaces>,
+}
+
+/// ListenerNamespaces indicate which namespaces ListenerSets should be
+/// selected from.
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct ListenerNamespaces {
+    /// From indicates where ListenerSets can attach to this Gateway. Possible
+    /// values are:
+    ///
+    /// * Same: Only ListenerSets in the same namespace may be attached to this
+    ///   Gateway.
+    /// * Selector: ListenerSets in namespaces selected by the selector may be
+    ///   attached to this Gateway.
+    /// * All: ListenerSets in all namespaces may be attached to this Gateway.
+    /// * None: Only listeners defined in the Gateway's spec are allowed.
+    ///
+    /// While this feature is experimental, the default value None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::none",
+        schema_with = "crate::schema::listener_from_namespaces"
+    )]
+    pub from: Option<FromNamespaces>,
+
+    /// Selector must be specified when From is set to "Selector". In that
+    /// case, only ListenerSets in Namespaces matching this Selector will be
+    /// selected by this Gateway. This field is ignored for other values of
+    /// "From".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<metav1::LabelSelector>,
 }
 
 /// Listener embodies the concept of a logical endpoint where a Gateway accepts
@@ -93,6 +288,7 @@ pub struct Listener {
     /// Gateway.
     ///
     /// Support: Core
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::SECTION_NAME")]
     pub name: String,
 
     /// Hostname specifies the virtual hostname to match for protocol types that
@@ -117,15 +313,19 @@ pub struct Listener {
     /// documentation.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::HOSTNAME")]
     pub hostname: Option<Hostname>,
 
     /// Port is the network port. Multiple listeners may use the same port,
     /// subject to the Listener compatibility rules.
+    #[schemars(range(min = 1, max = 65535))]
     pub port: PortNumber,
 
     /// Protocol specifies the network protocol this listener expects to receive.
     ///
     /// Support: Core
+    #[schemars(length(min = 1, max = 255), regex = "crate::schema::PROTOCOL")]
     pub protocol: ProtocolType,
 
     /// TLS is the TLS configuration for the Listener. This field is required if
@@ -139,6 +339,7 @@ pub struct Listener {
     /// certificates for any TLS handshake.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tls: Option<GatewayTlsConfig>,
 
     /// AllowedRoutes defines the types of routes that MAY be attached to a
@@ -165,53 +366,80 @@ pub struct Listener {
     /// of the rules within that Route should still be supported.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allowed_routes: Option<AllowedRoutes>,
 }
 
-/// ProtocolType defines the application protocol accepted by a Listener.
-/// Implementations are not required to accept all the defined protocols.
-/// If an implementation does not support a specified protocol, it
-/// should raise a "Detached" condition for the affected Listener with
-/// a reason of "UnsupportedProtocol".
-///
-/// Core ProtocolType values are listed in the table below.
-///
-/// Implementations can define their own protocols if a core ProtocolType does not
-/// exist. Such definitions must use prefixed name, such as
-/// `mycompany.com/my-custom-protocol`. Un-prefixed names are reserved for core
-/// protocols. Any protocol defined by implementations will fall under custom
-/// conformance.
-///
-/// Valid values include:
-///
-/// * "HTTP" - Core support
-/// * "example.com/bar" - Implementation-specific support
-///
-/// Invalid values include:
-///
-/// * "example.com" - must include path if domain is used
-/// * "foo.example.com" - must include path if domain is used
-///
-pub type ProtocolType = String;
+string_enum! {
+    /// ProtocolType defines the application protocol accepted by a Listener.
+    /// Implementations are not required to accept all the defined protocols.
+    /// If an implementation does not support a specified protocol, it
+    /// should raise a "Detached" condition for the affected Listener with
+    /// a reason of "UnsupportedProtocol".
+    ///
+    /// Core ProtocolType values are listed in the table below.
+    ///
+    /// Implementations can define their own protocols if a core ProtocolType
+    /// does not exist. Such definitions must use prefixed name, such as
+    /// `mycompany.com/my-custom-protocol`. Un-prefixed names are reserved for
+    /// core protocols. Any protocol defined by implementations will fall under
+    /// custom conformance.
+    ///
+    /// Valid values include:
+    ///
+    /// * "HTTP" - Core support
+    /// * "example.com/bar" - Implementation-specific support
+    ///
+    /// Invalid values include:
+    ///
+    /// * "example.com" - must include path if domain is used
+    /// * "foo.example.com" - must include path if domain is used
+    ///
+    /// Implementation-specific protocols are represented by `Other`.
+    pub enum ProtocolType {
+        /// Accepts cleartext HTTP/1.1 sessions over TCP. Implementations MAY
+        /// also support HTTP/2 over cleartext.
+        Http = "HTTP",
+
+        /// Accepts HTTP/1.1 or HTTP/2 sessions over TLS.
+        Https = "HTTPS",
+
+        /// Accepts TLS sessions over TCP.
+        Tls = "TLS",
+
+        /// Accepts TCP sessions.
+        Tcp = "TCP",
+
+        /// Accepts UDP packets.
+        Udp = "UDP",
+    }
+}
 
 /// GatewayTLSConfig describes a TLS configuration.
 #[derive(
-    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
 )]
 #[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("self.mode == 'Terminate' ? size(self.certificateRefs) > 0 || size(self.options) > 0 : true").message("certificateRefs or options must be specified when mode is Terminate"))]
 pub struct GatewayTlsConfig {
     /// Mode defines the TLS behavior for the TLS session initiated by the
     /// client.  There are two possible modes:
     ///
     /// - Terminate: The TLS session between the downstream client and the
     ///   Gateway is terminated at the Gateway. This mode requires
-    ///   certificateRefs to be set and contain at least one element.
+    ///   certificateRefs or options to be set and contain at least one
+    ///   element.
     /// - Passthrough: The TLS session is NOT terminated by the Gateway. This
     ///   implies that the Gateway can't decipher the TLS stream except for the
     ///   ClientHello message of the TLS protocol. CertificateRefs field is
     ///   ignored in this mode.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::terminate",
+        schema_with = "crate::schema::tls_mode_type"
+    )]
     pub mode: Option<TlsModeType>,
 
     /// CertificateRefs contains a series of references to Kubernetes objects
@@ -240,6 +468,8 @@ pub struct GatewayTlsConfig {
     ///
     /// Support: Implementation-specific (More than one reference or other
     /// resource types)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 64))]
     pub certificate_refs: Option<Vec<SecretObjectReference>>,
 
     /// Options are a list of key/value pairs to enable extended TLS
@@ -252,11 +482,74 @@ pub struct GatewayTlsConfig {
     /// Un-prefixed names are reserved for key names defined by Gateway API.
     ///
     /// Support: Implementation-specific
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::schema::tls_options")]
     pub options: Option<BTreeMap<String, String>>,
+
+    /// FrontendValidation holds configuration information for validating the
+    /// frontend (client). Setting this field will require clients to send a
+    /// client certificate required for validation during the TLS handshake.
+    /// In browsers this may result in a dialog appearing that requests a user
+    /// to specify the client certificate. The maximum depth of a certificate
+    /// chain accepted in verification is Implementation specific.
+    ///
+    /// Support: Extended
+    ///
+    /// gateway:experimental
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frontend_validation: Option<FrontendTlsValidation>,
 }
 
-/// TLSModeType type defines how a Gateway handles TLS sessions.
-pub type TlsModeType = String;
+/// FrontendTLSValidation holds configuration information that can be used to
+/// validate the frontend initiating the TLS connection.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontendTlsValidation {
+    /// CACertificateRefs contains one or more references to Kubernetes objects
+    /// that contain TLS certificates of the Certificate Authorities that can
+    /// be used as a trust anchor to validate the certificates presented by the
+    /// client.
+    ///
+    /// A single CA certificate reference to a Kubernetes ConfigMap has "Core"
+    /// support. Implementations MAY choose to support attaching multiple CA
+    /// certificates to a Listener, but this behavior is
+    /// implementation-specific.
+    ///
+    /// Support: Core - A single reference to a Kubernetes ConfigMap with the
+    /// CA certificate in a key named `ca.crt`.
+    ///
+    /// Support: Implementation-specific (More than one reference, or other
+    /// kinds of resources).
+    ///
+    /// References to a resource in a different namespace are invalid UNLESS
+    /// there is a ReferenceGrant in the target namespace that allows the
+    /// certificate to be attached. If a ReferenceGrant does not allow this
+    /// reference, the "ResolvedRefs" condition MUST be set to False for this
+    /// listener with the "RefNotPermitted" reason.
+    #[serde(
+        default,
+        rename = "caCertificateRefs",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(length(min = 1, max = 8))]
+    pub ca_certificate_refs: Option<Vec<ObjectReference>>,
+}
+
+string_enum! {
+    /// TLSModeType type defines how a Gateway handles TLS sessions.
+    pub enum TlsModeType {
+        /// In this mode, TLS session between the downstream client and the
+        /// Gateway is terminated at the Gateway.
+        Terminate = "Terminate",
+
+        /// In this mode, the TLS session NOT terminated by the Gateway. This
+        /// implies that the Gateway can't decipher the TLS stream except for
+        /// the ClientHello message of the TLS protocol.
+        Passthrough = "Passthrough",
+    }
+}
 
 /// AllowedRoutes defines which Routes may be attached to this Listener.
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
@@ -267,6 +560,7 @@ pub struct AllowedRoutes {
     /// default.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub namespaces: Option<RouteNamespaces>,
 
     /// Kinds specifies the groups and kinds of Routes that are allowed to bind
@@ -280,12 +574,32 @@ pub struct AllowedRoutes {
     /// Listener with the "InvalidRouteKinds" reason.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 8))]
     pub kinds: Option<Vec<RouteGroupKind>>,
 }
 
-/// FromNamespaces specifies namespace from which Routes may be attached to a
-/// Gateway.
-pub type FromNamespaces = String;
+string_enum! {
+    /// FromNamespaces specifies namespace from which Routes may be attached to
+    /// a Gateway.
+    pub enum FromNamespaces {
+        /// Routes in all namespaces may be attached to this Gateway.
+        All = "All",
+
+        /// Only Routes in namespaces selected by the selector may be attached
+        /// to this Gateway.
+        Selector = "Selector",
+
+        /// Only Routes in the same namespace as the Gateway may be attached to
+        /// this Gateway.
+        Same = "Same",
+
+        /// Only Listeners defined in the Gateway's spec may be attached to
+        /// this Gateway. This value is only valid for a Gateway's
+        /// `allowedListeners`.
+        None = "None",
+    }
+}
 
 /// RouteNamespaces indicate which namespaces Routes should be selected from.
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
@@ -298,6 +612,11 @@ pub struct RouteNamespaces {
     /// * Same: Only Routes in the same namespace may be used by this Gateway.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::same",
+        schema_with = "crate::schema::from_namespaces"
+    )]
     pub from: Option<FromNamespaces>,
 
     /// Selector must be specified when From is set to "Selector". In that case,
@@ -305,6 +624,7 @@ pub struct RouteNamespaces {
     /// Gateway. This field is ignored for other values of "From".
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub selector: Option<metav1::LabelSelector>,
 }
 
@@ -314,24 +634,37 @@ pub struct RouteNamespaces {
 )]
 pub struct RouteGroupKind {
     /// Group is the group of the Route.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::gateway_group",
+        length(max = 253),
+        regex = "crate::schema::GROUP"
+    )]
     pub group: Option<String>,
 
     /// Kind is the kind of the Route.
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
     pub kind: String,
 }
 
 /// GatewayAddress describes an address that can be bound to a Gateway.
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
+#[cel_validate(rule = Rule::new(r#"self.type == 'Hostname' ? self.value.matches(r"""^(\*\.)?[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$"""): true"#).message(r"Hostname value must only contain valid characters (matching ^(\*\.)?[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$)"))]
 pub struct GatewayAddress {
     /// Type of the address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::ip_address",
+        length(min = 1, max = 253),
+        regex = "crate::schema::ADDRESS_TYPE"
+    )]
     pub r#type: Option<AddressType>,
 
     /// Value of the address. The validity of the values will depend on the type
     /// and support by the controller.
     ///
     /// Examples: `1.2.3.4`, `128::1`, `my-ip-address`.
+    #[schemars(length(min = 1, max = 253))]
     pub value: String,
 }
 
@@ -341,6 +674,8 @@ pub struct GatewayStatus {
     /// Addresses lists the IP addresses that have actually been bound to the
     /// Gateway. These addresses may differ from the addresses in the Spec, e.g.
     /// if the Gateway automatically assigns an address from a reserved pool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub addresses: Option<Vec<GatewayAddress>>,
 
     /// Conditions describe the current conditions of the Gateway.
@@ -354,26 +689,105 @@ pub struct GatewayStatus {
     ///
     /// * "Scheduled"
     /// * "Ready"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 8))]
     pub conditions: Option<Vec<metav1::Condition>>,
 
     /// Routes is a list of routes bound to the Gateway.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 64))]
     pub listeners: Option<Vec<ListenerStatus>>,
 }
 
-/// GatewayConditionType is a type of condition associated with a
-/// Gateway. This type should be used with the GatewayStatus.Conditions
-/// field.
-pub type GatewayConditionType = String;
+string_enum! {
+    /// GatewayConditionType is a type of condition associated with a
+    /// Gateway. This type should be used with the GatewayStatus.Conditions
+    /// field.
+    pub enum GatewayConditionType {
+        /// This condition indicates whether a Gateway has generated some
+        /// configuration that is assumed to be ready soon in the underlying
+        /// data plane.
+        Programmed = "Programmed",
+
+        /// This condition is true when the controller managing the Gateway is
+        /// syntactically and semantically valid enough to produce some
+        /// configuration in the underlying data plane. This does not indicate
+        /// whether or not the configuration has been propagated to the data
+        /// plane.
+        Accepted = "Accepted",
+
+        /// This condition indicates whether a Gateway has generated some
+        /// configuration that will soon be ready in the underlying data plane.
+        ///
+        /// This is a reserved condition type for future use.
+        Ready = "Ready",
+    }
+}
 
-/// GatewayConditionReason defines the set of reasons that explain why a
-/// particular Gateway condition type has been raised.
-pub type GatewayConditionReason = String;
+string_enum! {
+    /// GatewayConditionReason defines the set of reasons that explain why a
+    /// particular Gateway condition type has been raised.
+    pub enum GatewayConditionReason {
+        /// This reason is used with the "Programmed" condition when the
+        /// condition is true.
+        Programmed = "Programmed",
+
+        /// This reason is used with the "Programmed" and "Accepted" conditions
+        /// when the Gateway is syntactically or semantically invalid.
+        Invalid = "Invalid",
+
+        /// This reason is used with the "Programmed" condition when the
+        /// Gateway is not scheduled because insufficient infrastructure
+        /// resources are available.
+        NoResources = "NoResources",
+
+        /// This reason is used with the "Programmed" condition when none of
+        /// the requested addresses have been assigned to the Gateway.
+        AddressNotAssigned = "AddressNotAssigned",
+
+        /// This reason is used with the "Programmed" condition when the
+        /// associated Gateway has an assigned address that is not usable.
+        AddressNotUsable = "AddressNotUsable",
+
+        /// This reason is used with the "Accepted" condition when the
+        /// condition is true.
+        Accepted = "Accepted",
+
+        /// This reason is used with the "Accepted" condition to indicate that
+        /// the Gateway could not be accepted because one or more Listeners
+        /// was not valid.
+        ListenersNotValid = "ListenersNotValid",
+
+        /// This reason is used with the "Accepted" and "Programmed" conditions
+        /// when the status is "Unknown" and no controller has reconciled the
+        /// Gateway.
+        Pending = "Pending",
+
+        /// This reason is used with the "Accepted" condition when the Gateway
+        /// requests an address type that the implementation does not support.
+        UnsupportedAddress = "UnsupportedAddress",
+
+        /// This reason is used with the "Accepted" condition when the
+        /// Gateway's infrastructure parameters reference cannot be resolved
+        /// or is invalid.
+        InvalidParameters = "InvalidParameters",
+
+        /// This reason is used with the "Ready" condition when the condition
+        /// is true.
+        Ready = "Ready",
+
+        /// This reason is used with the "Ready" condition when one or more
+        /// Listeners are not ready to serve traffic.
+        ListenersNotReady = "ListenersNotReady",
+    }
+}
 
 /// ListenerStatus is the status associated with a Listener.
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ListenerStatus {
     /// Name is the name of the Listener that this status corresponds to.
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::SECTION_NAME")]
     pub name: SectionName,
 
     /// SupportedKinds is the list indicating the Kinds supported by this
@@ -385,6 +799,7 @@ pub struct ListenerStatus {
     /// condition to "False" with the "InvalidRouteKinds" reason. If both valid
     /// and invalid Route kinds are specified, the implementation MUST reference
     /// the valid Route kinds that have been specified.
+    #[schemars(length(max = 8))]
     pub supported_kinds: Vec<RouteGroupKind>,
 
     /// AttachedRoutes represents the total number of Routes that have been
@@ -392,13 +807,119 @@ pub struct ListenerStatus {
     pub attached_routes: u16,
 
     /// Conditions describe the current condition of this listener.
+    #[schemars(length(max = 8))]
     pub conditions: Vec<metav1::Condition>,
 }
 
-/// ListenerConditionType is a type of condition associated with the listener.
-/// This type should be used with the ListenerStatus.Conditions field.
-pub type ListenerConditionType = String;
+string_enum! {
+    /// ListenerConditionType is a type of condition associated with the
+    /// listener. This type should be used with the ListenerStatus.Conditions
+    /// field.
+    pub enum ListenerConditionType {
+        /// This condition indicates that the controller was unable to resolve
+        /// conflicting specification requirements for this Listener. If a
+        /// Listener is conflicted, its network port should not be configured
+        /// on any network elements.
+        Conflicted = "Conflicted",
+
+        /// This condition indicates that the listener is syntactically and
+        /// semantically valid, and that all features used in the listener's
+        /// spec are supported.
+        Accepted = "Accepted",
+
+        /// This condition indicates whether the controller was able to resolve
+        /// all the object references for the Listener.
+        ResolvedRefs = "ResolvedRefs",
+
+        /// This condition indicates whether a Listener has generated some
+        /// configuration that will soon be ready in the underlying data plane.
+        Programmed = "Programmed",
+
+        /// This condition indicates whether a Listener has generated some
+        /// configuration that will soon be ready in the underlying data plane.
+        ///
+        /// This is a reserved condition type for future use.
+        Ready = "Ready",
+    }
+}
 
-/// ListenerConditionReason defines the set of reasons that explain why a
-/// particular Listener condition type has been raised.
-pub type ListenerConditionReason = String;
+string_enum! {
+    /// ListenerConditionReason defines the set of reasons that explain why a
+    /// particular Listener condition type has been raised.
+    pub enum ListenerConditionReason {
+        /// This reason is used with the "Conflicted" condition when the
+        /// Listener conflicts with hostnames in other Listeners.
+        HostnameConflict = "HostnameConflict",
+
+        /// This reason is used with the "Conflicted" condition when multiple
+        /// Listeners are specified with the same Listener port number, but
+        /// have conflicting protocol specifications.
+        ProtocolConflict = "ProtocolConflict",
+
+        /// This reason is used with the "Conflicted" condition when the
+        /// condition is False.
+        NoConflicts = "NoConflicts",
+
+        /// This reason is used with the "Accepted" condition when the
+        /// condition is True.
+        Accepted = "Accepted",
+
+        /// This reason is used with the "Accepted" condition when the Listener
+        /// requests a port that cannot be used on the Gateway.
+        PortUnavailable = "PortUnavailable",
+
+        /// This reason is used with the "Accepted" condition when the Listener
+        /// could not be attached to be Gateway because its protocol type is
+        /// not supported.
+        UnsupportedProtocol = "UnsupportedProtocol",
+
+        /// This reason is used with the "ResolvedRefs" condition when the
+        /// condition is true.
+        ResolvedRefs = "ResolvedRefs",
+
+        /// This reason is used with the "ResolvedRefs" condition when the
+        /// Listener has a TLS configuration with at least one TLS
+        /// CertificateRef that is invalid or does not exist.
+        InvalidCertificateRef = "InvalidCertificateRef",
+
+        /// This reason is used with the "ResolvedRefs" condition when an
+        /// invalid or unsupported Route kind is specified by the Listener.
+        InvalidRouteKinds = "InvalidRouteKinds",
+
+        /// This reason is used with the "ResolvedRefs" condition when the
+        /// Listener has a TLS configuration that references an object in
+        /// another namespace, where the object in the other namespace does
+        /// not have a ReferenceGrant explicitly allowing the reference.
+        RefNotPermitted = "RefNotPermitted",
+
+        /// This reason is used with the "Programmed" condition when the
+        /// condition is true.
+        Programmed = "Programmed",
+
+        /// This reason is used with the "Ready" and "Programmed" conditions
+        /// when the Listener is syntactically or semantically invalid.
+        Invalid = "Invalid",
+
+        /// This reason is used with the "Accepted", "Ready" and "Programmed"
+        /// conditions when the Listener is either not yet reconciled or not
+        /// yet not online and ready to accept client traffic.
+        Pending = "Pending",
+
+        /// This reason is used with the "Ready" condition when the condition
+        /// is true.
+        Ready = "Ready",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn omits_unset_address_type() {
+        let raw = serde_json::json!({ "value": "10.0.0.1" });
+        let address = serde_json::from_value::<GatewayAddress>(raw.clone()).unwrap();
+        assert_eq!(address.r#type, None);
+        assert_eq!(serde_json::to_value(&address).unwrap(), raw);
+    }
+}
@@ -19,7 +19,7 @@ use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 //
 // GatewayClass is a Cluster level resource.
 #[derive(
-    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, kube::CELSchema,
 )]
 #[kube(
     group = "gateway.networking.k8s.io",
@@ -35,6 +35,11 @@ pub struct GatewayClassSpec {
     /// Example: "example.net/gateway-controller".
     ///
     /// This field is not mutable and cannot be empty.
+    #[schemars(
+        length(min = 1, max = 253),
+        regex = "crate::schema::GATEWAY_CONTROLLER"
+    )]
+    #[cel_validate(rule = Rule::new("self == oldSelf").message("Value is immutable"))]
     pub controller_name: GatewayController,
 
     /// ParametersRef is a reference to a resource that contains the
@@ -50,9 +55,12 @@ pub struct GatewayClassSpec {
     /// status condition will be true.
     ///
     /// Support: Custom
-    pub paramters_ref: Option<ParametersReference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters_ref: Option<ParametersReference>,
 
     /// Description helps describe a GatewayClass with more details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 64))]
     pub description: Option<String>,
 }
 
@@ -63,29 +71,80 @@ pub struct GatewayClassSpec {
 )]
 pub struct ParametersReference {
     /// Group is the group of the referent.
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
     pub group: Group,
 
     /// Kind is the kind of the referent.
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
     pub kind: Kind,
 
     /// Name is the name of the referent.
+    #[schemars(length(min = 1, max = 253))]
     pub name: String,
 
     /// Namespace is the namespace of the referent.
     ///
     /// This field is required when referring to a Namespace-scoped resource and
     /// MUST be unset when referring to a Cluster-scoped resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::NAMESPACE")]
     pub namespace: Option<String>,
 }
 
-/// GatewayClassConditionType is the type for status conditions on
-/// Gateway resources. This type should be used with the
-/// GatewayClassStatus.Conditions field.
-pub type GatewayClassConditionType = String;
+string_enum! {
+    /// GatewayClassConditionType is the type for status conditions on
+    /// Gateway resources. This type should be used with the
+    /// GatewayClassStatus.Conditions field.
+    pub enum GatewayClassConditionType {
+        /// This condition indicates whether the GatewayClass has been accepted
+        /// by the controller requested in the `spec.controller` field.
+        ///
+        /// This condition defaults to Unknown, and MUST be set by a controller
+        /// when it sees a GatewayClass using its controller string.
+        Accepted = "Accepted",
 
-/// GatewayClassConditionReason defines the set of reasons that explain why a
-/// particular GatewayClass condition type has been raised.
-pub type GatewayClassConditionReason = String;
+        /// This condition indicates whether the GatewayClass supports the
+        /// version(s) of Gateway API CRDs present in the cluster.
+        SupportedVersion = "SupportedVersion",
+    }
+}
+
+string_enum! {
+    /// GatewayClassConditionReason defines the set of reasons that explain
+    /// why a particular GatewayClass condition type has been raised.
+    pub enum GatewayClassConditionReason {
+        /// This reason is used with the "Accepted" condition when the
+        /// condition is true.
+        Accepted = "Accepted",
+
+        /// This reason is used with the "Accepted" condition when the
+        /// GatewayClass was not accepted because the parametersRef field
+        /// refers to a nonexistent or unsupported resource or kind, or when
+        /// the data within that resource is malformed.
+        InvalidParameters = "InvalidParameters",
+
+        /// This reason is used with the "Accepted" condition when the
+        /// requested controller has not yet made a decision about whether to
+        /// admit the GatewayClass.
+        Pending = "Pending",
+
+        /// This reason is used with the "Accepted" condition when the
+        /// GatewayClass was not accepted because the implementation does not
+        /// support a user-defined GatewayClass.
+        Unsupported = "Unsupported",
+
+        /// This reason is used with the "SupportedVersion" condition when the
+        /// condition is true.
+        SupportedVersion = "SupportedVersion",
+
+        /// This reason is used with the "SupportedVersion" or "Accepted"
+        /// condition when the condition is false. A message SHOULD be
+        /// included in this condition that includes the detected CRD
+        /// version(s) present in the cluster and the CRD version(s) that are
+        /// supported by the GatewayClass.
+        UnsupportedVersion = "UnsupportedVersion",
+    }
+}
 
 /// GatewayClassStatus is the current status for the GatewayClass.
 #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
@@ -95,5 +154,7 @@ pub struct GatewayClassStatus {
     ///
     /// Controllers should prefer to publish conditions using values of
     /// GatewayClassConditionType for the type of each Condition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 8))]
     pub conditions: Option<Vec<metav1::Condition>>,
 }
@@ -0,0 +1,347 @@
+use crate::validate::formats;
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+/// Hostname is the fully qualified domain name of a network host. This matches
+/// the RFC 1123 definition of a hostname with 2 notable exceptions:
+///
+/// 1. IPs are not allowed.
+/// 2. A hostname may be prefixed with a wildcard label (`*.`). The wildcard
+///    label must appear by itself as the first label.
+///
+/// Hostname can be "precise" which is a domain name without the terminating dot
+/// of a network host (e.g. "foo.example.com") or "wildcard", which is a domain
+/// name prefixed with a single wildcard label (e.g. `*.example.com`).
+///
+/// Note that as per RFC1035 and RFC1123, a *label* must consist of lower case
+/// alphanumeric characters or '-', and must start and end with an alphanumeric
+/// character. No other punctuation is allowed.
+///
+/// Hostnames are validated when they are parsed, but not when they are
+/// deserialized, so that resources written by other clients can always be
+/// read; use [`Validate`](crate::Validate) to check deserialized resources.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[serde(transparent)]
+pub struct Hostname(String);
+
+/// PreciseHostname is the fully qualified domain name of a network host. This
+/// matches the RFC 1123 definition of a hostname with 1 notable exception that
+/// numeric IP addresses are not allowed.
+///
+/// Note that as per RFC1035 and RFC1123, a *label* must consist of lower case
+/// alphanumeric characters or '-', and must start and end with an alphanumeric
+/// character. No other punctuation is allowed.
+///
+/// Like [`Hostname`], precise hostnames are validated when they are parsed but
+/// not when they are deserialized.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[serde(transparent)]
+pub struct PreciseHostname(String);
+
+/// Indicates that a string is not a valid [`Hostname`] or [`PreciseHostname`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidHostname(String);
+
+// === impl Hostname ===
+
+impl Hostname {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Wraps a value without validating it, so that it is reported by
+    /// [`Validate`](crate::Validate) rather than when it is constructed.
+    pub(crate) fn new_unchecked(s: impl Into<String>) -> Self {
+        Self(s.into())
+    }
+
+    /// Returns true if the hostname is prefixed with a wildcard label.
+    pub fn is_wildcard(&self) -> bool {
+        self.0.starts_with("*.")
+    }
+
+    /// Returns true if `host` is matched by this hostname: either `host` is
+    /// equal to a precise hostname, or it has a wildcard hostname's suffix
+    /// preceded by at least one label. Hosts are compared case-insensitively.
+    pub fn matches(&self, host: &str) -> bool {
+        match self.0.strip_prefix('*') {
+            Some(suffix) => {
+                host.len() > suffix.len()
+                    && host.is_char_boundary(host.len() - suffix.len())
+                    && host[host.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+            }
+            None => host.eq_ignore_ascii_case(&self.0),
+        }
+    }
+
+    /// Returns the most specific hostname matched by both hostnames, if any.
+    ///
+    /// Two equal hostnames intersect as themselves; a wildcard hostname and a
+    /// hostname that it covers (e.g. `*.example.com` and `foo.example.com` or
+    /// `*.foo.example.com`) intersect as the more specific of the two.
+    pub fn intersect(&self, other: &Hostname) -> Option<Hostname> {
+        if self == other || self.covers(other) {
+            Some(other.clone())
+        } else if other.covers(self) {
+            Some(self.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Orders hostnames by specificity, so that `Greater` is returned when
+    /// this hostname is more specific than `other`: precise hostnames are more
+    /// specific than wildcard hostnames, and longer hostnames are more specific
+    /// than shorter ones.
+    pub fn cmp_specificity(&self, other: &Hostname) -> Ordering {
+        let key = |h: &Hostname| (!h.is_wildcard(), h.0.len());
+        key(self).cmp(&key(other))
+    }
+
+    /// Returns true if this is a wildcard hostname that matches every hostname
+    /// matched by `other`.
+    fn covers(&self, other: &Hostname) -> bool {
+        match other.0.strip_prefix("*.") {
+            Some(other) if self.is_wildcard() => self.matches(other),
+            Some(_) => false,
+            None => self.is_wildcard() && self.matches(&other.0),
+        }
+    }
+}
+
+impl FromStr for Hostname {
+    type Err = InvalidHostname;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.to_string().try_into()
+    }
+}
+
+impl TryFrom<String> for Hostname {
+    type Error = InvalidHostname;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.len() > 253 || !formats::is_hostname(&s) {
+            return Err(InvalidHostname(s));
+        }
+        Ok(Self(s))
+    }
+}
+
+impl From<PreciseHostname> for Hostname {
+    fn from(PreciseHostname(s): PreciseHostname) -> Self {
+        Self(s)
+    }
+}
+
+impl From<Hostname> for String {
+    fn from(Hostname(s): Hostname) -> Self {
+        s
+    }
+}
+
+impl std::ops::Deref for Hostname {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Hostname {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Hostname {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Hostname {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for Hostname {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// === impl PreciseHostname ===
+
+impl PreciseHostname {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Returns true if `host` is equal to this hostname, compared
+    /// case-insensitively.
+    pub fn matches(&self, host: &str) -> bool {
+        host.eq_ignore_ascii_case(&self.0)
+    }
+}
+
+impl FromStr for PreciseHostname {
+    type Err = InvalidHostname;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.to_string().try_into()
+    }
+}
+
+impl TryFrom<String> for PreciseHostname {
+    type Error = InvalidHostname;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.len() > 253 || !formats::is_dns_subdomain(&s) {
+            return Err(InvalidHostname(s));
+        }
+        Ok(Self(s))
+    }
+}
+
+impl From<PreciseHostname> for String {
+    fn from(PreciseHostname(s): PreciseHostname) -> Self {
+        s
+    }
+}
+
+impl std::ops::Deref for PreciseHostname {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for PreciseHostname {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for PreciseHostname {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for PreciseHostname {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for PreciseHostname {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// === impl InvalidHostname ===
+
+impl fmt::Display for InvalidHostname {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hostname: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidHostname {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hostname(s: &str) -> Hostname {
+        s.parse().expect("hostname must be valid")
+    }
+
+    #[test]
+    fn parses() {
+        assert!("*.example.com".parse::<Hostname>().is_ok());
+        assert!("*.example.com".parse::<PreciseHostname>().is_err());
+        assert!("Example.com".parse::<Hostname>().is_err());
+        assert!("foo.*.com".parse::<Hostname>().is_err());
+        assert_eq!(
+            serde_json::from_str::<Hostname>(r#""Not A Hostname""#).unwrap(),
+            "Not A Hostname"
+        );
+        assert_eq!(
+            serde_json::to_string(&hostname("example.com")).unwrap(),
+            r#""example.com""#
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let wildcard = hostname("*.example.com");
+        assert!(wildcard.matches("foo.example.com"));
+        assert!(wildcard.matches("foo.bar.Example.COM"));
+        assert!(!wildcard.matches("example.com"));
+        assert!(!wildcard.matches("fooexample.com"));
+        assert!(hostname("example.com").matches("EXAMPLE.com"));
+    }
+
+    #[test]
+    fn intersects() {
+        let intersect = |a: &str, b: &str| hostname(a).intersect(&hostname(b));
+        assert_eq!(
+            intersect("*.example.com", "foo.example.com"),
+            Some(hostname("foo.example.com"))
+        );
+        assert_eq!(
+            intersect("*.foo.example.com", "*.example.com"),
+            Some(hostname("*.foo.example.com"))
+        );
+        assert_eq!(
+            intersect("example.com", "example.com"),
+            Some(hostname("example.com"))
+        );
+        assert_eq!(intersect("*.example.com", "example.com"), None);
+        assert_eq!(intersect("foo.example.com", "bar.example.com"), None);
+    }
+
+    #[test]
+    fn specificity() {
+        let mut hostnames = ["*.example.com", "a.example.com", "*.foo.example.com"].map(hostname);
+        hostnames.sort_by(|a, b| b.cmp_specificity(a));
+        assert_eq!(
+            hostnames,
+            ["a.example.com", "*.foo.example.com", "*.example.com"].map(hostname)
+        );
+    }
+}
@@ -59,9 +59,16 @@ pub struct HttpRouteSpec {
     /// `False` in the corresponding RouteParentStatus.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        length(max = 16),
+        inner(length(min = 1, max = 253), regex = "crate::schema::HOSTNAME")
+    )]
     pub hostnames: Option<Vec<Hostname>>,
 
     /// Rules are a list of HTTP matchers, filters and actions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub rules: Option<Vec<HttpRouteRule>>,
 }
 
@@ -69,10 +76,23 @@ pub struct HttpRouteSpec {
 /// conditions (matches), processing it (filters), and forwarding the request to
 /// an API object (backendRefs).
 #[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
 )]
 #[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("(has(self.backendRefs) && size(self.backendRefs) > 0) ? (!has(self.filters) || self.filters.all(f, !has(f.requestRedirect))): true").message("RequestRedirect filter must not be used together with backendRefs"))]
+#[cel_validate(rule = Rule::new(REDIRECT_PREFIX_MATCH).message("When using RequestRedirect filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified"))]
+#[cel_validate(rule = Rule::new(REWRITE_PREFIX_MATCH).message("When using URLRewrite filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified"))]
+#[cel_validate(rule = Rule::new(BACKEND_REDIRECT_PREFIX_MATCH).message("Within backendRefs, when using RequestRedirect filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified"))]
+#[cel_validate(rule = Rule::new(BACKEND_REWRITE_PREFIX_MATCH).message("Within backendRefs, When using URLRewrite filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified"))]
 pub struct HttpRouteRule {
+    /// Name is the name of the route rule. This name MUST be unique within a
+    /// Route if it is set.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::SECTION_NAME")]
+    pub name: Option<SectionName>,
+
     /// Matches define conditions used for matching the rule against incoming
     /// HTTP requests. Each match is independent, i.e. this rule will be matched
     /// if **any** one of the matches is satisfied.
@@ -127,6 +147,8 @@ pub struct HttpRouteRule {
     ///
     /// When no rules matching a request have been successfully attached to the
     /// parent a request is coming from, a HTTP 404 status code MUST be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 8))]
     pub matches: Option<Vec<HttpRouteMatch>>,
 
     /// Filters define the filters that are applied to requests that match this
@@ -147,6 +169,13 @@ pub struct HttpRouteRule {
     /// conformance.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
+    #[cel_validate(rule = Rule::new("!(self.exists(f, f.type == 'RequestRedirect') && self.exists(f, f.type == 'URLRewrite'))").message("May specify either httpRouteFilterRequestRedirect or httpRouteFilterRequestRewrite, but not both"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'RequestHeaderModifier').size() <= 1").message("RequestHeaderModifier filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'ResponseHeaderModifier').size() <= 1").message("ResponseHeaderModifier filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'RequestRedirect').size() <= 1").message("RequestRedirect filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'URLRewrite').size() <= 1").message("URLRewrite filter cannot be repeated"))]
     pub filters: Option<Vec<HttpRouteFilter>>,
 
     /// BackendRefs defines the backend(s) where matching requests should be
@@ -175,9 +204,178 @@ pub struct HttpRouteRule {
     /// Support: Custom for any other resource
     ///
     /// Support for weight: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub backend_refs: Option<Vec<HttpBackendRef>>,
+
+    /// Timeouts defines the timeouts that can be configured for an HTTP
+    /// request.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeouts: Option<HttpRouteTimeouts>,
+
+    /// Retry defines the configuration for when to retry an HTTP request.
+    ///
+    /// Support: Extended
+    ///
+    /// gateway:experimental
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<HttpRouteRetry>,
+
+    /// SessionPersistence defines and configures session persistence for the
+    /// route rule.
+    ///
+    /// Support: Extended
+    ///
+    /// gateway:experimental
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_persistence: Option<SessionPersistence>,
+}
+
+/// A rule that replaces a prefix match must have exactly one PathPrefix match.
+macro_rules! single_prefix_match {
+    ($filters:literal) => {
+        concat!(
+            $filters,
+            " ? ((size(self.matches) != 1 || !has(self.matches[0].path) || self.matches[0].path.type != 'PathPrefix') ? false : true) : true",
+        )
+    };
+}
+
+const REDIRECT_PREFIX_MATCH: &str = single_prefix_match!("(has(self.filters) && self.filters.exists_one(f, has(f.requestRedirect) && has(f.requestRedirect.path) && f.requestRedirect.path.type == 'ReplacePrefixMatch' && has(f.requestRedirect.path.replacePrefixMatch)))");
+
+const REWRITE_PREFIX_MATCH: &str = single_prefix_match!("(has(self.filters) && self.filters.exists_one(f, has(f.urlRewrite) && has(f.urlRewrite.path) && f.urlRewrite.path.type == 'ReplacePrefixMatch' && has(f.urlRewrite.path.replacePrefixMatch)))");
+
+const BACKEND_REDIRECT_PREFIX_MATCH: &str = single_prefix_match!("(has(self.backendRefs) && self.backendRefs.exists_one(b, (has(b.filters) && b.filters.exists_one(f, has(f.requestRedirect) && has(f.requestRedirect.path) && f.requestRedirect.path.type == 'ReplacePrefixMatch' && has(f.requestRedirect.path.replacePrefixMatch))) ))");
+
+const BACKEND_REWRITE_PREFIX_MATCH: &str = single_prefix_match!("(has(self.backendRefs) && self.backendRefs.exists_one(b, (has(b.filters) && b.filters.exists_one(f, has(f.urlRewrite) && has(f.urlRewrite.path) && f.urlRewrite.path.type == 'ReplacePrefixMatch' && has(f.urlRewrite.path.replacePrefixMatch))) ))");
+
+/// HTTPRouteTimeouts defines timeouts that can be configured for an HTTPRoute.
+/// Timeout values are represented with Gateway API Duration formatting.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("!(has(self.request) && has(self.backendRequest) && duration(self.request) != duration('0s') && duration(self.backendRequest) > duration(self.request))").message("backendRequest timeout cannot be longer than request timeout"))]
+pub struct HttpRouteTimeouts {
+    /// Request specifies the maximum duration for a gateway to respond to an
+    /// HTTP request. If the gateway has not been able to respond before this
+    /// deadline is met, the gateway MUST return a timeout error.
+    ///
+    /// For example, setting the `rules.timeouts.request` field to the value
+    /// `10s` in an `HTTPRoute` will cause a timeout if a client request is
+    /// taking longer than 10 seconds to complete.
+    ///
+    /// Setting a timeout to the zero duration (e.g. "0s") SHOULD disable the
+    /// timeout completely. Implementations that cannot completely disable the
+    /// timeout MUST instead interpret the zero duration as the longest possible
+    /// value to which the timeout can be set.
+    ///
+    /// This timeout is intended to cover as close to the whole request-response
+    /// transaction as possible although an implementation MAY choose to start
+    /// the timeout after the entire request stream has been received instead
+    /// of immediately after the transaction is initiated by the client.
+    ///
+    /// When this field is unspecified, request timeout behavior is
+    /// implementation-specific.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "crate::schema::DURATION")]
+    pub request: Option<Duration>,
+
+    /// BackendRequest specifies a timeout for an individual request from the
+    /// gateway to a backend. This covers the time from when the request first
+    /// starts being sent from the gateway to when the full response has been
+    /// received from the backend.
+    ///
+    /// Setting a timeout to the zero duration (e.g. "0s") SHOULD disable the
+    /// timeout completely. Implementations that cannot completely disable the
+    /// timeout MUST instead interpret the zero duration as the longest possible
+    /// value to which the timeout can be set.
+    ///
+    /// An entire client HTTP transaction with a gateway, covered by the
+    /// Request timeout, may result in more than one call from the gateway to
+    /// the destination backend, for example, if automatic retries are
+    /// supported.
+    ///
+    /// When this field is unspecified, its behavior is
+    /// implementation-specific; when specified, the value of BackendRequest
+    /// must be no more than the value of the Request timeout (since the
+    /// Request timeout encompasses the BackendRequest timeout).
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "crate::schema::DURATION")]
+    pub backend_request: Option<Duration>,
+}
+
+/// HTTPRouteRetry defines retry configuration for an HTTPRoute.
+///
+/// Implementations SHOULD retry on connection errors (disconnect, reset,
+/// timeout, TCP failure) if a retry stanza is configured.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRouteRetry {
+    /// Codes defines the HTTP response status codes for which a backend
+    /// request should be retried.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(inner(range(min = 400, max = 599)))]
+    pub codes: Option<Vec<HttpRouteRetryStatusCode>>,
+
+    /// Attempts specifies the maximum number of times an individual request
+    /// from the gateway to a backend should be retried.
+    ///
+    /// If the maximum number of retries has been attempted without a
+    /// successful response from the backend, the Gateway MUST return an error.
+    ///
+    /// When this field is unspecified, the number of times to attempt to retry
+    /// a backend request is implementation-specific.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<i32>,
+
+    /// Backoff specifies the minimum duration a Gateway should wait between
+    /// retry attempts and is represented in Gateway API Duration formatting.
+    ///
+    /// For example, setting the `rules[].retry.backoff` field to the value
+    /// `100ms` will cause a backend request to first be retried approximately
+    /// 100 milliseconds after timing out or receiving a response code
+    /// configured to be retryable.
+    ///
+    /// An implementation MAY use an exponential or alternative backoff
+    /// strategy for subsequent retry attempts, MAY cap the maximum backoff
+    /// duration to some amount greater than the specified minimum, and MAY add
+    /// arbitrary jitter to stagger requests, as long as unsuccessful backend
+    /// requests are not retried before the configured minimum duration.
+    ///
+    /// If a Request timeout (`rules[].timeouts.request`) is configured on the
+    /// route, the entire duration of the initial request and any retry attempts
+    /// MUST not exceed the Request timeout duration. If any retry attempts are
+    /// still in progress when the Request timeout duration has been reached,
+    /// these SHOULD be canceled if possible and the Gateway MUST immediately
+    /// return a timeout error.
+    ///
+    /// When this field is unspecified, the time to wait between retry attempts
+    /// is implementation-specific.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "crate::schema::DURATION")]
+    pub backoff: Option<Duration>,
 }
 
+/// HTTPRouteRetryStatusCode defines an HTTP response status code for which a
+/// backend request should be retried. Only codes in the 400-599 range may be
+/// retried.
+pub type HttpRouteRetryStatusCode = u16;
+
 /// HTTPRouteMatch defines the predicate used to match requests to a given
 /// action. Multiple match types are ANDed together, i.e. the match will
 /// evaluate to true only if all conditions are satisfied.
@@ -200,16 +398,21 @@ pub struct HttpRouteRule {
 pub struct HttpRouteMatch {
     /// Path specifies a HTTP request path matcher. If this field is not
     /// specified, a default prefix match on the "/" path is provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<HttpPathMatch>,
 
     /// Headers specifies HTTP request header matchers. Multiple match values
     /// are ANDed together, meaning, a request must match all the specified
     /// headers to select the route.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub headers: Option<Vec<HttpHeaderMatch>>,
 
     /// QueryParams specifies HTTP query parameter matchers. Multiple match
     /// values are ANDed together, meaning, a request must match all the
     /// specified query parameters to select the route.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub query_params: Option<Vec<HttpQueryParamMatch>>,
 
     /// Method specifies HTTP method matcher.
@@ -218,6 +421,8 @@ pub struct HttpRouteMatch {
     /// specified method.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::schema::http_method")]
     pub method: Option<HttpMethod>,
 }
 
@@ -234,16 +439,33 @@ pub struct HttpRouteMatch {
 ///
 /// - Must begin with the `/` character
 /// - Must not contain consecutive `/` characters (e.g. `/foo///`, `//`)
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
-#[serde(tag = "type", rename_all = "PascalCase")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "PascalCase")]
 pub enum HttpPathMatch {
-    Exact { value: String },
-    PathPrefix { value: String },
-    RegularExpression { value: String },
+    Exact {
+        value: String,
+    },
+    PathPrefix {
+        value: String,
+    },
+    RegularExpression {
+        value: String,
+    },
+
+    /// A path match with a type that is not known to this version of the
+    /// library.
+    ///
+    /// `raw` holds the path match as it was read, and is written back
+    /// verbatim.
+    #[serde(skip)]
+    Unknown {
+        type_: String,
+        raw: serde_json::Value,
+    },
 }
 
+tagged_enum_serde!(HttpPathMatch { "Exact", "PathPrefix", "RegularExpression" } default = "PathPrefix");
+
 /// HTTPHeaderName is the name of an HTTP header.
 ///
 /// Valid values include:
@@ -275,10 +497,8 @@ pub type HttpHeaderName = String;
 /// Generally, proxies should follow the guidance from the RFC:
 /// <https://www.rfc-editor.org/rfc/rfc7230.html#section-3.2.2> regarding
 /// processing a repeated header, with special handling for "Set-Cookie".
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
-#[serde(tag = "type", rename_all = "PascalCase")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "PascalCase")]
 pub enum HttpHeaderMatch {
     #[serde(rename_all = "camelCase")]
     Exact { name: HttpHeaderName, value: String },
@@ -293,22 +513,46 @@ pub enum HttpHeaderMatch {
         /// the supported dialect.
         value: String,
     },
+
+    /// A header match with a type that is not known to this version of the
+    /// library.
+    ///
+    /// `raw` holds the header match as it was read, and is written back
+    /// verbatim.
+    #[serde(skip)]
+    Unknown {
+        type_: String,
+        raw: serde_json::Value,
+    },
 }
 
+tagged_enum_serde!(HttpHeaderMatch { "Exact", "RegularExpression" } default = "Exact");
+
 /// HTTPQueryParamMatch describes how to select a HTTP route by matching HTTP
 /// query parameters.
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
-#[serde(tag = "type", rename_all = "PascalCase")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "PascalCase")]
 pub enum HttpQueryParamMatch {
     #[serde(rename_all = "camelCase")]
     Exact { name: String, value: String },
 
     #[serde(rename_all = "camelCase")]
     RegularExpression { name: String, value: String },
+
+    /// A query parameter match with a type that is not known to this version
+    /// of the library.
+    ///
+    /// `raw` holds the query parameter match as it was read, and is written
+    /// back verbatim.
+    #[serde(skip)]
+    Unknown {
+        type_: String,
+        raw: serde_json::Value,
+    },
 }
 
+tagged_enum_serde!(HttpQueryParamMatch { "Exact", "RegularExpression" } default = "Exact");
+
 /// HTTPMethod describes how to select a HTTP route by matching the HTTP
 /// method as defined by
 /// [RFC 7231](https://datatracker.ietf.org/doc/html/rfc7231#section-4) and
@@ -347,10 +591,12 @@ pub type HttpMethod = String;
 /// If a reference to a custom filter type cannot be resolved, the filter
 /// MUST NOT be skipped. Instead, requests that would have been processed by
 /// that filter MUST receive a HTTP error response.
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
-#[serde(tag = "type", rename_all = "PascalCase")]
+///
+/// Filters with a type that is not known to this version of the library are
+/// read as [`HttpRouteFilter::Unknown`], so that a single unrecognized filter
+/// does not make the whole route unreadable.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "PascalCase")]
 pub enum HttpRouteFilter {
     /// RequestHeaderModifier defines a schema for a filter that modifies request
     /// headers.
@@ -403,8 +649,50 @@ pub enum HttpRouteFilter {
     /// Support: Implementation-specific
     #[serde(rename_all = "camelCase")]
     ExtensionRef { extension_ref: LocalObjectReference },
+
+    /// CORS defines a schema for a filter that responds to the cross-origin
+    /// request based on HTTP response header.
+    ///
+    /// Support: Extended
+    #[serde(rename_all = "camelCase")]
+    CORS { cors: HttpCorsFilter },
+
+    /// ExternalAuth configures settings related to sending request details to
+    /// an external auth service. The external service MUST authenticate the
+    /// request, and MAY authorize the request as well.
+    ///
+    /// If there is any problem communicating with the external service, this
+    /// filter MUST fail closed.
+    ///
+    /// Support: Extended
+    ///
+    /// gateway:experimental
+    #[serde(rename_all = "camelCase")]
+    ExternalAuth {
+        external_auth: HttpExternalAuthFilter,
+    },
+
+    /// A filter with a type that is not known to this version of the library.
+    ///
+    /// `raw` holds the filter as it was read, and is written back verbatim.
+    #[serde(skip)]
+    Unknown {
+        type_: String,
+        raw: serde_json::Value,
+    },
 }
 
+tagged_enum_serde!(HttpRouteFilter {
+    "RequestHeaderModifier",
+    "ResponseHeaderModifier",
+    "RequestMirror",
+    "RequestRedirect",
+    "URLRewrite",
+    "ExtensionRef",
+    "CORS",
+    "ExternalAuth",
+});
+
 /// HTTPRequestHeaderFilter defines configuration for the RequestHeaderModifier
 /// filter.
 #[derive(
@@ -426,6 +714,8 @@ pub struct HttpRequestHeaderFilter {
     /// Output:
     ///   GET /foo HTTP/1.1
     ///   my-header: bar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub set: Option<Vec<HttpHeader>>,
 
     /// Add adds the given header(s) (name, value) to the request
@@ -445,6 +735,8 @@ pub struct HttpRequestHeaderFilter {
     ///   GET /foo HTTP/1.1
     ///   my-header: foo
     ///   my-header: bar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub add: Option<Vec<HttpHeader>>,
 
     /// Remove the given header(s) from the HTTP request before the action. The
@@ -464,6 +756,8 @@ pub struct HttpRequestHeaderFilter {
     /// Output:
     ///   GET /foo HTTP/1.1
     ///   my-header2: bar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
     pub remove: Option<Vec<String>>,
 }
 
@@ -480,19 +774,19 @@ pub struct HttpHeader {
     /// with an equivalent header name MUST be ignored. Due to the
     /// case-insensitivity of header names, "foo" and "Foo" are considered
     /// equivalent.
+    #[schemars(length(min = 1, max = 256), regex = "crate::schema::HEADER_NAME")]
     pub name: HttpHeaderName,
 
     /// Value is the value of HTTP Header to be matched.
+    #[schemars(length(min = 1, max = 4096))]
     pub value: String,
 }
 
 /// HTTPPathModifier defines configuration for path modifiers.
 ///
 // gateway:experimental
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
-#[serde(tag = "type", rename_all = "PascalCase")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "PascalCase")]
 pub enum HttpPathModifier {
     /// ReplaceFullPath specifies the value with which to replace the full path
     /// of a request during a rewrite or redirect.
@@ -504,8 +798,21 @@ pub enum HttpPathModifier {
     /// to "/foo/bar" with a prefix match of "/foo" would be modified to "/bar".
     #[serde(rename_all = "camelCase")]
     ReplacePrefixMatch { replace_prefix_match: String },
+
+    /// A path modifier with a type that is not known to this version of the
+    /// library.
+    ///
+    /// `raw` holds the path modifier as it was read, and is written back
+    /// verbatim.
+    #[serde(skip)]
+    Unknown {
+        type_: String,
+        raw: serde_json::Value,
+    },
 }
 
+tagged_enum_serde!(HttpPathModifier { "ReplaceFullPath", "ReplacePrefixMatch" });
+
 /// HTTPRequestRedirect defines a filter that redirects a request. This filter
 /// MUST not be used on the same Route rule as a HTTPURLRewrite filter.
 #[derive(
@@ -518,6 +825,8 @@ pub struct HttpRequestRedirectFilter {
     /// When empty, the scheme of the request is used.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::schema::redirect_scheme")]
     pub scheme: Option<String>,
 
     /// Hostname is the hostname to be used in the value of the `Location`
@@ -526,6 +835,8 @@ pub struct HttpRequestRedirectFilter {
     /// When empty, the hostname of the request is used.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::PRECISE_HOSTNAME")]
     pub hostname: Option<PreciseHostname>,
 
     /// Path defines parameters used to modify the path of the incoming request.
@@ -533,6 +844,7 @@ pub struct HttpRequestRedirectFilter {
     /// empty, the request path is used as-is.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<HttpPathModifier>,
 
     /// Port is the port to be used in the value of the `Location`
@@ -540,11 +852,18 @@ pub struct HttpRequestRedirectFilter {
     /// When empty, port (if specified) of the request is used.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 65535))]
     pub port: Option<PortNumber>,
 
     /// StatusCode is the HTTP status code to be used in response.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::found",
+        schema_with = "crate::schema::redirect_status_code"
+    )]
     pub status_code: Option<u16>,
 }
 
@@ -562,19 +881,21 @@ pub struct HttpUrlRewriteFilter {
     /// forwarding.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::PRECISE_HOSTNAME")]
     pub hostname: Option<PreciseHostname>,
 
     /// Path defines a path rewrite.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<HttpPathModifier>,
 }
 
 /// HTTPRequestMirrorFilter defines configuration for the RequestMirror filter.
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
 #[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("!(has(self.percent) && has(self.fraction))").message("Only one of percent or fraction may be specified in HTTPRequestMirrorFilter"))]
 pub struct HttpRequestMirrorFilter {
     /// BackendRef references a resource where mirrored requests are sent.
     ///
@@ -595,13 +916,380 @@ pub struct HttpRequestMirrorFilter {
     /// Support: Extended for Kubernetes Service
     /// Support: Custom for any other resource
     pub backend_ref: BackendObjectReference,
+
+    /// Percent represents the percentage of requests that should be mirrored
+    /// to BackendRef. Its minimum value is 0 (indicating 0% of requests) and
+    /// its maximum value is 100 (indicating 100% of requests).
+    ///
+    /// Only one of Fraction or Percent may be specified. If neither field is
+    /// specified, 100% of requests will be mirrored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 0, max = 100))]
+    pub percent: Option<i32>,
+
+    /// Fraction represents the fraction of requests that should be mirrored to
+    /// BackendRef.
+    ///
+    /// Only one of Fraction or Percent may be specified. If neither field is
+    /// specified, 100% of requests will be mirrored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fraction: Option<Fraction>,
 }
 
-/// HTTPBackendRef defines how a HTTPRoute should forward an HTTP request.
+/// HTTPCORSFilter defines a filter that configures Cross-Origin Request
+/// Sharing (CORS).
 #[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpCorsFilter {
+    /// AllowOrigins indicates whether the response can be shared with
+    /// requested resource from the given `Origin`.
+    ///
+    /// The `Origin` consists of a scheme and a host, with an optional port,
+    /// and takes the form `<scheme>://<host>(:<port>)`.
+    ///
+    /// Valid values for scheme are: `http` and `https`.
+    ///
+    /// Valid values for port are any integer between 1 and 65535 (the list of
+    /// available TCP/UDP ports). Note that, if not included, port `80` is
+    /// assumed for `http` scheme origins, and port `443` is assumed for
+    /// `https` origins. This may affect origin matching.
+    ///
+    /// The host part of the origin may contain the wildcard character `*`.
+    /// These wildcard characters behave as follows:
+    ///
+    /// * `*` is a greedy match to the _left_, including any number of DNS
+    ///   labels to the left of its position. This also means that `*` will
+    ///   include any number of period `.` characters to the left of its
+    ///   position.
+    /// * A wildcard by itself matches all hosts.
+    ///
+    /// An origin value that includes _only_ the `*` character indicates
+    /// requests from all `Origin`s are allowed.
+    ///
+    /// When the `allowOrigins` field is configured with multiple origins, it
+    /// means the server supports clients from multiple origins. If the request
+    /// `Origin` matches the configured allowed origins, the gateway must
+    /// return the given `Origin` and sets value of the header
+    /// `Access-Control-Allow-Origin` same as the `Origin` header provided by
+    /// the client.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        length(max = 64),
+        inner(length(min = 1, max = 253), regex = "crate::schema::CORS_ORIGIN")
+    )]
+    pub allow_origins: Option<Vec<CorsOrigin>>,
+
+    /// AllowCredentials indicates whether the actual cross-origin request
+    /// allows to include credentials.
+    ///
+    /// When set to true, the gateway will include the
+    /// `Access-Control-Allow-Credentials` response header with value true
+    /// (case-sensitive).
+    ///
+    /// When set to false or omitted the gateway will omit the header
+    /// `Access-Control-Allow-Credentials` entirely (this is the standard CORS
+    /// behavior).
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_credentials: Option<bool>,
+
+    /// AllowMethods indicates which HTTP methods are supported for accessing
+    /// the requested resource.
+    ///
+    /// Valid values are any method defined by RFC9110, along with the special
+    /// value `*`, which represents all HTTP methods are allowed.
+    ///
+    /// Method names are case sensitive, so these values are also
+    /// case-sensitive. (See <https://www.rfc-editor.org/rfc/rfc2616#section-5.1.1>)
+    ///
+    /// Multiple method names in the value of the
+    /// `Access-Control-Allow-Methods` response header are separated by a
+    /// comma (",").
+    ///
+    /// A CORS-safelisted method is a method that is `GET`, `HEAD`, or `POST`.
+    /// (See <https://fetch.spec.whatwg.org/#cors-safelisted-method>) The
+    /// CORS-safelisted methods are always allowed, regardless of whether they
+    /// are specified in the `allowMethods` field.
+    ///
+    /// When the `allowMethods` field is configured with one or more methods,
+    /// the gateway must return the `Access-Control-Allow-Methods` response
+    /// header which value is present in the `allowMethods` field.
+    ///
+    /// If the HTTP method of the `Access-Control-Request-Method` request
+    /// header is not included in the list of methods specified by the
+    /// response header `Access-Control-Allow-Methods`, it will present an
+    /// error on the client side.
+    ///
+    /// The `Access-Control-Allow-Methods` response header can only use `*`
+    /// wildcard as value when the `allowCredentials` field is unspecified.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::schema::http_methods_with_wildcard")]
+    #[cel_validate(rule = Rule::new("!('*' in self && self.size() > 1)").message("AllowMethods cannot contain '*' alongside other methods"))]
+    pub allow_methods: Option<Vec<HttpMethodWithWildcard>>,
+
+    /// AllowHeaders indicates which HTTP request headers are supported for
+    /// accessing the requested resource.
+    ///
+    /// Header names are not case sensitive.
+    ///
+    /// Multiple header names in the value of the
+    /// `Access-Control-Allow-Headers` response header are separated by a
+    /// comma (",").
+    ///
+    /// When the `allowHeaders` field is configured with one or more headers,
+    /// the gateway must return the `Access-Control-Allow-Headers` response
+    /// header which value is present in the `allowHeaders` field.
+    ///
+    /// If any header name in the `Access-Control-Request-Headers` request
+    /// header is not included in the list of header names specified by the
+    /// response header `Access-Control-Allow-Headers`, it will present an
+    /// error on the client side.
+    ///
+    /// A wildcard indicates that the requests with all HTTP headers are
+    /// allowed. The `Access-Control-Allow-Headers` response header can only
+    /// use `*` wildcard as value when the `allowCredentials` field is
+    /// unspecified.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        length(max = 64),
+        inner(length(min = 1, max = 256), regex = "crate::schema::HEADER_NAME")
+    )]
+    #[cel_validate(rule = Rule::new("!('*' in self && self.size() > 1)").message("AllowHeaders cannot contain '*' alongside other methods"))]
+    pub allow_headers: Option<Vec<HttpHeaderName>>,
+
+    /// ExposeHeaders indicates which HTTP response headers can be exposed to
+    /// client-side scripts in response to a cross-origin request.
+    ///
+    /// A CORS-safelisted response header is an HTTP header in a CORS response
+    /// that it is considered safe to expose to the client scripts. The
+    /// CORS-safelisted response headers include the following headers:
+    /// `Cache-Control`, `Content-Language`, `Content-Length`, `Content-Type`,
+    /// `Expires`, `Last-Modified` and `Pragma`. (See
+    /// <https://fetch.spec.whatwg.org/#cors-safelisted-response-header-name>)
+    /// The CORS-safelisted response headers are exposed to client by default.
+    ///
+    /// When an HTTP header name is specified using the `exposeHeaders` field,
+    /// this additional header will be exposed as part of the response to the
+    /// client.
+    ///
+    /// Header names are not case sensitive.
+    ///
+    /// Multiple header names in the value of the
+    /// `Access-Control-Expose-Headers` response header are separated by a
+    /// comma (",").
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        length(max = 64),
+        inner(length(min = 1, max = 256), regex = "crate::schema::HEADER_NAME")
+    )]
+    pub expose_headers: Option<Vec<HttpHeaderName>>,
+
+    /// MaxAge indicates the duration (in seconds) for the client to cache the
+    /// results of a "preflight" request.
+    ///
+    /// The information provided by the `Access-Control-Allow-Methods` and
+    /// `Access-Control-Allow-Headers` response headers can be cached by the
+    /// client until the time specified by `Access-Control-Max-Age` elapses.
+    ///
+    /// The default value of `Access-Control-Max-Age` response header is 5
+    /// (seconds).
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(default = "crate::schema::five", range(min = 1))]
+    pub max_age: Option<i32>,
+}
+
+/// CORSOrigin is an origin that may make cross-origin requests, e.g.
+/// `https://example.com`, `https://*.example.com` or `*`.
+pub type CorsOrigin = String;
+
+/// HTTPMethodWithWildcard is an HTTP method, or `*` to indicate all methods.
+pub type HttpMethodWithWildcard = String;
+
+/// HTTPExternalAuthFilter defines a filter that modifies requests by sending
+/// request details to an external authorization server.
+///
+/// Support: Extended
+///
+/// gateway:experimental
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
+#[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("self.protocol == 'GRPC' ? has(self.grpc) : true").message("grpc must be specified when protocol is set to 'GRPC'"))]
+#[cel_validate(rule = Rule::new("has(self.grpc) ? self.protocol == 'GRPC' : true").message("protocol must be 'GRPC' when grpc is set"))]
+#[cel_validate(rule = Rule::new("self.protocol == 'HTTP' ? has(self.http) : true").message("http must be specified when protocol is set to 'HTTP'"))]
+#[cel_validate(rule = Rule::new("has(self.http) ? self.protocol == 'HTTP' : true").message("protocol must be 'HTTP' when http is set"))]
+pub struct HttpExternalAuthFilter {
+    /// ExternalAuthProtocol describes which protocol to use when communicating
+    /// with an ext_authz authorization server.
+    ///
+    /// When this is set to GRPC, each backend must use the Envoy ext_authz
+    /// protocol on the port specified in `backendRefs`.
+    ///
+    /// When this is set to HTTP, each backend must respond with a `200` status
+    /// code in on a successful authorization. Any other code is considered an
+    /// authorization failure.
+    #[schemars(schema_with = "crate::schema::external_auth_protocol")]
+    pub protocol: HttpRouteExternalAuthProtocol,
+
+    /// BackendRef is a reference to a backend to send authorization requests
+    /// to.
+    ///
+    /// The backend must speak the selected protocol (GRPC or HTTP) on the
+    /// referenced port.
+    ///
+    /// If the backend service requires TLS, use BackendTLSPolicy to tell the
+    /// implementation to supply the TLS details to be used to connect to that
+    /// backend.
+    pub backend_ref: BackendObjectReference,
+
+    /// GRPCAuthConfig contains configuration for communication with
+    /// ext_authz protocol-speaking backends.
+    ///
+    /// If unset, implementations must assume the default behavior for each
+    /// included field is intended.
+    #[serde(default, rename = "grpc", skip_serializing_if = "Option::is_none")]
+    pub grpc_auth_config: Option<GrpcAuthConfig>,
+
+    /// HTTPAuthConfig contains configuration for communication with
+    /// HTTP-speaking backends.
+    ///
+    /// If unset, implementations must assume the default behavior for each
+    /// included field is intended.
+    #[serde(default, rename = "http", skip_serializing_if = "Option::is_none")]
+    pub http_auth_config: Option<HttpAuthConfig>,
+
+    /// ForwardBody controls if requests to the authorization server should
+    /// include the body of the client request; and if so, how big that body
+    /// is allowed to be.
+    ///
+    /// It is expected that implementations will buffer the request body up to
+    /// `forwardBody.maxSize` bytes. Bodies over that size must be rejected
+    /// with a 4xx series error (413 or 403 are common examples), and fail
+    /// processing of the filter.
+    ///
+    /// If unset, or `forwardBody.maxSize` is set to `0`, then the body will
+    /// not be forwarded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward_body: Option<ForwardBodyConfig>,
+}
+
+string_enum! {
+    /// HTTPRouteExternalAuthProtocol is the protocol used to communicate with
+    /// an external authorization server.
+    pub enum HttpRouteExternalAuthProtocol {
+        /// The Envoy ext_authz gRPC protocol.
+        Grpc = "GRPC",
+
+        /// Plain HTTP, where a `200` response authorizes the request.
+        Http = "HTTP",
+    }
+}
+
+/// GRPCAuthConfig contains configuration for communication with ext_authz
+/// protocol-speaking backends.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcAuthConfig {
+    /// AllowedRequestHeaders specifies what headers from the client request
+    /// will be sent to the authorization server.
+    ///
+    /// If this list is empty, then all headers must be sent.
+    ///
+    /// If the list has entries, only those entries must be sent.
+    #[serde(
+        default,
+        rename = "allowedHeaders",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(length(max = 64))]
+    pub allowed_request_headers: Option<Vec<String>>,
+}
+
+/// HTTPAuthConfig contains configuration for communication with HTTP-speaking
+/// backends.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpAuthConfig {
+    /// Path sets the prefix that paths from the client request will have
+    /// added when forwarded to the authorization server.
+    ///
+    /// When empty or unspecified, no prefix is added.
+    ///
+    /// Valid values are the same as the "value" regex for path values in the
+    /// `match` stanza, and the validation regex will screen out invalid paths
+    /// in the same way. Even with the validation, implementations MUST
+    /// sanitize this input before using it directly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 1024), regex = "crate::schema::PATH")]
+    pub path: Option<String>,
+
+    /// AllowedRequestHeaders specifies what additional headers from the client
+    /// request will be sent to the authorization server.
+    ///
+    /// The following headers must always be sent to the authorization server,
+    /// regardless of this setting:
+    ///
+    /// * `Host`
+    /// * `Method`
+    /// * `Path`
+    /// * `Content-Length`
+    /// * `Authorization`
+    ///
+    /// If this list is empty, then only those headers must be sent.
+    #[serde(
+        default,
+        rename = "allowedHeaders",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(length(max = 64))]
+    pub allowed_request_headers: Option<Vec<String>>,
+
+    /// AllowedResponseHeaders specifies what headers from the authorization
+    /// response will be copied into the request to the backend.
+    ///
+    /// If this list is empty, then all headers from the authorization server
+    /// except Authority or Host must be copied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 64))]
+    pub allowed_response_headers: Option<Vec<String>>,
+}
+
+/// ForwardBodyConfig configures if requests to the authorization server
+/// should include the body of the client request.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
 )]
 #[serde(rename_all = "camelCase")]
+pub struct ForwardBodyConfig {
+    /// MaxSize specifies how large in bytes the largest body that will be
+    /// buffered and sent to the authorization server. If the body size is
+    /// larger than `maxSize`, then the body sent to the authorization server
+    /// must be truncated to `maxSize` bytes.
+    ///
+    /// If 0, the body will not be sent to the authorization server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u16>,
+}
+
+/// HTTPBackendRef defines how a HTTPRoute should forward an HTTP request.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct HttpBackendRef {
     /// BackendRef is a reference to a backend to forward matched requests to.
     ///
@@ -628,6 +1316,13 @@ pub struct HttpBackendRef {
     ///
     /// Support: Custom (For broader support of filters, use the Filters field
     /// in HTTPRouteRule.)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
+    #[cel_validate(rule = Rule::new("!(self.exists(f, f.type == 'RequestRedirect') && self.exists(f, f.type == 'URLRewrite'))").message("May specify either httpRouteFilterRequestRedirect or httpRouteFilterRequestRewrite, but not both"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'RequestHeaderModifier').size() <= 1").message("RequestHeaderModifier filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'ResponseHeaderModifier').size() <= 1").message("ResponseHeaderModifier filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'RequestRedirect').size() <= 1").message("RequestRedirect filter cannot be repeated"))]
+    #[cel_validate(rule = Rule::new("self.filter(f, f.type == 'URLRewrite').size() <= 1").message("URLRewrite filter cannot be repeated"))]
     pub filters: Option<Vec<HttpRouteFilter>>,
 }
 
@@ -670,4 +1365,55 @@ mod test {
         let route: Result<HttpRoute, _> = serde_json::from_str(test_json);
         assert!(route.is_ok());
     }
+
+    #[test]
+    fn preserves_unknown_filters() {
+        let raw = serde_json::json!({
+            "type": "RateLimit",
+            "rateLimit": { "requestsPerUnit": 10 },
+        });
+        let filter = serde_json::from_value::<HttpRouteFilter>(raw.clone()).unwrap();
+        assert_eq!(
+            filter,
+            HttpRouteFilter::Unknown {
+                type_: "RateLimit".to_string(),
+                raw: raw.clone(),
+            }
+        );
+        assert_eq!(serde_json::to_value(&filter).unwrap(), raw);
+
+        // Filters of a known type must still be well-formed.
+        let malformed = serde_json::json!({ "type": "RequestRedirect", "requestRedirect": 1 });
+        assert!(serde_json::from_value::<HttpRouteFilter>(malformed).is_err());
+    }
+
+    #[test]
+    fn defaults_match_types() {
+        let matches = serde_json::from_value::<Vec<HttpRouteMatch>>(serde_json::json!([{
+            "path": { "value": "/foo" },
+            "headers": [{ "name": "version", "value": "one" }],
+            "queryParams": [{ "name": "animal", "value": "whale" }],
+        }]))
+        .expect("matches without a type must deserialize");
+        assert_eq!(
+            matches[0].path,
+            Some(HttpPathMatch::PathPrefix {
+                value: "/foo".to_string()
+            })
+        );
+        assert_eq!(
+            matches[0].headers,
+            Some(vec![HttpHeaderMatch::Exact {
+                name: "version".to_string(),
+                value: "one".to_string(),
+            }])
+        );
+        assert_eq!(
+            matches[0].query_params,
+            Some(vec![HttpQueryParamMatch::Exact {
+                name: "animal".to_string(),
+                value: "whale".to_string(),
+            }])
+        );
+    }
 }
@@ -8,17 +8,67 @@
 // TODO(ver): We should deny missing_docs, but this doesn't play with
 // CustomResource derivations.
 
+#[macro_use]
+mod macros;
+
+mod attachment;
+mod builder;
+mod condition;
+mod duration;
+mod filter;
 mod gateway;
 mod gatewayclass;
+mod hostname;
 mod httproute;
 mod object_reference;
+mod parameters;
+mod route_status;
+mod routing;
+mod schema;
 mod shared;
+mod unsupported;
+mod validate;
+
+pub use self::{
+    attachment::{attach, AttachmentRejection, ParentAttachment, Route, RouteParent},
+    builder::{GatewayBuilder, HttpRouteBuilder, HttpRouteRuleBuilder, ListenerBuilder},
+    condition::{ConditionStatus, ConditionType},
+    duration::{Duration, InvalidDuration},
+    filter::{
+        apply_http_filters, HttpFilterAction, HttpFilterError, HttpFilterOutcome, HttpRedirect,
+    },
+    gateway::*,
+    gatewayclass::*,
+    hostname::*,
+    httproute::*,
+    object_reference::*,
+    parameters::InvalidParameters,
+    route_status::route_status_patch,
+    routing::{select_http_route, HttpRequest, HttpRouteRules, HttpRouteSelection, HttpRouteTable},
+    shared::*,
+    unsupported::{UnsupportedFeature, UnsupportedFeatures},
+    validate::{FieldError, FieldPath, Validate, ValidationError},
+};
 
-pub use self::{gateway::*, gatewayclass::*, httproute::*, object_reference::*, shared::*};
+/// The `gateway.networking.k8s.io/v1` versions of the GA resources.
+///
+/// The v1 resources share all of their nested types with the v1beta1 resources
+/// exported at the crate root; only the spec and root types are distinct.
+/// Resources convert losslessly between the two versions with `From`, and both
+/// versions may be served from one CRD with `kube::core::crd::merge_crds`.
+pub mod v1 {
+    mod gateway;
+    mod gatewayclass;
+    mod httproute;
+
+    pub use self::{gateway::*, gatewayclass::*, httproute::*};
+}
 
 #[cfg(feature = "experimental")]
 mod exp {
+    mod backendtlspolicy;
     mod grpcroute;
+    mod listenerset;
     mod policy;
     mod referencegrant;
     mod tcproute;
@@ -26,9 +76,18 @@ mod exp {
     mod udproute;
 
     pub use self::{
-        grpcroute::*, policy::*, referencegrant::*, tcproute::*, tlsroute::*, udproute::*,
+        backendtlspolicy::*, grpcroute::*, listenerset::*, policy::*, referencegrant::*,
+        tcproute::*, tlsroute::*, udproute::*,
     };
 }
 
 #[cfg(feature = "experimental")]
-pub use self::exp::*;
+pub use self::{
+    attachment::{allows_listener_set, ListenerSetParent},
+    builder::{
+        BackendRefsBuilder, GrpcRouteBuilder, GrpcRouteRuleBuilder, TcpRouteBuilder,
+        TlsRouteBuilder, UdpRouteBuilder,
+    },
+    exp::*,
+    routing::{select_grpc_route, GrpcRequest, GrpcRouteSelection, GrpcRouteTable},
+};
@@ -0,0 +1,177 @@
+/// Defines an enum for a string type with a set of well-known values.
+///
+/// Values that are not known to this version of the library are preserved in
+/// an `Other` variant, so that values written by newer implementations
+/// round-trip without loss. The enum (de)serializes as a plain string.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$vmeta:meta])*
+                $variant:ident = $value:literal,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$vmeta])*
+                $variant,
+            )+
+
+            /// A value that is not known to this version of the library.
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Other(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)+
+                    value => Self::Other(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match Self::from(value.as_str()) {
+                    Self::Other(_) => Self::Other(value),
+                    known => known,
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Other(value) => value,
+                    known => known.as_str().to_string(),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(s))
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self::from)
+            }
+        }
+
+        impl schemars::JsonSchema for $name {
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> String {
+                String::schema_name()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                String::json_schema(gen)
+            }
+        }
+    };
+}
+
+/// Implements serde for an internally tagged enum so that values with a `type`
+/// that is not known to this version of the library are preserved.
+///
+/// The enum must derive its serde impls with `#[serde(remote = "Self")]`, so
+/// that they are generated as inherent functions, and must have a
+/// `#[serde(skip)] Unknown { type_: String, raw: serde_json::Value }` variant.
+/// Values whose `type` is not one of the listed types are read into the
+/// `Unknown` variant, which is written back verbatim; values with a known type
+/// that are malformed still fail to deserialize.
+///
+/// A `default = "..."` suffix names the type that the CRD defaults `type` to;
+/// values without a `type` are read as that type.
+///
+/// Enums that implement `Deserialize` by hand use the `@serialize` form, which
+/// only implements `Serialize` and `KNOWN_TYPES`.
+macro_rules! tagged_enum_serde {
+    ($name:ident { $($type:literal),+ $(,)? } $(default = $default:literal)?) => {
+        tagged_enum_serde!(@serialize $name { $($type),+ });
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                #[allow(unused_mut)]
+                let mut raw = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+                $(
+                    if let Some(fields) = raw.as_object_mut() {
+                        fields
+                            .entry("type")
+                            .or_insert_with(|| $default.into());
+                    }
+                )?
+                match raw.get("type").and_then(serde_json::Value::as_str) {
+                    Some(type_) if !Self::KNOWN_TYPES.contains(&type_) => Ok(Self::Unknown {
+                        type_: type_.to_string(),
+                        raw,
+                    }),
+                    _ => Self::deserialize(raw).map_err(D::Error::custom),
+                }
+            }
+        }
+    };
+
+    (@serialize $name:ident { $($type:literal),+ $(,)? }) => {
+        impl $name {
+            /// The values of `type` that are known to this version of the
+            /// library.
+            pub const KNOWN_TYPES: &'static [&'static str] = &[$($type),+];
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    Self::Unknown { raw, .. } => raw.serialize(serializer),
+                    known => Self::serialize(known, serializer),
+                }
+            }
+        }
+    };
+}
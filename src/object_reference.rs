@@ -14,12 +14,15 @@ use crate::*;
 pub struct LocalObjectReference {
     /// Group is the group of the referent. For example, "networking.k8s.io".
     /// When unspecified (empty string), core API group is inferred.
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
     pub group: Group,
 
     /// Kind is kind of the referent. For example "HTTPRoute" or "Service".
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
     pub kind: Kind,
 
     /// Name is the name of the referent.
+    #[schemars(length(min = 1, max = 253))]
     pub name: ObjectName,
 }
 
@@ -38,12 +41,25 @@ pub struct LocalObjectReference {
 pub struct SecretObjectReference {
     /// Group is the group of the referent. For example, "networking.k8s.io".
     /// When unspecified (empty string), core API group is inferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::core_group",
+        length(max = 253),
+        regex = "crate::schema::GROUP"
+    )]
     pub group: Option<Group>,
 
     /// Kind is kind of the referent. For example "HTTPRoute" or "Service".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::secret_kind",
+        length(min = 1, max = 63),
+        regex = "crate::schema::KIND"
+    )]
     pub kind: Option<Kind>,
 
     /// Name is the name of the referent.
+    #[schemars(length(min = 1, max = 253))]
     pub name: ObjectName,
 
     /// Namespace is the namespace of the backend. When unspecified, the local
@@ -55,6 +71,8 @@ pub struct SecretObjectReference {
     /// for details.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::NAMESPACE")]
     pub namespace: Option<Namespace>,
 }
 
@@ -73,19 +91,31 @@ pub struct SecretObjectReference {
 /// References to objects with invalid Group and Kind are not valid, and must
 /// be rejected by the implementation, with appropriate Conditions set
 /// on the containing object.
-#[derive(
-    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
-)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
+#[cel_validate(rule = Rule::new("(size(self.group) == 0 && self.kind == 'Service') ? has(self.port) : true").message("Must have port for Service reference"))]
 pub struct BackendObjectReference {
     /// Group is the group of the referent. For example, "networking.k8s.io".
     /// When unspecified (empty string), core API group is inferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::core_group",
+        length(max = 253),
+        regex = "crate::schema::GROUP"
+    )]
     pub group: Option<Group>,
 
     /// Kind is kind of the referent. For example "HTTPRoute" or "Service".
     /// Defaults to "Service" when not specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::service_kind",
+        length(min = 1, max = 63),
+        regex = "crate::schema::KIND"
+    )]
     pub kind: Option<Kind>,
 
     /// Name is the name of the referent.
+    #[schemars(length(min = 1, max = 253))]
     pub name: ObjectName,
 
     /// Namespace is the namespace of the backend. When unspecified, the local
@@ -97,11 +127,55 @@ pub struct BackendObjectReference {
     /// for details.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::NAMESPACE")]
     pub namespace: Option<Namespace>,
 
     /// Port specifies the destination port number to use for this resource.
     /// Port is required when the referent is a Kubernetes Service. For other
     /// resources, destination port might be derived from the referent resource
     /// or this field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 65535))]
     pub port: Option<PortNumber>,
 }
+
+/// ObjectReference identifies an API object including its namespace.
+///
+/// The API object must be valid in the cluster; the Group and Kind must
+/// be registered in the cluster for this reference to be valid.
+///
+/// References to objects with invalid Group and Kind are not valid, and must
+/// be rejected by the implementation, with appropriate Conditions set
+/// on the containing object.
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct ObjectReference {
+    /// Group is the group of the referent. For example,
+    /// "gateway.networking.k8s.io". When unspecified or empty string, core API
+    /// group is inferred.
+    #[schemars(length(max = 253), regex = "crate::schema::GROUP")]
+    pub group: Group,
+
+    /// Kind is kind of the referent. For example "ConfigMap" or "Service".
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::KIND")]
+    pub kind: Kind,
+
+    /// Name is the name of the referent.
+    #[schemars(length(min = 1, max = 253))]
+    pub name: ObjectName,
+
+    /// Namespace is the namespace of the referenced object. When unspecified,
+    /// the local namespace is inferred.
+    ///
+    /// Note that when a namespace different than the local namespace is
+    /// specified, a ReferenceGrant object is required in the referent
+    /// namespace to allow that namespace's owner to accept the reference. See
+    /// the ReferenceGrant documentation for details.
+    ///
+    /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::NAMESPACE")]
+    pub namespace: Option<Namespace>,
+}
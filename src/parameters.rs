@@ -0,0 +1,185 @@
+//! Resolves the parameters that a GatewayClass refers to.
+
+use crate::*;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+
+/// Indicates that a GatewayClass's parameters are invalid, as reported with
+/// the `InvalidParameters` reason of the GatewayClass's `Accepted` condition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvalidParameters {
+    /// The parametersRef refers to a kind that the controller does not
+    /// support.
+    UnsupportedKind { group: Group, kind: Kind },
+
+    /// The parametersRef refers to a resource that does not exist.
+    NotFound(ParametersReference),
+}
+
+// === impl ParametersReference ===
+
+impl ParametersReference {
+    /// Resolves the referenced resource, which must be a `K`, using `lookup`
+    /// (e.g. a reflector store or an API client).
+    ///
+    /// `dt` is `K`'s dynamic type: `&()` for static types like `ConfigMap`,
+    /// or an `ApiResource` for `DynamicObject`s.
+    ///
+    /// ```
+    /// # use k8s_gateway_api::*;
+    /// use k8s_openapi::api::core::v1::ConfigMap;
+    ///
+    /// # fn get_config_map(_: &str, _: &str) -> Option<ConfigMap> { None }
+    /// # let parameters_ref = ParametersReference {
+    /// #     group: "".to_string(),
+    /// #     kind: "ConfigMap".to_string(),
+    /// #     name: "params".to_string(),
+    /// #     namespace: Some("infra".to_string()),
+    /// # };
+    /// let result = parameters_ref.resolve::<ConfigMap>(&(), |r| {
+    ///     get_config_map(r.namespace.as_deref()?, &r.name)
+    /// });
+    /// if let Err(invalid) = result {
+    ///     let condition = invalid.condition(Some(1));
+    ///     assert_eq!(condition.reason, "InvalidParameters");
+    /// }
+    /// ```
+    pub fn resolve<K>(
+        &self,
+        dt: &K::DynamicType,
+        lookup: impl FnOnce(&ParametersReference) -> Option<K>,
+    ) -> Result<K, InvalidParameters>
+    where
+        K: kube::Resource,
+    {
+        if self.group != K::group(dt) || self.kind != K::kind(dt) {
+            return Err(InvalidParameters::UnsupportedKind {
+                group: self.group.clone(),
+                kind: self.kind.clone(),
+            });
+        }
+        lookup(self).ok_or_else(|| InvalidParameters::NotFound(self.clone()))
+    }
+}
+
+// === impl InvalidParameters ===
+
+impl InvalidParameters {
+    /// Builds the `Accepted` condition for a GatewayClass with these
+    /// parameters.
+    pub fn condition(&self, observed_generation: Option<i64>) -> metav1::Condition {
+        GatewayClassConditionType::Accepted.condition(
+            false,
+            GatewayClassConditionReason::InvalidParameters,
+            self.to_string(),
+            observed_generation,
+        )
+    }
+}
+
+impl From<InvalidParameters> for GatewayClassConditionReason {
+    fn from(_: InvalidParameters) -> Self {
+        Self::InvalidParameters
+    }
+}
+
+impl std::fmt::Display for InvalidParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedKind { group, kind } if group.is_empty() => {
+                write!(f, "unsupported parameters kind {kind}")
+            }
+            Self::UnsupportedKind { group, kind } => {
+                write!(f, "unsupported parameters kind {kind}.{group}")
+            }
+            Self::NotFound(r) => match &r.namespace {
+                Some(ns) => write!(f, "{} {}/{} not found", r.kind, ns, r.name),
+                None => write!(f, "{} {} not found", r.kind, r.name),
+            },
+        }
+    }
+}
+
+impl std::error::Error for InvalidParameters {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use k8s_openapi::api::core::v1::ConfigMap;
+    use kube::core::{ApiResource, DynamicObject, GroupVersionKind};
+
+    fn parameters_ref(group: &str, kind: &str) -> ParametersReference {
+        ParametersReference {
+            group: group.to_string(),
+            kind: kind.to_string(),
+            name: "params".to_string(),
+            namespace: Some("infra".to_string()),
+        }
+    }
+
+    #[test]
+    fn reads_parameters_ref() {
+        let class: GatewayClass = serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "GatewayClass",
+            "metadata": { "name": "example" },
+            "spec": {
+                "controllerName": "example.com/gateway",
+                "parametersRef": { "group": "", "kind": "ConfigMap", "name": "params", "namespace": "infra" },
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            class.spec.parameters_ref,
+            Some(parameters_ref("", "ConfigMap"))
+        );
+        let spec = serde_json::to_value(&class.spec).unwrap();
+        assert_eq!(spec["parametersRef"]["name"], "params");
+    }
+
+    #[test]
+    fn resolves_parameters() {
+        let config_map = ConfigMap {
+            metadata: kube::core::ObjectMeta {
+                name: Some("params".to_string()),
+                namespace: Some("infra".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let lookup = |r: &ParametersReference| {
+            (r.name == "params" && r.namespace.as_deref() == Some("infra"))
+                .then(|| config_map.clone())
+        };
+
+        assert_eq!(
+            parameters_ref("", "ConfigMap").resolve(&(), lookup),
+            Ok(config_map.clone())
+        );
+
+        let mut missing = parameters_ref("", "ConfigMap");
+        missing.name = "other".to_string();
+        let invalid = missing.resolve(&(), lookup).unwrap_err();
+        assert_eq!(invalid.to_string(), "ConfigMap infra/other not found");
+        let condition = invalid.condition(Some(2));
+        assert_eq!(condition.type_, "Accepted");
+        assert_eq!(condition.status, "False");
+        assert_eq!(condition.reason, "InvalidParameters");
+
+        assert_eq!(
+            parameters_ref("example.com", "Params").resolve::<ConfigMap>(&(), |_| None),
+            Err(InvalidParameters::UnsupportedKind {
+                group: "example.com".to_string(),
+                kind: "Params".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn resolves_dynamic_parameters() {
+        let ar = ApiResource::from_gvk(&GroupVersionKind::gvk("example.com", "v1", "Params"));
+        let params = parameters_ref("example.com", "Params")
+            .resolve(&ar, |r| Some(DynamicObject::new(&r.name, &ar)))
+            .unwrap();
+        assert_eq!(params.metadata.name.as_deref(), Some("params"));
+    }
+}
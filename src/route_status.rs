@@ -0,0 +1,241 @@
+//! Helps controllers write route status without clobbering the entries that
+//! other controllers write for their own parents.
+
+use crate::*;
+
+impl RouteStatus {
+    /// Replaces the parent statuses written by `controller_name` with
+    /// `parents`, returning true if the status changed.
+    ///
+    /// Entries are keyed by their `parentRef`, compared after filling in the
+    /// default group, kind and namespace (that of the route, given as
+    /// `route_namespace`). Entries written by other controllers are preserved;
+    /// entries written by this controller that are not in `parents` are
+    /// removed. When an entry is updated, its conditions are set as with
+    /// [`RouteParentStatus::set_condition`], so that the transition times of
+    /// conditions whose status did not change are preserved.
+    pub fn merge_parents(
+        &mut self,
+        controller_name: &str,
+        route_namespace: &str,
+        parents: impl IntoIterator<Item = RouteParentStatus>,
+    ) -> bool {
+        let mut parents = parents
+            .into_iter()
+            .map(|mut parent| {
+                parent.controller_name = controller_name.to_string();
+                parent
+            })
+            .collect::<Vec<_>>();
+
+        let before = self.parents.clone();
+        self.parents.retain_mut(|existing| {
+            if existing.controller_name != controller_name {
+                return true;
+            }
+            let Some(idx) = parents
+                .iter()
+                .position(|p| same_parent(&p.parent_ref, &existing.parent_ref, route_namespace))
+            else {
+                return false;
+            };
+            let parent = parents.remove(idx);
+            existing.parent_ref = parent.parent_ref;
+            existing
+                .conditions
+                .retain(|c| parent.conditions.iter().any(|n| n.type_ == c.type_));
+            for condition in parent.conditions {
+                crate::condition::set(&mut existing.conditions, condition);
+            }
+            true
+        });
+        self.parents.extend(parents);
+
+        self.parents != before
+    }
+}
+
+/// Returns true if two parent references, made from a route in
+/// `route_namespace`, refer to the same parent.
+fn same_parent(a: &ParentReference, b: &ParentReference, route_namespace: &str) -> bool {
+    a.name == b.name
+        && a.group.as_deref().unwrap_or("gateway.networking.k8s.io")
+            == b.group.as_deref().unwrap_or("gateway.networking.k8s.io")
+        && a.kind.as_deref().unwrap_or("Gateway") == b.kind.as_deref().unwrap_or("Gateway")
+        && a.namespace.as_deref().unwrap_or(route_namespace)
+            == b.namespace.as_deref().unwrap_or(route_namespace)
+        && a.section_name.as_deref().filter(|s| !s.is_empty())
+            == b.section_name.as_deref().filter(|s| !s.is_empty())
+        && a.port == b.port
+}
+
+/// Builds a patch body for a route's status subresource that sets the parent
+/// statuses written by `controller_name` to `parents`, or returns `None` if
+/// the route's status already reflects them.
+///
+/// Upstream does not declare `status.parents` as a map list, so both JSON
+/// merge patches and server-side apply replace the list as a whole. The body
+/// therefore carries the entries written by other controllers, as observed on
+/// `route`, and includes the route's `apiVersion`, `kind`, name and namespace
+/// so that it may be sent as either kind of patch.
+///
+/// The body also carries the route's `metadata.resourceVersion`, so that the
+/// API server rejects it with a 409 Conflict if the route changed after it
+/// was read (e.g. because another controller wrote its own entries). A
+/// controller should then read the route again and rebuild the patch.
+pub fn route_status_patch<R: Route>(
+    route: &R,
+    controller_name: &str,
+    parents: impl IntoIterator<Item = RouteParentStatus>,
+) -> Option<serde_json::Value> {
+    let mut status = route
+        .route_status()
+        .cloned()
+        .unwrap_or(RouteStatus { parents: vec![] });
+    let meta = route.meta();
+    let namespace = meta.namespace.as_deref().unwrap_or_default();
+    if !status.merge_parents(controller_name, namespace, parents) {
+        return None;
+    }
+
+    Some(serde_json::json!({
+        "apiVersion": R::api_version(&()),
+        "kind": R::kind(&()),
+        "metadata": {
+            "name": meta.name,
+            "namespace": meta.namespace,
+            "resourceVersion": meta.resource_version,
+        },
+        "status": status,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parent(name: &str, controller: &str, accepted: bool) -> RouteParentStatus {
+        RouteParentStatus {
+            parent_ref: ParentReference {
+                group: None,
+                kind: None,
+                namespace: None,
+                name: name.to_string(),
+                section_name: None,
+                port: None,
+            },
+            controller_name: controller.to_string(),
+            conditions: vec![RouteConditionType::Accepted.condition(
+                accepted,
+                if accepted {
+                    RouteConditionReason::Accepted
+                } else {
+                    RouteConditionReason::NotAllowedByListeners
+                },
+                "",
+                Some(1),
+            )],
+        }
+    }
+
+    #[test]
+    fn preserves_other_controllers() {
+        let mut status = RouteStatus {
+            parents: vec![
+                parent("a", "example.com/ours", true),
+                parent("b", "example.com/theirs", true),
+                parent("c", "example.com/ours", true),
+            ],
+        };
+        let transition = status.parents[0].conditions[0].last_transition_time.clone();
+
+        let updated = status.merge_parents(
+            "example.com/ours",
+            "apps",
+            [parent("a", "ignored", true), parent("d", "ignored", false)],
+        );
+        assert!(updated);
+        let parents = status
+            .parents
+            .iter()
+            .map(|p| (p.parent_ref.name.as_str(), p.controller_name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parents,
+            [
+                ("a", "example.com/ours"),
+                ("b", "example.com/theirs"),
+                ("d", "example.com/ours"),
+            ]
+        );
+        assert_eq!(
+            status.parents[0].conditions[0].last_transition_time,
+            transition
+        );
+
+        assert!(!status.merge_parents(
+            "example.com/ours",
+            "apps",
+            [parent("a", "", true), parent("d", "", false)]
+        ));
+    }
+
+    #[test]
+    fn matches_defaulted_parent_refs() {
+        let mut status = RouteStatus {
+            parents: vec![parent("a", "example.com/ours", true)],
+        };
+        let mut explicit = parent("a", "", false);
+        explicit.parent_ref.group = Some("gateway.networking.k8s.io".to_string());
+        explicit.parent_ref.kind = Some("Gateway".to_string());
+        explicit.parent_ref.namespace = Some("apps".to_string());
+
+        assert!(status.merge_parents("example.com/ours", "apps", [explicit.clone()]));
+        assert_eq!(status.parents.len(), 1, "the entry must be replaced");
+        assert_eq!(status.parents[0].parent_ref, explicit.parent_ref);
+
+        let mut other = parent("a", "", true);
+        other.parent_ref.namespace = Some("infra".to_string());
+        assert!(status.merge_parents("example.com/ours", "apps", [explicit, other]));
+        assert_eq!(status.parents.len(), 2);
+    }
+
+    #[test]
+    fn builds_patch() {
+        let mut route: HttpRoute = serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "HTTPRoute",
+            "metadata": { "name": "route", "namespace": "apps", "resourceVersion": "42" },
+            "spec": {},
+        }))
+        .unwrap();
+        route.status = Some(HttpRouteStatus {
+            inner: RouteStatus {
+                parents: vec![parent("b", "example.com/theirs", true)],
+            },
+        });
+
+        let patch = route_status_patch(&route, "example.com/ours", [parent("a", "", true)])
+            .expect("status must change");
+        assert_eq!(patch["apiVersion"], "gateway.networking.k8s.io/v1beta1");
+        assert_eq!(patch["kind"], "HTTPRoute");
+        assert_eq!(patch["metadata"]["namespace"], "apps");
+        assert_eq!(
+            patch["metadata"]["resourceVersion"], "42",
+            "a stale patch must be rejected"
+        );
+        let controllers = patch["status"]["parents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["controllerName"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(controllers, ["example.com/theirs", "example.com/ours"]);
+
+        assert_eq!(
+            route_status_patch(&route, "example.com/ours", []),
+            None,
+            "no entries to remove"
+        );
+    }
+}
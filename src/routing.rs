@@ -0,0 +1,901 @@
+//! Evaluates route rules against requests, following the Gateway API's
+//! matching and precedence rules, so that routing decisions can be tested
+//! without a data plane.
+//!
+//! Regular expressions are evaluated with the [`regex`] crate's syntax and
+//! must match the entire value. They are compiled once, when an
+//! [`HttpRouteTable`] (or `GrpcRouteTable`) is built; a rule whose expression
+//! is invalid never matches.
+
+use crate::*;
+use std::{cmp::Ordering, collections::HashMap};
+
+/// A description of an HTTP request, as seen by route matching.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// The request's scheme, e.g. `http`.
+    pub scheme: String,
+
+    /// The request's host, without a port.
+    pub host: String,
+
+    /// The port on which the request was received, if known.
+    pub port: Option<PortNumber>,
+
+    /// The request's path, without a query string.
+    pub path: String,
+
+    /// The request's method, in upper case.
+    pub method: HttpMethod,
+
+    /// The request's headers, in order.
+    pub headers: Vec<(HttpHeaderName, String)>,
+
+    /// The request's query parameters, in order and without percent-decoding.
+    pub query_params: Vec<(String, String)>,
+}
+
+/// An HTTP route kind whose rules can be evaluated against requests.
+pub trait HttpRouteRules: Route {
+    fn rules(&self) -> &[HttpRouteRule];
+}
+
+/// The route rule that handles a request.
+#[derive(Clone, Debug)]
+pub struct HttpRouteSelection<'r, R> {
+    /// The route that handles the request.
+    pub route: &'r R,
+
+    /// The rule that handles the request.
+    pub rule: &'r HttpRouteRule,
+
+    /// The match that selected the rule, or `None` if the rule has no matches
+    /// and therefore matches every request (as a `/` path prefix).
+    pub route_match: Option<&'r HttpRouteMatch>,
+}
+
+/// A set of HTTP routes, prepared for selecting the rule that handles each
+/// request.
+///
+/// Building a table compiles the regular expressions in the routes' matches,
+/// so a table should be reused across requests for as long as the routes do
+/// not change.
+#[derive(Clone, Debug)]
+pub struct HttpRouteTable<'r, R> {
+    routes: Vec<&'r R>,
+    regexes: Regexes,
+}
+
+/// A description of a gRPC request, as seen by route matching.
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GrpcRequest {
+    /// The request's authority, without a port.
+    pub authority: String,
+
+    /// The fully-qualified name of the called service, e.g. `foo.bar.Baz`.
+    pub service: String,
+
+    /// The name of the called method.
+    pub method: String,
+
+    /// The request's metadata, in order.
+    pub metadata: Vec<(HttpHeaderName, String)>,
+}
+
+/// The GRPCRoute rule that handles a request.
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug)]
+pub struct GrpcRouteSelection<'r> {
+    /// The route that handles the request.
+    pub route: &'r GrpcRoute,
+
+    /// The rule that handles the request.
+    pub rule: &'r GrpcRouteRule,
+
+    /// The match that selected the rule, or `None` if the rule has no matches
+    /// and therefore matches every request.
+    pub route_match: Option<&'r GrpcRouteMatch>,
+}
+
+/// A set of GRPCRoutes, prepared for selecting the rule that handles each
+/// request.
+///
+/// Building a table compiles the regular expressions in the routes' matches,
+/// so a table should be reused across requests for as long as the routes do
+/// not change.
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug)]
+pub struct GrpcRouteTable<'r> {
+    routes: Vec<&'r GrpcRoute>,
+    regexes: Regexes,
+}
+
+/// Compiled regular expressions, keyed by their pattern. An invalid pattern
+/// maps to `None`.
+#[derive(Clone, Debug, Default)]
+struct Regexes(HashMap<String, Option<regex::Regex>>);
+
+/// Selects the route rule that handles `request`.
+///
+/// When more than one rule matches the request, precedence is given to the
+/// match with, in order:
+///
+/// 1. the most characters in a matching non-wildcard hostname;
+/// 2. the most characters in a matching hostname;
+/// 3. an exact path match;
+/// 4. the most characters in a matching path prefix or regular expression;
+/// 5. a method match;
+/// 6. the most header matches;
+/// 7. the most query parameter matches.
+///
+/// A rule without a path match ranks as a `/` prefix match. Remaining ties go
+/// to the oldest route, then to the route that is first by
+/// `{namespace}/{name}`, and finally to the first matching rule within the
+/// route.
+///
+/// This builds an [`HttpRouteTable`] for a single request; use a table
+/// directly to route many requests.
+pub fn select_http_route<'r, R>(
+    routes: impl IntoIterator<Item = &'r R>,
+    request: &HttpRequest,
+) -> Option<HttpRouteSelection<'r, R>>
+where
+    R: HttpRouteRules + 'r,
+{
+    HttpRouteTable::new(routes).select(request)
+}
+
+/// Selects the GRPCRoute rule that handles `request`.
+///
+/// When more than one rule matches the request, precedence is given to the
+/// match with, in order:
+///
+/// 1. the most characters in a matching non-wildcard hostname;
+/// 2. the most characters in a matching hostname;
+/// 3. the most characters in a matching service;
+/// 4. the most characters in a matching method;
+/// 5. the most header matches.
+///
+/// Remaining ties go to the oldest route, then to the route that is first by
+/// `{namespace}/{name}`, and finally to the first matching rule within the
+/// route.
+///
+/// This builds a [`GrpcRouteTable`] for a single request; use a table
+/// directly to route many requests.
+#[cfg(feature = "experimental")]
+pub fn select_grpc_route<'r>(
+    routes: impl IntoIterator<Item = &'r GrpcRoute>,
+    request: &GrpcRequest,
+) -> Option<GrpcRouteSelection<'r>> {
+    GrpcRouteTable::new(routes).select(request)
+}
+
+/// Selects the highest-ranked match across all routes. A rule without matches
+/// is evaluated as a single empty match.
+fn select<'r, R, T, M, K>(
+    routes: impl IntoIterator<Item = &'r R>,
+    host: &str,
+    rules: impl Fn(&'r R) -> &'r [T],
+    matches: impl Fn(&'r T) -> &'r [M],
+    rank: impl Fn(Option<&'r M>, HostnameRank) -> Option<K>,
+) -> Option<Selected<'r, R, T, M>>
+where
+    R: Route + 'r,
+    T: 'r,
+    M: 'r,
+    K: Ord,
+{
+    let mut selected: Option<(K, Selected<'r, R, T, M>)> = None;
+    for route in routes {
+        let Some(hostname) = hostname_rank(route.hostnames(), host) else {
+            continue;
+        };
+        for rule in rules(route) {
+            let matches = matches(rule);
+            let candidates = if matches.is_empty() {
+                vec![None]
+            } else {
+                matches.iter().map(Some).collect()
+            };
+            for route_match in candidates {
+                let Some(rank) = rank(route_match, hostname) else {
+                    continue;
+                };
+                let preferred = selected.as_ref().map_or(true, |(best, (current, _, _))| {
+                    rank.cmp(best).then_with(|| age(*current, route)).is_gt()
+                });
+                if preferred {
+                    selected = Some((rank, (route, rule, route_match)));
+                }
+            }
+        }
+    }
+    selected.map(|(_, selection)| selection)
+}
+
+/// A route, one of its rules, and the rule's match that was selected.
+type Selected<'r, R, T, M> = (&'r R, &'r T, Option<&'r M>);
+
+/// The number of characters in a matching non-wildcard hostname and in a
+/// matching hostname.
+type HostnameRank = (usize, usize);
+
+/// The precedence of an HTTP match; greater ranks take precedence.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct HttpRank {
+    hostname: HostnameRank,
+    exact_path: bool,
+    path: usize,
+    method: bool,
+    headers: usize,
+    query_params: usize,
+}
+
+/// The precedence of a gRPC match; greater ranks take precedence.
+#[cfg(feature = "experimental")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct GrpcRank {
+    hostname: HostnameRank,
+    service: usize,
+    method: usize,
+    headers: usize,
+}
+
+/// Orders routes so that `Greater` is returned when `b` is older than `a` (or,
+/// when they are the same age, `b` comes first by namespace and name).
+fn age<R: kube::Resource>(a: &R, b: &R) -> Ordering {
+    let key = |r: &R| {
+        let meta = r.meta();
+        let created = meta.creation_timestamp.as_ref().map(|t| t.0);
+        (
+            created.is_none(),
+            created,
+            meta.namespace.clone(),
+            meta.name.clone(),
+        )
+    };
+    key(a).cmp(&key(b))
+}
+
+/// Returns the hostname precedence of a route for the request's host, or
+/// `None` if none of the route's hostnames match the host.
+fn hostname_rank(hostnames: &[Hostname], host: &str) -> Option<HostnameRank> {
+    if hostnames.is_empty() {
+        return Some((0, 0));
+    }
+    hostnames
+        .iter()
+        .filter(|hostname| hostname.matches(host))
+        .map(|hostname| {
+            let precise = if hostname.is_wildcard() {
+                0
+            } else {
+                hostname.len()
+            };
+            (precise, hostname.len())
+        })
+        .max()
+}
+
+/// Returns the precedence of an HTTP match for the request, or `None` if the
+/// request does not satisfy the match.
+fn http_rank(
+    route_match: Option<&HttpRouteMatch>,
+    request: &HttpRequest,
+    hostname: HostnameRank,
+    regexes: &Regexes,
+) -> Option<HttpRank> {
+    let default = HttpRouteMatch::default();
+    let route_match = route_match.unwrap_or(&default);
+    let mut rank = HttpRank {
+        hostname,
+        ..HttpRank::default()
+    };
+
+    match &route_match.path {
+        Some(HttpPathMatch::Exact { value }) => {
+            if request.path != *value {
+                return None;
+            }
+            rank.exact_path = true;
+            rank.path = value.len();
+        }
+        Some(HttpPathMatch::PathPrefix { value }) => {
+            let prefix = value.trim_end_matches('/');
+            let rest = request.path.strip_prefix(prefix)?;
+            if !(rest.is_empty() || rest.starts_with('/')) {
+                return None;
+            }
+            rank.path = value.len();
+        }
+        Some(HttpPathMatch::RegularExpression { value }) => {
+            if !regexes.matches(value, &request.path) {
+                return None;
+            }
+            rank.path = value.len();
+        }
+        Some(HttpPathMatch::Unknown { .. }) => return None,
+        None => rank.path = 1,
+    }
+
+    if let Some(method) = &route_match.method {
+        if request.method != *method {
+            return None;
+        }
+        rank.method = true;
+    }
+
+    rank.headers = headers_rank(
+        route_match.headers.as_deref().unwrap_or_default(),
+        &request.headers,
+        regexes,
+    )?;
+
+    let mut seen = Vec::<&str>::new();
+    for param in route_match.query_params.iter().flatten() {
+        let name = match param {
+            HttpQueryParamMatch::Exact { name, .. }
+            | HttpQueryParamMatch::RegularExpression { name, .. } => name,
+            HttpQueryParamMatch::Unknown { .. } => return None,
+        };
+        if seen.contains(&name.as_str()) {
+            continue;
+        }
+        seen.push(name);
+        let actual = request
+            .query_params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str());
+        let matched = match (param, actual) {
+            (HttpQueryParamMatch::Exact { value, .. }, Some(actual)) => value == actual,
+            (HttpQueryParamMatch::RegularExpression { value, .. }, Some(actual)) => {
+                regexes.matches(value, actual)
+            }
+            _ => false,
+        };
+        if !matched {
+            return None;
+        }
+        rank.query_params += 1;
+    }
+
+    Some(rank)
+}
+
+/// Returns the precedence of a gRPC match for the request, or `None` if the
+/// request does not satisfy the match.
+#[cfg(feature = "experimental")]
+fn grpc_rank(
+    route_match: Option<&GrpcRouteMatch>,
+    request: &GrpcRequest,
+    hostname: HostnameRank,
+    regexes: &Regexes,
+) -> Option<GrpcRank> {
+    let default = GrpcRouteMatch::default();
+    let route_match = route_match.unwrap_or(&default);
+    let mut rank = GrpcRank {
+        hostname,
+        ..GrpcRank::default()
+    };
+
+    let (service, method, regex) = match &route_match.method {
+        Some(GrpcMethodMatch::Exact { service, method }) => (service, method, false),
+        Some(GrpcMethodMatch::RegularExpression { service, method }) => (service, method, true),
+        Some(GrpcMethodMatch::Unknown { .. }) => return None,
+        None => (&None, &None, false),
+    };
+    let matches = |pattern: &str, value: &str| {
+        if regex {
+            regexes.matches(pattern, value)
+        } else {
+            pattern == value
+        }
+    };
+    if let Some(service) = service.as_deref().filter(|s| !s.is_empty()) {
+        if !matches(service, &request.service) {
+            return None;
+        }
+        rank.service = service.len();
+    }
+    if let Some(method) = method.as_deref().filter(|m| !m.is_empty()) {
+        if !matches(method, &request.method) {
+            return None;
+        }
+        rank.method = method.len();
+    }
+
+    rank.headers = headers_rank(
+        route_match.headers.as_deref().unwrap_or_default(),
+        &request.metadata,
+        regexes,
+    )?;
+
+    Some(rank)
+}
+
+/// Returns the number of header matches that the headers satisfy, or `None`
+/// if any match is not satisfied. Only the first match for each
+/// (case-insensitive) header name is considered.
+fn headers_rank(
+    matches: &[HttpHeaderMatch],
+    headers: &[(String, String)],
+    regexes: &Regexes,
+) -> Option<usize> {
+    let mut seen = Vec::<&str>::new();
+    for header in matches {
+        let name = match header {
+            HttpHeaderMatch::Exact { name, .. }
+            | HttpHeaderMatch::RegularExpression { name, .. } => name,
+            HttpHeaderMatch::Unknown { .. } => return None,
+        };
+        if seen.iter().any(|s| s.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+        seen.push(name);
+        let actual = headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str());
+        let matched = match (header, actual) {
+            (HttpHeaderMatch::Exact { value, .. }, Some(actual)) => value == actual,
+            (HttpHeaderMatch::RegularExpression { value, .. }, Some(actual)) => {
+                regexes.matches(value, actual)
+            }
+            _ => false,
+        };
+        if !matched {
+            return None;
+        }
+    }
+    Some(seen.len())
+}
+
+// === impl HttpRouteTable ===
+
+impl<'r, R: HttpRouteRules + 'r> HttpRouteTable<'r, R> {
+    /// Prepares `routes` for routing requests.
+    pub fn new(routes: impl IntoIterator<Item = &'r R>) -> Self {
+        let routes = routes.into_iter().collect::<Vec<_>>();
+        let mut regexes = Regexes::default();
+        for route_match in routes
+            .iter()
+            .flat_map(|route| route.rules())
+            .flat_map(|rule| rule.matches.iter().flatten())
+        {
+            if let Some(HttpPathMatch::RegularExpression { value }) = &route_match.path {
+                regexes.compile(value);
+            }
+            regexes.compile_headers(route_match.headers.as_deref().unwrap_or_default());
+            for param in route_match.query_params.iter().flatten() {
+                if let HttpQueryParamMatch::RegularExpression { value, .. } = param {
+                    regexes.compile(value);
+                }
+            }
+        }
+        Self { routes, regexes }
+    }
+
+    /// Selects the route rule that handles `request`, as described by
+    /// [`select_http_route`].
+    pub fn select(&self, request: &HttpRequest) -> Option<HttpRouteSelection<'r, R>> {
+        let (route, rule, route_match) = select(
+            self.routes.iter().copied(),
+            &request.host,
+            |route| route.rules(),
+            |rule| rule.matches.as_deref().unwrap_or_default(),
+            |route_match, hostname| http_rank(route_match, request, hostname, &self.regexes),
+        )?;
+        Some(HttpRouteSelection {
+            route,
+            rule,
+            route_match,
+        })
+    }
+}
+
+// === impl GrpcRouteTable ===
+
+#[cfg(feature = "experimental")]
+impl<'r> GrpcRouteTable<'r> {
+    /// Prepares `routes` for routing requests.
+    pub fn new(routes: impl IntoIterator<Item = &'r GrpcRoute>) -> Self {
+        let routes = routes.into_iter().collect::<Vec<_>>();
+        let mut regexes = Regexes::default();
+        for route_match in routes
+            .iter()
+            .flat_map(|route| route.spec.rules.iter().flatten())
+            .flat_map(|rule| rule.matches.iter().flatten())
+        {
+            if let Some(GrpcMethodMatch::RegularExpression { service, method }) =
+                &route_match.method
+            {
+                for pattern in [service, method].into_iter().flatten() {
+                    regexes.compile(pattern);
+                }
+            }
+            regexes.compile_headers(route_match.headers.as_deref().unwrap_or_default());
+        }
+        Self { routes, regexes }
+    }
+
+    /// Selects the GRPCRoute rule that handles `request`, as described by
+    /// [`select_grpc_route`].
+    pub fn select(&self, request: &GrpcRequest) -> Option<GrpcRouteSelection<'r>> {
+        let (route, rule, route_match) = select(
+            self.routes.iter().copied(),
+            &request.authority,
+            |route| route.spec.rules.as_deref().unwrap_or_default(),
+            |rule| rule.matches.as_deref().unwrap_or_default(),
+            |route_match, hostname| grpc_rank(route_match, request, hostname, &self.regexes),
+        )?;
+        Some(GrpcRouteSelection {
+            route,
+            rule,
+            route_match,
+        })
+    }
+}
+
+// === impl Regexes ===
+
+impl Regexes {
+    /// Compiles `pattern`, anchored so that it must match an entire value.
+    fn compile(&mut self, pattern: &str) {
+        if !self.0.contains_key(pattern) {
+            let regex = regex::Regex::new(&format!("^(?:{pattern})$")).ok();
+            self.0.insert(pattern.to_string(), regex);
+        }
+    }
+
+    fn compile_headers(&mut self, matches: &[HttpHeaderMatch]) {
+        for header in matches {
+            if let HttpHeaderMatch::RegularExpression { value, .. } = header {
+                self.compile(value);
+            }
+        }
+    }
+
+    /// Returns true if the compiled `pattern` matches all of `value`. Invalid
+    /// (or uncompiled) patterns never match.
+    fn matches(&self, pattern: &str, value: &str) -> bool {
+        self.0
+            .get(pattern)
+            .and_then(Option::as_ref)
+            .map_or(false, |re| re.is_match(value))
+    }
+}
+
+// === impl HttpRequest ===
+
+impl HttpRequest {
+    /// Describes an `http` request for `path_and_query` on `host`. A port in
+    /// `host` and the query string in `path_and_query` are split off.
+    pub fn new(method: impl Into<HttpMethod>, host: &str, path_and_query: &str) -> Self {
+        let (host, port) = split_port(host);
+        let (path, query) = path_and_query
+            .split_once('?')
+            .unwrap_or((path_and_query, ""));
+        let query_params = query
+            .split('&')
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                let (name, value) = p.split_once('=').unwrap_or((p, ""));
+                (name.to_string(), value.to_string())
+            })
+            .collect();
+        Self {
+            scheme: "http".to_string(),
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+            method: method.into(),
+            headers: Vec::new(),
+            query_params,
+        }
+    }
+
+    /// Sets the request's scheme.
+    pub fn scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.scheme = scheme.into();
+        self
+    }
+
+    /// Returns the request's path and query string.
+    pub fn path_and_query(&self) -> String {
+        let mut path = self.path.clone();
+        for (i, (name, value)) in self.query_params.iter().enumerate() {
+            path.push(if i == 0 { '?' } else { '&' });
+            path.push_str(name);
+            if !value.is_empty() {
+                path.push('=');
+                path.push_str(value);
+            }
+        }
+        path
+    }
+
+    /// Adds a header to the request.
+    pub fn header(mut self, name: impl Into<HttpHeaderName>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+// === impl GrpcRequest ===
+
+#[cfg(feature = "experimental")]
+impl GrpcRequest {
+    /// Describes a call to `service`'s `method` on `authority`. A port in
+    /// `authority` is split off.
+    pub fn new(authority: &str, service: impl Into<String>, method: impl Into<String>) -> Self {
+        Self {
+            authority: split_port(authority).0.to_string(),
+            service: service.into(),
+            method: method.into(),
+            metadata: Vec::new(),
+        }
+    }
+
+    /// Adds a metadata entry to the request.
+    pub fn metadata(mut self, name: impl Into<HttpHeaderName>, value: impl Into<String>) -> Self {
+        self.metadata.push((name.into(), value.into()));
+        self
+    }
+}
+
+fn split_port(host: &str) -> (&str, Option<PortNumber>) {
+    match host.rsplit_once(':') {
+        Some((h, port)) if port.bytes().all(|b| b.is_ascii_digit()) => (h, port.parse().ok()),
+        _ => (host, None),
+    }
+}
+
+// === impl HttpRouteRules ===
+
+impl HttpRouteRules for HttpRoute {
+    fn rules(&self) -> &[HttpRouteRule] {
+        self.spec.rules.as_deref().unwrap_or_default()
+    }
+}
+
+impl HttpRouteRules for v1::HttpRoute {
+    fn rules(&self) -> &[HttpRouteRule] {
+        self.spec.rules.as_deref().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn route(name: &str, created: &str, spec: serde_json::Value) -> HttpRoute {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "HTTPRoute",
+            "metadata": {
+                "name": name,
+                "namespace": "default",
+                "creationTimestamp": created,
+            },
+            "spec": spec,
+        }))
+        .unwrap()
+    }
+
+    /// Returns the route name and rule index that handle the request.
+    fn select(routes: &[HttpRoute], request: &HttpRequest) -> Option<(String, usize)> {
+        let selection = select_http_route(routes, request)?;
+        let rules = selection.route.rules();
+        let idx = rules
+            .iter()
+            .position(|r| std::ptr::eq(r, selection.rule))
+            .unwrap();
+        Some((selection.route.metadata.name.clone().unwrap(), idx))
+    }
+
+    #[test]
+    fn path_matches() {
+        let routes = [route(
+            "paths",
+            "2024-01-01T00:00:00Z",
+            serde_json::json!({
+                "rules": [
+                    { "matches": [{ "path": { "type": "PathPrefix", "value": "/foo/" } }] },
+                    { "matches": [{ "path": { "type": "Exact", "value": "/foo" } }] },
+                    { "matches": [{ "path": { "type": "PathPrefix", "value": "/" } }] },
+                ]
+            }),
+        )];
+        let get = |path| select(&routes, &HttpRequest::new("GET", "example.com", path));
+        assert_eq!(get("/foo"), Some(("paths".to_string(), 1)));
+        assert_eq!(get("/foo/bar"), Some(("paths".to_string(), 0)));
+        assert_eq!(get("/foobar"), Some(("paths".to_string(), 2)));
+    }
+
+    #[test]
+    fn regex_matches() {
+        let routes = [route(
+            "regexes",
+            "2024-01-01T00:00:00Z",
+            serde_json::json!({
+                "rules": [
+                    { "matches": [{ "path": { "type": "RegularExpression", "value": "/(unclosed" } }] },
+                    { "matches": [{ "path": { "type": "RegularExpression", "value": "/v[0-9]+" } }] },
+                    {
+                        "matches": [{
+                            "headers": [{ "type": "RegularExpression", "name": "x-tenant", "value": "a|b" }],
+                        }],
+                    },
+                ]
+            }),
+        )];
+        let table = HttpRouteTable::new(&routes);
+        let get = |path, tenant| {
+            let request = HttpRequest::new("GET", "example.com", path).header("x-tenant", tenant);
+            let selection = table.select(&request)?;
+            let rules = selection.route.spec.rules.as_deref().unwrap_or_default();
+            rules.iter().position(|r| std::ptr::eq(r, selection.rule))
+        };
+        assert_eq!(get("/v1", "c"), Some(1));
+        assert_eq!(get("/v1/foo", "c"), None, "the entire path must match");
+        assert_eq!(
+            get("/(unclosed", "a"),
+            Some(2),
+            "invalid patterns never match"
+        );
+        assert_eq!(get("/", "ab"), None);
+    }
+
+    #[test]
+    fn regex_outranks_catch_all() {
+        let routes = [route(
+            "regexes",
+            "2024-01-01T00:00:00Z",
+            serde_json::json!({
+                "rules": [
+                    { "matches": [{ "path": { "type": "PathPrefix", "value": "/" } }] },
+                    { "matches": [{ "path": { "type": "RegularExpression", "value": "/v[0-9]+" } }] },
+                    { "matches": [{ "path": { "type": "PathPrefix", "value": "/v1/api" } }] },
+                ]
+            }),
+        )];
+        let table = HttpRouteTable::new(&routes);
+        let get = |path| {
+            let selection = table.select(&HttpRequest::new("GET", "example.com", path))?;
+            let rules = selection.route.spec.rules.as_deref().unwrap_or_default();
+            rules.iter().position(|r| std::ptr::eq(r, selection.rule))
+        };
+        assert_eq!(get("/v1"), Some(1));
+        assert_eq!(get("/v1/api"), Some(2));
+        assert_eq!(get("/other"), Some(0));
+    }
+
+    #[test]
+    fn precedence() {
+        let routes = [
+            route(
+                "newer",
+                "2024-01-02T00:00:00Z",
+                serde_json::json!({
+                    "hostnames": ["*.example.com"],
+                    "rules": [
+                        {
+                            "matches": [{
+                                "path": { "type": "PathPrefix", "value": "/api" },
+                                "headers": [
+                                    { "type": "Exact", "name": "x-env", "value": "canary" },
+                                    { "type": "Exact", "name": "X-Env", "value": "ignored" },
+                                ],
+                            }]
+                        },
+                        {
+                            "matches": [{
+                                "path": { "type": "PathPrefix", "value": "/api" },
+                                "method": "POST",
+                            }]
+                        },
+                        {
+                            "matches": [{
+                                "path": { "type": "PathPrefix", "value": "/api" },
+                                "queryParams": [{ "type": "Exact", "name": "v", "value": "2" }],
+                            }]
+                        },
+                    ]
+                }),
+            ),
+            route(
+                "older",
+                "2024-01-01T00:00:00Z",
+                serde_json::json!({
+                    "hostnames": ["*.example.com"],
+                    "rules": [{
+                        "matches": [{
+                            "path": { "type": "PathPrefix", "value": "/api" },
+                            "queryParams": [{ "type": "Exact", "name": "v", "value": "2" }],
+                        }]
+                    }]
+                }),
+            ),
+            route(
+                "exact-host",
+                "2024-01-03T00:00:00Z",
+                serde_json::json!({
+                    "hostnames": ["admin.example.com"],
+                    "rules": [{}]
+                }),
+            ),
+        ];
+
+        let request = HttpRequest::new("POST", "api.example.com:8080", "/api/users?v=2")
+            .header("X-ENV", "canary");
+        assert_eq!(select(&routes, &request), Some(("newer".to_string(), 1)));
+
+        let request =
+            HttpRequest::new("GET", "api.example.com", "/api/users?v=2").header("X-ENV", "canary");
+        assert_eq!(select(&routes, &request), Some(("newer".to_string(), 0)));
+
+        let request = HttpRequest::new("GET", "api.example.com", "/api/users?v=2");
+        assert_eq!(select(&routes, &request), Some(("older".to_string(), 0)));
+
+        let request = HttpRequest::new("GET", "admin.example.com", "/api?v=2");
+        assert_eq!(
+            select(&routes, &request),
+            Some(("exact-host".to_string(), 0))
+        );
+
+        let request = HttpRequest::new("GET", "example.com", "/api");
+        assert_eq!(select(&routes, &request), None);
+    }
+
+    #[test]
+    #[cfg(feature = "experimental")]
+    fn grpc_precedence() {
+        let route: GrpcRoute = serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1alpha2",
+            "kind": "GRPCRoute",
+            "metadata": { "name": "grpc", "namespace": "default" },
+            "spec": {
+                "hostnames": ["grpc.example.com"],
+                "rules": [
+                    { "matches": [{ "method": { "service": "foo.Bar" } }] },
+                    { "matches": [{ "method": { "service": "foo.Bar", "method": "Get" } }] },
+                    {
+                        "matches": [{
+                            "method": {
+                                "type": "RegularExpression",
+                                "service": "foo.Bar",
+                                "method": "Get.*"
+                            },
+                            "headers": [{ "type": "Exact", "name": "version", "value": "v2" }],
+                        }]
+                    },
+                    {}
+                ]
+            }
+        }))
+        .unwrap();
+
+        let select = |request: &GrpcRequest| {
+            let selection = select_grpc_route([&route], request)?;
+            let rules = selection.route.spec.rules.as_deref().unwrap();
+            rules.iter().position(|r| std::ptr::eq(r, selection.rule))
+        };
+        let request = |method| GrpcRequest::new("grpc.example.com:443", "foo.Bar", method);
+        assert_eq!(select(&request("Get")), Some(1));
+        assert_eq!(select(&request("List")), Some(0));
+        assert_eq!(
+            select(&request("GetAll").metadata("Version", "v2")),
+            Some(2)
+        );
+        assert_eq!(
+            select(&GrpcRequest::new("grpc.example.com", "baz.Qux", "Get")),
+            Some(3)
+        );
+        assert_eq!(
+            select(&GrpcRequest::new("example.com", "foo.Bar", "Get")),
+            None
+        );
+    }
+}
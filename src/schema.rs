@@ -0,0 +1,614 @@
+//! Schema support for the upstream validation markers.
+//!
+//! Most markers are expressed with `#[schemars(...)]` attributes on the types
+//! themselves and CEL rules are attached with [`kube::CELSchema`]. This module
+//! holds the patterns shared by those attributes, as well as the schemas for
+//! string enums and for the internally tagged enums, which upstream models as
+//! a single object with a `type` discriminator.
+
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject},
+    JsonSchema,
+};
+
+pub(crate) const GROUP: &str =
+    r"^$|^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$";
+
+pub(crate) const KIND: &str = r"^[a-zA-Z]([-a-zA-Z0-9]*[a-zA-Z0-9])?$";
+
+pub(crate) const NAMESPACE: &str = r"^[a-z0-9]([-a-z0-9]*[a-z0-9])?$";
+
+pub(crate) const SECTION_NAME: &str =
+    r"^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$";
+
+pub(crate) const HOSTNAME: &str =
+    r"^(\*\.)?[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$";
+
+pub(crate) const PRECISE_HOSTNAME: &str =
+    r"^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$";
+
+pub(crate) const GATEWAY_CONTROLLER: &str = r"^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*\/[A-Za-z0-9\/\-._~%!$&'()*+,;=:]+$";
+
+pub(crate) const PROTOCOL: &str = r"^[a-zA-Z0-9]([-a-zA-Z0-9]*[a-zA-Z0-9])?$|[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*\/[A-Za-z0-9]+$";
+
+pub(crate) const ADDRESS_TYPE: &str = r"^Hostname|IPAddress|NamedAddress|[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*\/[A-Za-z0-9\/\-._~%!$&'()*+,;=:]+$";
+
+pub(crate) const HEADER_NAME: &str = r"^[A-Za-z0-9!#$%&'*+\-.^_\x60|~]+$";
+
+pub(crate) const ANNOTATION_KEY: &str = r"^(([A-Za-z0-9][-A-Za-z0-9_.]{0,251})?[A-Za-z0-9]\/)?([A-Za-z0-9][-A-Za-z0-9_.]{0,61})?[A-Za-z0-9]$";
+
+pub(crate) const LABEL_VALUE: &str = r"^(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9])?$";
+
+pub(crate) const DURATION: &str = r"^([0-9]{1,5}(h|m|s|ms)){1,4}$";
+
+pub(crate) const PATH: &str = r"^(?:[-A-Za-z0-9/._~!$&'()*+,;=:@]|[%][0-9a-fA-F]{2})+$";
+
+#[cfg(feature = "experimental")]
+pub(crate) const ABSOLUTE_URI: &str = r"^(([^:/?#]+):)(//([^/?#]*))([^?#]*)(\?([^#]*))?(#(.*))?";
+
+pub(crate) const CORS_ORIGIN: &str =
+    r"^(\*|https?:\/\/(\*|(\*\.)?(([\w-]+\.?)+)?[\w-]+)(:\d{1,5})?)$";
+
+// === defaults ===
+
+pub(crate) fn gateway_group() -> Option<String> {
+    Some("gateway.networking.k8s.io".to_string())
+}
+
+pub(crate) fn gateway_kind() -> Option<String> {
+    Some("Gateway".to_string())
+}
+
+pub(crate) fn terminate() -> Option<crate::TlsModeType> {
+    Some(crate::TlsModeType::Terminate)
+}
+
+pub(crate) fn same() -> Option<crate::FromNamespaces> {
+    Some(crate::FromNamespaces::Same)
+}
+
+pub(crate) fn none() -> Option<crate::FromNamespaces> {
+    Some(crate::FromNamespaces::None)
+}
+
+pub(crate) fn no_listener_namespaces() -> Option<crate::ListenerNamespaces> {
+    Some(crate::ListenerNamespaces {
+        from: none(),
+        selector: None,
+    })
+}
+
+pub(crate) fn ip_address() -> Option<crate::AddressType> {
+    Some(crate::AddressType::IPAddress)
+}
+
+pub(crate) fn found() -> Option<u16> {
+    Some(302)
+}
+
+pub(crate) fn core_group() -> Option<String> {
+    Some(String::new())
+}
+
+pub(crate) fn secret_kind() -> Option<String> {
+    Some("Secret".to_string())
+}
+
+pub(crate) fn service_kind() -> Option<String> {
+    Some("Service".to_string())
+}
+
+pub(crate) fn five() -> Option<i32> {
+    Some(5)
+}
+
+pub(crate) fn one_hundred() -> Option<i32> {
+    Some(100)
+}
+
+pub(crate) fn cookie() -> Option<crate::SessionPersistenceType> {
+    Some(crate::SessionPersistenceType::Cookie)
+}
+
+pub(crate) fn session() -> Option<crate::CookieLifetimeType> {
+    Some(crate::CookieLifetimeType::Session)
+}
+
+// === string enums ===
+
+fn string_enum(values: &[&str]) -> Schema {
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(values.iter().map(|v| (*v).into()).collect()),
+        ..SchemaObject::default()
+    })
+}
+
+pub(crate) fn tls_mode_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["Terminate", "Passthrough"])
+}
+
+pub(crate) fn from_namespaces(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["All", "Selector", "Same"])
+}
+
+pub(crate) fn listener_from_namespaces(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["All", "Selector", "Same", "None"])
+}
+
+pub(crate) fn session_persistence_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["Cookie", "Header"])
+}
+
+pub(crate) fn cookie_lifetime_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["Permanent", "Session"])
+}
+
+pub(crate) fn http_method(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&[
+        "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH",
+    ])
+}
+
+/// CORS methods are bounded by `maxItems` and may include a `*` wildcard.
+pub(crate) fn http_methods_with_wildcard(gen: &mut SchemaGenerator) -> Schema {
+    let mut schema = gen.subschema_for::<Option<Vec<String>>>().into_object();
+    let array = schema.array();
+    array.max_items = Some(9);
+    array.items = Some(
+        string_enum(&[
+            "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH", "*",
+        ])
+        .into(),
+    );
+    Schema::Object(schema)
+}
+
+pub(crate) fn external_auth_protocol(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["HTTP", "GRPC"])
+}
+
+#[cfg(feature = "experimental")]
+pub(crate) fn well_known_ca_certificates_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["System"])
+}
+
+#[cfg(feature = "experimental")]
+pub(crate) fn subject_alt_name_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["Hostname", "URI"])
+}
+
+pub(crate) fn redirect_scheme(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["http", "https"])
+}
+
+pub(crate) fn redirect_status_code(_: &mut SchemaGenerator) -> Schema {
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::Integer.into()),
+        enum_values: Some(vec![301.into(), 302.into()]),
+        ..SchemaObject::default()
+    })
+}
+
+/// TLS options are bounded by a `maxProperties` marker, which schemars cannot
+/// express as an attribute.
+pub(crate) fn tls_options(gen: &mut SchemaGenerator) -> Schema {
+    let mut schema = gen
+        .subschema_for::<std::collections::BTreeMap<String, String>>()
+        .into_object();
+    schema.object().max_properties = Some(16);
+    Schema::Object(schema)
+}
+
+/// Infrastructure labels are bounded by a `maxProperties` marker, and their
+/// values are constrained like Kubernetes label values.
+pub(crate) fn infrastructure_labels(gen: &mut SchemaGenerator) -> Schema {
+    let mut schema = gen
+        .subschema_for::<Option<std::collections::BTreeMap<String, String>>>()
+        .into_object();
+    let object = schema.object();
+    object.max_properties = Some(8);
+    let mut value = SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        ..SchemaObject::default()
+    };
+    value.string().max_length = Some(63);
+    value.string().pattern = Some(LABEL_VALUE.to_string());
+    object.additional_properties = Some(Box::new(value.into()));
+    Schema::Object(schema)
+}
+
+/// Infrastructure annotations are bounded by a `maxProperties` marker, and
+/// their values are bounded like other annotation values.
+pub(crate) fn infrastructure_annotations(gen: &mut SchemaGenerator) -> Schema {
+    let mut schema = gen
+        .subschema_for::<Option<std::collections::BTreeMap<String, String>>>()
+        .into_object();
+    let object = schema.object();
+    object.max_properties = Some(8);
+    let mut value = SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        ..SchemaObject::default()
+    };
+    value.string().max_length = Some(4096);
+    object.additional_properties = Some(Box::new(value.into()));
+    Schema::Object(schema)
+}
+
+// === tagged enums ===
+
+/// Implements `JsonSchema` for an internally tagged enum by delegating to a
+/// struct that describes its upstream (flattened) shape.
+macro_rules! tagged_schema {
+    ($ty:ty => $schema:ty) => {
+        impl JsonSchema for $ty {
+            fn schema_name() -> String {
+                stringify!($ty).to_string()
+            }
+
+            fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+                <$schema>::json_schema(gen)
+            }
+        }
+    };
+}
+
+/// HTTPPathMatch describes how to select a HTTP route by matching the HTTP
+/// request path.
+#[derive(serde::Serialize, kube::CELSchema)]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? self.value.startsWith('/') : true").message("value must be an absolute path and start with '/' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.contains('//') : true").message("must not contain '//' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.contains('/./') : true").message("must not contain '/./' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.contains('/../') : true").message("must not contain '/../' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.contains('%2f') : true").message("must not contain '%2f' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.contains('%2F') : true").message("must not contain '%2F' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.contains('#') : true").message("must not contain '#' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.endsWith('/..') : true").message("must not end with '/..' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("(self.type in ['Exact','PathPrefix']) ? !self.value.endsWith('/.') : true").message("must not end with '/.' when type one of ['Exact', 'PathPrefix']"))]
+#[cel_validate(rule = Rule::new("self.type in ['Exact','PathPrefix'] || self.type == 'RegularExpression'").message("type must be one of ['Exact', 'PathPrefix', 'RegularExpression']"))]
+#[cel_validate(rule = Rule::new(r#"(self.type in ['Exact','PathPrefix']) ? self.value.matches(r"""^(?:[-A-Za-z0-9/._~!$&'()*+,;=:@]|[%][0-9a-fA-F]{2})+$""") : true"#).message("must only contain valid characters (matching ^(?:[-A-Za-z0-9/._~!$&'()*+,;=:@]|[%][0-9a-fA-F]{2})+$) for types ['Exact', 'PathPrefix']"))]
+struct HttpPathMatchSchema {
+    /// Type specifies how to match against the path Value.
+    #[serde(default)]
+    #[schemars(schema_with = "path_match_type")]
+    r#type: Option<String>,
+
+    /// Value of the HTTP path to match against.
+    #[serde(default)]
+    #[schemars(length(max = 1024))]
+    value: Option<String>,
+}
+
+fn path_match_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(crate::HttpPathMatch::KNOWN_TYPES)
+}
+
+tagged_schema!(crate::HttpPathMatch => HttpPathMatchSchema);
+
+/// HTTPHeaderMatch describes how to select a HTTP route by matching HTTP
+/// request headers.
+#[derive(serde::Serialize, schemars::JsonSchema)]
+struct HttpHeaderMatchSchema {
+    /// Type specifies how to match against the value of the header.
+    #[serde(default)]
+    #[schemars(schema_with = "string_match_type")]
+    r#type: Option<String>,
+
+    /// Name is the name of the HTTP Header to be matched. Name matching MUST be
+    /// case insensitive.
+    #[schemars(length(min = 1, max = 256), regex = "HEADER_NAME")]
+    name: String,
+
+    /// Value is the value of HTTP Header to be matched.
+    #[schemars(length(min = 1, max = 4096))]
+    value: String,
+}
+
+fn string_match_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(crate::HttpHeaderMatch::KNOWN_TYPES)
+}
+
+tagged_schema!(crate::HttpHeaderMatch => HttpHeaderMatchSchema);
+
+/// HTTPQueryParamMatch describes how to select a HTTP route by matching HTTP
+/// query parameters.
+#[derive(serde::Serialize, schemars::JsonSchema)]
+struct HttpQueryParamMatchSchema {
+    /// Type specifies how to match against the value of the query parameter.
+    #[serde(default)]
+    #[schemars(schema_with = "string_match_type")]
+    r#type: Option<String>,
+
+    /// Name is the name of the HTTP query param to be matched.
+    #[schemars(length(min = 1, max = 256), regex = "HEADER_NAME")]
+    name: String,
+
+    /// Value is the value of HTTP query param to be matched.
+    #[schemars(length(min = 1, max = 1024))]
+    value: String,
+}
+
+tagged_schema!(crate::HttpQueryParamMatch => HttpQueryParamMatchSchema);
+
+/// HTTPPathModifier defines configuration for path modifiers.
+#[derive(serde::Serialize, kube::CELSchema)]
+#[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("self.type == 'ReplaceFullPath' ? has(self.replaceFullPath) : true").message("replaceFullPath must be specified when type is set to 'ReplaceFullPath'"))]
+#[cel_validate(rule = Rule::new("has(self.replaceFullPath) ? self.type == 'ReplaceFullPath' : true").message("type must be 'ReplaceFullPath' when replaceFullPath is set"))]
+#[cel_validate(rule = Rule::new("self.type == 'ReplacePrefixMatch' ? has(self.replacePrefixMatch) : true").message("replacePrefixMatch must be specified when type is set to 'ReplacePrefixMatch'"))]
+#[cel_validate(rule = Rule::new("has(self.replacePrefixMatch) ? self.type == 'ReplacePrefixMatch' : true").message("type must be 'ReplacePrefixMatch' when replacePrefixMatch is set"))]
+struct HttpPathModifierSchema {
+    /// Type defines the type of path modifier.
+    #[schemars(schema_with = "path_modifier_type")]
+    r#type: String,
+
+    /// ReplaceFullPath specifies the value with which to replace the full path
+    /// of a request during a rewrite or redirect.
+    #[serde(default)]
+    #[schemars(length(max = 1024))]
+    replace_full_path: Option<String>,
+
+    /// ReplacePrefixMatch specifies the value with which to replace the prefix
+    /// match of a request during a rewrite or redirect.
+    #[serde(default)]
+    #[schemars(length(max = 1024))]
+    replace_prefix_match: Option<String>,
+}
+
+fn path_modifier_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(crate::HttpPathModifier::KNOWN_TYPES)
+}
+
+tagged_schema!(crate::HttpPathModifier => HttpPathModifierSchema);
+
+/// HTTPRouteFilter defines processing steps that must be completed during the
+/// request or response lifecycle.
+#[derive(serde::Serialize, kube::CELSchema)]
+#[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("!(has(self.requestHeaderModifier) && self.type != 'RequestHeaderModifier')").message("filter.requestHeaderModifier must be nil if the filter.type is not RequestHeaderModifier"))]
+#[cel_validate(rule = Rule::new("!(!has(self.requestHeaderModifier) && self.type == 'RequestHeaderModifier')").message("filter.requestHeaderModifier must be specified for RequestHeaderModifier filter.type"))]
+#[cel_validate(rule = Rule::new("!(has(self.responseHeaderModifier) && self.type != 'ResponseHeaderModifier')").message("filter.responseHeaderModifier must be nil if the filter.type is not ResponseHeaderModifier"))]
+#[cel_validate(rule = Rule::new("!(!has(self.responseHeaderModifier) && self.type == 'ResponseHeaderModifier')").message("filter.responseHeaderModifier must be specified for ResponseHeaderModifier filter.type"))]
+#[cel_validate(rule = Rule::new("!(has(self.requestMirror) && self.type != 'RequestMirror')").message("filter.requestMirror must be nil if the filter.type is not RequestMirror"))]
+#[cel_validate(rule = Rule::new("!(!has(self.requestMirror) && self.type == 'RequestMirror')").message("filter.requestMirror must be specified for RequestMirror filter.type"))]
+#[cel_validate(rule = Rule::new("!(has(self.requestRedirect) && self.type != 'RequestRedirect')").message("filter.requestRedirect must be nil if the filter.type is not RequestRedirect"))]
+#[cel_validate(rule = Rule::new("!(!has(self.requestRedirect) && self.type == 'RequestRedirect')").message("filter.requestRedirect must be specified for RequestRedirect filter.type"))]
+#[cel_validate(rule = Rule::new("!(has(self.urlRewrite) && self.type != 'URLRewrite')").message("filter.urlRewrite must be nil if the filter.type is not URLRewrite"))]
+#[cel_validate(rule = Rule::new("!(!has(self.urlRewrite) && self.type == 'URLRewrite')").message("filter.urlRewrite must be specified for URLRewrite filter.type"))]
+#[cel_validate(rule = Rule::new("!(has(self.extensionRef) && self.type != 'ExtensionRef')").message("filter.extensionRef must be nil if the filter.type is not ExtensionRef"))]
+#[cel_validate(rule = Rule::new("!(!has(self.extensionRef) && self.type == 'ExtensionRef')").message("filter.extensionRef must be specified for ExtensionRef filter.type"))]
+#[cel_validate(rule = Rule::new("!(has(self.cors) && self.type != 'CORS')").message("filter.cors must be nil if the filter.type is not CORS"))]
+#[cel_validate(rule = Rule::new("!(!has(self.cors) && self.type == 'CORS')").message("filter.cors must be specified for CORS filter.type"))]
+#[cel_validate(rule = Rule::new("!(has(self.externalAuth) && self.type != 'ExternalAuth')").message("filter.externalAuth must be nil if the filter.type is not ExternalAuth"))]
+#[cel_validate(rule = Rule::new("!(!has(self.externalAuth) && self.type == 'ExternalAuth')").message("filter.externalAuth must be specified for ExternalAuth filter.type"))]
+struct HttpRouteFilterSchema {
+    /// Type identifies the type of filter to apply.
+    #[schemars(schema_with = "http_route_filter_type")]
+    r#type: String,
+
+    /// RequestHeaderModifier defines a schema for a filter that modifies
+    /// request headers.
+    #[serde(default)]
+    request_header_modifier: Option<crate::HttpRequestHeaderFilter>,
+
+    /// ResponseHeaderModifier defines a schema for a filter that modifies
+    /// response headers.
+    #[serde(default)]
+    response_header_modifier: Option<crate::HttpRequestHeaderFilter>,
+
+    /// RequestMirror defines a schema for a filter that mirrors requests.
+    #[serde(default)]
+    request_mirror: Option<crate::HttpRequestMirrorFilter>,
+
+    /// RequestRedirect defines a schema for a filter that responds to the
+    /// request with an HTTP redirection.
+    #[serde(default)]
+    request_redirect: Option<crate::HttpRequestRedirectFilter>,
+
+    /// URLRewrite defines a schema for a filter that modifies a request during
+    /// forwarding.
+    #[serde(default)]
+    url_rewrite: Option<crate::HttpUrlRewriteFilter>,
+
+    /// ExtensionRef is an optional, implementation-specific extension to the
+    /// "filter" behavior.
+    #[serde(default)]
+    extension_ref: Option<crate::LocalObjectReference>,
+
+    /// CORS defines a schema for a filter that responds to the cross-origin
+    /// request based on HTTP response header.
+    #[serde(default)]
+    cors: Option<crate::HttpCorsFilter>,
+
+    /// ExternalAuth configures settings related to sending request details to
+    /// an external auth service.
+    #[serde(default)]
+    external_auth: Option<crate::HttpExternalAuthFilter>,
+}
+
+fn http_route_filter_type(_: &mut SchemaGenerator) -> Schema {
+    string_enum(crate::HttpRouteFilter::KNOWN_TYPES)
+}
+
+tagged_schema!(crate::HttpRouteFilter => HttpRouteFilterSchema);
+
+#[cfg(feature = "experimental")]
+mod exp {
+    use super::*;
+
+    /// GRPCMethodMatch describes how to select a gRPC route by matching the
+    /// gRPC request service and/or method.
+    #[derive(serde::Serialize, kube::CELSchema)]
+    #[cel_validate(rule = Rule::new("has(self.type) ? has(self.service) || has(self.method) : true").message("One or both of 'service' or 'method' must be specified"))]
+    #[cel_validate(rule = Rule::new(r#"(!has(self.type) || self.type == 'Exact') && has(self.service) ? self.service.matches(r"""^(?i)\.?[a-z_][a-z_0-9]*(\.[a-z_][a-z_0-9]*)*$""") : true"#).message(r"service must only contain valid characters (matching ^(?i)\.?[a-z_][a-z_0-9]*(\.[a-z_][a-z_0-9]*)*$)"))]
+    #[cel_validate(rule = Rule::new(r#"(!has(self.type) || self.type == 'Exact') && has(self.method) ? self.method.matches(r"""^[A-Za-z_][A-Za-z_0-9]*$""") : true"#).message("method must only contain valid characters (matching ^[A-Za-z_][A-Za-z_0-9]*$)"))]
+    struct GrpcMethodMatchSchema {
+        /// Type specifies how to match against the service and/or method.
+        #[serde(default)]
+        #[schemars(schema_with = "string_match_type")]
+        r#type: Option<String>,
+
+        /// Value of the service to match against. If left empty or omitted,
+        /// will match any service.
+        #[serde(default)]
+        #[schemars(length(max = 1024))]
+        service: Option<String>,
+
+        /// Value of the method to match against. If left empty or omitted,
+        /// will match all services.
+        #[serde(default)]
+        #[schemars(length(max = 1024))]
+        method: Option<String>,
+    }
+
+    tagged_schema!(crate::GrpcMethodMatch => GrpcMethodMatchSchema);
+
+    /// GRPCRouteFilter defines processing steps that must be completed during
+    /// the request or response lifecycle.
+    #[derive(serde::Serialize, kube::CELSchema)]
+    #[serde(rename_all = "camelCase")]
+    #[cel_validate(rule = Rule::new("!(has(self.requestHeaderModifier) && self.type != 'RequestHeaderModifier')").message("filter.requestHeaderModifier must be nil if the filter.type is not RequestHeaderModifier"))]
+    #[cel_validate(rule = Rule::new("!(!has(self.requestHeaderModifier) && self.type == 'RequestHeaderModifier')").message("filter.requestHeaderModifier must be specified for RequestHeaderModifier filter.type"))]
+    #[cel_validate(rule = Rule::new("!(has(self.responseHeaderModifier) && self.type != 'ResponseHeaderModifier')").message("filter.responseHeaderModifier must be nil if the filter.type is not ResponseHeaderModifier"))]
+    #[cel_validate(rule = Rule::new("!(!has(self.responseHeaderModifier) && self.type == 'ResponseHeaderModifier')").message("filter.responseHeaderModifier must be specified for ResponseHeaderModifier filter.type"))]
+    #[cel_validate(rule = Rule::new("!(has(self.requestMirror) && self.type != 'RequestMirror')").message("filter.requestMirror must be nil if the filter.type is not RequestMirror"))]
+    #[cel_validate(rule = Rule::new("!(!has(self.requestMirror) && self.type == 'RequestMirror')").message("filter.requestMirror must be specified for RequestMirror filter.type"))]
+    #[cel_validate(rule = Rule::new("!(has(self.extensionRef) && self.type != 'ExtensionRef')").message("filter.extensionRef must be nil if the filter.type is not ExtensionRef"))]
+    #[cel_validate(rule = Rule::new("!(!has(self.extensionRef) && self.type == 'ExtensionRef')").message("filter.extensionRef must be specified for ExtensionRef filter.type"))]
+    struct GrpcRouteFilterSchema {
+        /// Type identifies the type of filter to apply.
+        #[schemars(schema_with = "grpc_route_filter_type")]
+        r#type: String,
+
+        /// RequestHeaderModifier defines a schema for a filter that modifies
+        /// request headers.
+        #[serde(default)]
+        request_header_modifier: Option<crate::HttpRequestHeaderFilter>,
+
+        /// ResponseHeaderModifier defines a schema for a filter that modifies
+        /// response headers.
+        #[serde(default)]
+        response_header_modifier: Option<crate::HttpRequestHeaderFilter>,
+
+        /// RequestMirror defines a schema for a filter that mirrors requests.
+        #[serde(default)]
+        request_mirror: Option<crate::HttpRequestMirrorFilter>,
+
+        /// ExtensionRef is an optional, implementation-specific extension to
+        /// the "filter" behavior.
+        #[serde(default)]
+        extension_ref: Option<crate::LocalObjectReference>,
+    }
+
+    fn grpc_route_filter_type(_: &mut SchemaGenerator) -> Schema {
+        string_enum(crate::GrpcRouteFilter::KNOWN_TYPES)
+    }
+
+    tagged_schema!(crate::GrpcRouteFilter => GrpcRouteFilterSchema);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use kube::CustomResourceExt;
+
+    fn spec_schema<K: CustomResourceExt>() -> serde_json::Value {
+        let crd = serde_json::to_value(K::crd()).expect("CRD must serialize");
+        crd["spec"]["versions"][0]["schema"]["openAPIV3Schema"]["properties"]["spec"].clone()
+    }
+
+    #[test]
+    fn gateway_markers() {
+        let spec = spec_schema::<Gateway>();
+        let listeners = &spec["properties"]["listeners"];
+        assert_eq!(listeners["minItems"], 1);
+        assert_eq!(listeners["maxItems"], 64);
+        assert_eq!(
+            listeners["x-kubernetes-validations"]
+                .as_array()
+                .map(Vec::len),
+            Some(5)
+        );
+        let listener = &listeners["items"]["properties"];
+        assert_eq!(listener["port"]["minimum"], 1.0);
+        assert_eq!(listener["hostname"]["pattern"], super::HOSTNAME);
+        assert_eq!(
+            listener["tls"]["properties"]["mode"]["default"],
+            "Terminate"
+        );
+        assert_eq!(
+            listener["tls"]["properties"]["options"]["maxProperties"],
+            16
+        );
+    }
+
+    #[test]
+    fn terminate_accepts_options() {
+        let spec = spec_schema::<Gateway>();
+        let tls = &spec["properties"]["listeners"]["items"]["properties"]["tls"];
+        assert_eq!(
+            tls["x-kubernetes-validations"],
+            serde_json::json!([{
+                "rule": "self.mode == 'Terminate' ? size(self.certificateRefs) > 0 || size(self.options) > 0 : true",
+                "message": "certificateRefs or options must be specified when mode is Terminate",
+            }])
+        );
+
+        let options_only = serde_json::from_value::<GatewayTlsConfig>(serde_json::json!({
+            "mode": "Terminate",
+            "options": { "example.com/certificate-source": "vault" },
+        }))
+        .unwrap();
+        assert_eq!(options_only.validate(), Ok(()));
+    }
+
+    #[test]
+    fn http_route_markers() {
+        let spec = spec_schema::<HttpRoute>();
+        assert_eq!(
+            spec["properties"]["hostnames"]["items"]["pattern"],
+            super::HOSTNAME
+        );
+        assert_eq!(spec["properties"]["parentRefs"]["maxItems"], 32);
+
+        let rule = &spec["properties"]["rules"]["items"];
+        assert_eq!(
+            rule["x-kubernetes-validations"].as_array().map(Vec::len),
+            Some(5)
+        );
+        let filter = &rule["properties"]["filters"]["items"];
+        assert_eq!(
+            filter["properties"]["type"]["enum"]
+                .as_array()
+                .map(Vec::len),
+            Some(8)
+        );
+        assert!(filter["properties"]["urlRewrite"]["properties"]["path"]
+            ["x-kubernetes-validations"]
+            .is_array());
+        let methods = &filter["properties"]["cors"]["properties"]["allowMethods"];
+        assert_eq!(methods["maxItems"], 9);
+        assert_eq!(methods["items"]["enum"][9], "*");
+        assert!(methods["x-kubernetes-validations"].is_array());
+
+        let path = &rule["properties"]["matches"]["items"]["properties"]["path"];
+        assert_eq!(path["properties"]["value"]["maxLength"], 1024);
+        assert!(path["x-kubernetes-validations"].is_array());
+
+        let backend = &rule["properties"]["backendRefs"]["items"];
+        assert_eq!(backend["properties"]["weight"]["maximum"], 1_000_000.0);
+        assert!(backend["x-kubernetes-validations"].is_array());
+    }
+
+    #[test]
+    fn gateway_class_markers() {
+        let spec = spec_schema::<GatewayClass>();
+        let controller = &spec["properties"]["controllerName"];
+        assert_eq!(controller["pattern"], super::GATEWAY_CONTROLLER);
+        assert_eq!(
+            controller["x-kubernetes-validations"][0]["rule"],
+            "self == oldSelf"
+        );
+    }
+}
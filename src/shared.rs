@@ -1,6 +1,6 @@
 use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
 
-use crate::BackendObjectReference;
+use crate::{BackendObjectReference, Duration};
 
 /// ParentReference identifies an API object (usually a Gateway) that can be considered
 /// a parent of this resource (usually a route). The only kind of parent resource
@@ -17,23 +17,38 @@ pub struct ParentReference {
     /// Group is the group of the referent.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::gateway_group",
+        length(max = 253),
+        regex = "crate::schema::GROUP"
+    )]
     pub group: Option<Group>,
 
     /// Kind is kind of the referent.
     ///
     /// Support: Core (Gateway)
     /// Support: Custom (Other Resources)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::gateway_kind",
+        length(min = 1, max = 63),
+        regex = "crate::schema::KIND"
+    )]
     pub kind: Option<Kind>,
 
     /// Namespace is the namespace of the referent. When unspecified (or empty
     /// string), this refers to the local namespace of the Route.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 63), regex = "crate::schema::NAMESPACE")]
     pub namespace: Option<Namespace>,
 
     /// Name is the name of the referent.
     ///
     /// Support: Core
+    #[schemars(length(min = 1, max = 253))]
     pub name: ObjectName,
 
     /// SectionName is the name of a section within the target resource. In the
@@ -58,6 +73,8 @@ pub struct ParentReference {
     /// the Gateway.
     ///
     /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(min = 1, max = 253), regex = "crate::schema::SECTION_NAME")]
     pub section_name: Option<SectionName>,
 
     /// Port is the network port this Route targets. It can be interpreted
@@ -83,13 +100,15 @@ pub struct ParentReference {
     /// Route MUST be considered detached from the Gateway.
     ///
     /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 65535))]
     pub port: Option<PortNumber>,
 }
 
 /// CommonRouteSpec defines the common attributes that all Routes MUST include
 /// within their spec.
 #[derive(
-    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct CommonRouteSpec {
@@ -128,9 +147,17 @@ pub struct CommonRouteSpec {
     /// connections originating from the same namespace as the Route, for which
     /// the intended destination of the connections are a Service targeted as a
     /// ParentRef of the Route.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 32))]
+    #[cel_validate(rule = Rule::new(PARENT_REFS_SECTION_NAME_REQUIRED).message("sectionName must be specified when parentRefs includes 2 or more references to the same parent"))]
+    #[cel_validate(rule = Rule::new(PARENT_REFS_SECTION_NAME_UNIQUE).message("sectionName must be unique when parentRefs includes 2 or more references to the same parent"))]
     pub parent_refs: Option<Vec<ParentReference>>,
 }
 
+const PARENT_REFS_SECTION_NAME_REQUIRED: &str = "self.all(p1, self.all(p2, p1.group == p2.group && p1.kind == p2.kind && p1.name == p2.name && (((!has(p1.__namespace__) || p1.__namespace__ == '') && (!has(p2.__namespace__) || p2.__namespace__ == '')) || (has(p1.__namespace__) && has(p2.__namespace__) && p1.__namespace__ == p2.__namespace__)) ? ((!has(p1.sectionName) || p1.sectionName == '') == (!has(p2.sectionName) || p2.sectionName == '')) : true))";
+
+const PARENT_REFS_SECTION_NAME_UNIQUE: &str = "self.all(p1, self.exists_one(p2, p1.group == p2.group && p1.kind == p2.kind && p1.name == p2.name && (((!has(p1.__namespace__) || p1.__namespace__ == '') && (!has(p2.__namespace__) || p2.__namespace__ == '')) || (has(p1.__namespace__) && has(p2.__namespace__) && p1.__namespace__ == p2.__namespace__)) && (((!has(p1.sectionName) || p1.sectionName == '') && (!has(p2.sectionName) || p2.sectionName == '')) || (has(p1.sectionName) && has(p2.sectionName) && p1.sectionName == p2.sectionName))))";
+
 /// PortNumber defines a network port.
 pub type PortNumber = u16;
 
@@ -157,18 +184,92 @@ pub struct BackendRef {
     /// defaults to 1.
     ///
     /// Support for this field varies based on the context where used.
-    pub weight: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 0, max = 1_000_000))]
+    pub weight: Option<u32>,
 
     /// BackendObjectReference references a Kubernetes object.
     #[serde(flatten)]
     pub inner: BackendObjectReference,
 }
 
-/// RouteConditionType is a type of condition for a route.
-pub type RouteConditionType = String;
+string_enum! {
+    /// RouteConditionType is a type of condition for a route.
+    pub enum RouteConditionType {
+        /// This condition indicates whether the route has been accepted or
+        /// rejected by a Gateway, and why.
+        Accepted = "Accepted",
+
+        /// This condition indicates whether the controller was able to resolve
+        /// all the object references for the Route.
+        ResolvedRefs = "ResolvedRefs",
+
+        /// This condition indicates that the Route contains a combination of
+        /// both valid and invalid rules.
+        PartiallyInvalid = "PartiallyInvalid",
+    }
+}
+
+string_enum! {
+    /// RouteConditionReason is a reason for a route condition.
+    pub enum RouteConditionReason {
+        /// This reason is used with the "Accepted" condition when the Route
+        /// has been accepted by the Gateway.
+        Accepted = "Accepted",
+
+        /// This reason is used with the "Accepted" condition when the route
+        /// has not been accepted by a Gateway because the Gateway has no
+        /// Listener whose allowedRoutes criteria permit the route.
+        NotAllowedByListeners = "NotAllowedByListeners",
+
+        /// This reason is used with the "Accepted" condition when the Gateway
+        /// has no compatible Listeners whose Hostname matches the route.
+        NoMatchingListenerHostname = "NoMatchingListenerHostname",
+
+        /// This reason is used with the "Accepted" condition when there are no
+        /// matching Parents. In the case of Gateways, this can occur when a
+        /// Route ParentRef specifies a Port and/or SectionName that does not
+        /// match any Listeners in the Gateway.
+        NoMatchingParent = "NoMatchingParent",
+
+        /// This reason is used with the "Accepted" condition when a value for
+        /// an Enum is not recognized.
+        UnsupportedValue = "UnsupportedValue",
 
-/// RouteConditionReason is a reason for a route condition.
-pub type RouteConditionReason = String;
+        /// This reason is used with the "Accepted" when a Route has been
+        /// recently created and no controller has reconciled it yet.
+        Pending = "Pending",
+
+        /// This reason is used with the "Accepted" condition when there are
+        /// incompatible filters present on a route rule.
+        IncompatibleFilters = "IncompatibleFilters",
+
+        /// This reason is used with the "ResolvedRefs" condition when the
+        /// condition is true.
+        ResolvedRefs = "ResolvedRefs",
+
+        /// This reason is used with the "ResolvedRefs" condition when one of
+        /// the Listener's Routes has a BackendRef to an object in another
+        /// namespace, where the object in the other namespace does not have a
+        /// ReferenceGrant explicitly allowing the reference.
+        RefNotPermitted = "RefNotPermitted",
+
+        /// This reason is used with the "ResolvedRefs" condition when one of
+        /// the Route's rules has a reference to an unknown or unsupported
+        /// Group and/or Kind.
+        InvalidKind = "InvalidKind",
+
+        /// This reason is used with the "ResolvedRefs" condition when one of
+        /// the Route's rules has a reference to a resource that does not
+        /// exist.
+        BackendNotFound = "BackendNotFound",
+
+        /// This reason is used with the "ResolvedRefs" condition when one of
+        /// the Route's rules has a reference to a resource with an app
+        /// protocol that is not supported by this implementation.
+        UnsupportedProtocol = "UnsupportedProtocol",
+    }
+}
 
 /// RouteParentStatus describes the status of a route with respect to an
 /// associated Parent.
@@ -193,6 +294,10 @@ pub struct RouteParentStatus {
     /// longer necessary.
     ///
     /// [names]: https://kubernetes.io/docs/concepts/overview/working-with-objects/names/#names
+    #[schemars(
+        length(min = 1, max = 253),
+        regex = "crate::schema::GATEWAY_CONTROLLER"
+    )]
     pub controller_name: GatewayController,
 
     /// Conditions describes the status of the route with respect to the
@@ -214,6 +319,7 @@ pub struct RouteParentStatus {
     /// * The Route refers to a non-existent parent.
     /// * The Route is of a type that the controller does not support.
     /// * The Route is in a namespace the the controller does not have access to.
+    #[schemars(length(min = 1, max = 8))]
     pub conditions: Vec<metav1::Condition>,
 }
 
@@ -235,34 +341,10 @@ pub struct RouteStatus {
     ///
     /// A maximum of 32 Gateways will be represented in this list. An empty list
     /// means the route has not been attached to any Gateway.
+    #[schemars(length(max = 32))]
     pub parents: Vec<RouteParentStatus>,
 }
 
-/// Hostname is the fully qualified domain name of a network host. This matches
-/// the RFC 1123 definition of a hostname with 2 notable exceptions:
-///
-/// 1. IPs are not allowed.
-/// 2. A hostname may be prefixed with a wildcard label (`*.`). The wildcard
-///    label must appear by itself as the first label.
-///
-/// Hostname can be "precise" which is a domain name without the terminating dot
-/// of a network host (e.g. "foo.example.com") or "wildcard", which is a domain
-/// name prefixed with a single wildcard label (e.g. `*.example.com`).
-///
-/// Note that as per RFC1035 and RFC1123, a *label* must consist of lower case
-/// alphanumeric characters or '-', and must start and end with an alphanumeric
-/// character. No other punctuation is allowed.
-pub type Hostname = String;
-
-/// PreciseHostname is the fully qualified domain name of a network host. This
-/// matches the RFC 1123 definition of a hostname with 1 notable exception that
-/// numeric IP addresses are not allowed.
-///
-/// Note that as per RFC1035 and RFC1123, a *label* must consist of lower case
-/// alphanumeric characters or '-', and must start and end with an alphanumeric
-/// character. No other punctuation is allowed.
-pub type PreciseHostname = String;
-
 /// Group refers to a Kubernetes Group. It must either be an empty string or a
 /// RFC 1123 subdomain.
 ///
@@ -367,5 +449,195 @@ pub type AnnotationKey = String;
 /// on the entire size of the annotations struct.
 pub type AnnotationValue = String;
 
-/// AddressType defines how a network address is represented as a text string.
-pub type AddressType = String;
+/// LabelKey is the key of a label in the Gateway API. This is used for
+/// validation of maps such as Gateway infrastructure labels. This matches the
+/// Kubernetes "qualified name" validation that is used for labels.
+///
+/// Valid values include:
+///
+/// * example
+/// * example.com
+/// * example.com/path
+/// * example.com/path.html
+///
+/// Invalid values include:
+///
+/// * example~ - "~" is an invalid character
+/// * example.com. - can not start or end with "."
+pub type LabelKey = String;
+
+/// LabelValue is the value of a label in the Gateway API. This is used for
+/// validation of maps such as Gateway infrastructure labels. This matches the
+/// Kubernetes label validation rules:
+///
+/// * must be 63 characters or less (can be empty),
+/// * unless empty, must begin and end with an alphanumeric character
+///   (`[a-z0-9A-Z]`),
+/// * could contain dashes (`-`), underscores (`_`), dots (`.`), and
+///   alphanumerics between.
+///
+/// Valid values include:
+///
+/// * MyValue
+/// * my.name
+/// * 123-my-value
+pub type LabelValue = String;
+
+string_enum! {
+    /// AddressType defines how a network address is represented as a text
+    /// string.
+    ///
+    /// Implementation-specific address types are represented by `Other`.
+    pub enum AddressType {
+        /// A textual representation of a numeric IP address. IPv4 addresses
+        /// must be in dotted-decimal form. IPv6 addresses must be in a
+        /// standard IPv6 text representation (see RFC 5952).
+        IPAddress = "IPAddress",
+
+        /// A Hostname represents a DNS based ingress point. This is similar
+        /// to the corresponding hostname field in Kubernetes load balancer
+        /// status. For example, this concept may be used for cloud load
+        /// balancers where a DNS name is used to expose a load balancer.
+        Hostname = "Hostname",
+
+        /// A NamedAddress provides a way to reference a specific IP address
+        /// by name. For example, this may be a name or other unique
+        /// identifier that refers to a resource on a cloud provider such as a
+        /// static IP.
+        ///
+        /// The `NamedAddress` type has been deprecated in favor of
+        /// implementation specific domain-prefixed strings.
+        NamedAddress = "NamedAddress",
+    }
+}
+
+/// SessionPersistence defines the desired state of SessionPersistence.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("!has(self.cookieConfig) || !has(self.cookieConfig.lifetimeType) || self.cookieConfig.lifetimeType != 'Permanent' || has(self.absoluteTimeout)").message("AbsoluteTimeout must be specified when cookie lifetimeType is Permanent"))]
+pub struct SessionPersistence {
+    /// SessionName defines the name of the persistent session token which may
+    /// be reflected in the cookie or the header. Users should avoid reusing
+    /// session names to prevent unintended consequences, such as rejection or
+    /// unpredictable behavior.
+    ///
+    /// Support: Implementation-specific
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 128))]
+    pub session_name: Option<String>,
+
+    /// AbsoluteTimeout defines the absolute timeout of the persistent session.
+    /// Once the AbsoluteTimeout duration has elapsed, the session becomes
+    /// invalid.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "crate::schema::DURATION")]
+    pub absolute_timeout: Option<Duration>,
+
+    /// IdleTimeout defines the idle timeout of the persistent session. Once
+    /// the session has been idle for more than the specified IdleTimeout
+    /// duration, the session becomes invalid.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "crate::schema::DURATION")]
+    pub idle_timeout: Option<Duration>,
+
+    /// Type defines the type of session persistence such as through the use a
+    /// header or cookie. Defaults to cookie based session persistence.
+    ///
+    /// Support: Core for "Cookie" type
+    ///
+    /// Support: Extended for "Header" type
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::cookie",
+        schema_with = "crate::schema::session_persistence_type"
+    )]
+    pub type_: Option<SessionPersistenceType>,
+
+    /// CookieConfig provides configuration settings that are specific to
+    /// cookie-based session persistence.
+    ///
+    /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_config: Option<CookieConfig>,
+}
+
+string_enum! {
+    /// SessionPersistenceType is the type of session persistence.
+    pub enum SessionPersistenceType {
+        /// Cookie-based session persistence.
+        ///
+        /// Support: Core
+        Cookie = "Cookie",
+
+        /// Header-based session persistence.
+        ///
+        /// Support: Extended
+        Header = "Header",
+    }
+}
+
+/// CookieConfig defines the configuration for cookie-based session
+/// persistence.
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CookieConfig {
+    /// LifetimeType specifies whether the cookie has a permanent or
+    /// session-based lifetime. A permanent cookie persists until its specified
+    /// expiry time, defined by the Expires or Max-Age cookie attributes, while
+    /// a session cookie is deleted when the current session ends.
+    ///
+    /// When set to "Permanent", AbsoluteTimeout indicates the cookie's
+    /// lifetime via the Expires or Max-Age cookie attributes and is required.
+    ///
+    /// When set to "Session", AbsoluteTimeout indicates the absolute lifetime
+    /// of the cookie tracked by the gateway and is optional.
+    ///
+    /// Defaults to "Session".
+    ///
+    /// Support: Core for "Session" type
+    ///
+    /// Support: Extended for "Permanent" type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        default = "crate::schema::session",
+        schema_with = "crate::schema::cookie_lifetime_type"
+    )]
+    pub lifetime_type: Option<CookieLifetimeType>,
+}
+
+string_enum! {
+    /// CookieLifetimeType is the lifetime of a session persistence cookie.
+    pub enum CookieLifetimeType {
+        /// A session cookie, which is deleted when the current session ends.
+        ///
+        /// Support: Core
+        Session = "Session",
+
+        /// A permanent cookie, which persists until its specified expiry time.
+        ///
+        /// Support: Extended
+        Permanent = "Permanent",
+    }
+}
+
+/// Fraction is a ratio, e.g. of requests, expressed as a numerator over a
+/// denominator that defaults to 100.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, kube::CELSchema)]
+#[serde(rename_all = "camelCase")]
+#[cel_validate(rule = Rule::new("self.numerator <= self.denominator").message("numerator must be less than or equal to denominator"))]
+pub struct Fraction {
+    #[schemars(range(min = 0))]
+    pub numerator: i32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(default = "crate::schema::one_hundred", range(min = 1))]
+    pub denominator: Option<i32>,
+}
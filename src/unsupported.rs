@@ -0,0 +1,291 @@
+//! Reports the values in a route that this version of the library does not
+//! understand.
+//!
+//! Tagged enums such as [`HttpRouteFilter`] read values with unrecognized
+//! types as an `Unknown` variant, so that resources written for a newer
+//! version of the Gateway API can still be read (and written back unchanged).
+//! Controllers should not program such routes as though the unknown values
+//! were absent; instead, they should report them with an `Accepted`
+//! condition whose reason is `UnsupportedValue`.
+
+use crate::*;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as metav1;
+use std::fmt;
+
+/// UnsupportedFeatures finds the values in a resource that are not supported
+/// by this version of the library.
+pub trait UnsupportedFeatures {
+    /// Returns every unsupported value in this resource, in the order in which
+    /// they appear.
+    fn unsupported_features(&self) -> Vec<UnsupportedFeature> {
+        let mut features = Vec::new();
+        self.unsupported_features_at(&FieldPath::default(), &mut features);
+        features
+    }
+
+    /// Returns true if this resource contains any unsupported value.
+    fn has_unsupported_features(&self) -> bool {
+        !self.unsupported_features().is_empty()
+    }
+
+    /// Builds the `Accepted` condition, with the `UnsupportedValue` reason,
+    /// that should be reported for a route with unsupported values, or `None`
+    /// if the route has none.
+    fn unsupported_condition(&self, observed_generation: Option<i64>) -> Option<metav1::Condition> {
+        let features = self.unsupported_features();
+        if features.is_empty() {
+            return None;
+        }
+        let message = features
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+        Some(RouteConditionType::Accepted.condition(
+            false,
+            RouteConditionReason::UnsupportedValue,
+            message,
+            observed_generation,
+        ))
+    }
+
+    /// Records each unsupported value in this resource into `features`.
+    /// Values are reported relative to `path`, the location of this value
+    /// within the enclosing object.
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>);
+}
+
+/// UnsupportedFeature describes a value that is not supported by this version
+/// of the library.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnsupportedFeature {
+    /// Path is the JSON path of the unsupported field, e.g.
+    /// `spec.rules[0].filters[1].type`.
+    pub path: FieldPath,
+
+    /// Value is the unsupported value.
+    pub value: String,
+}
+
+// === impl UnsupportedFeature ===
+
+impl UnsupportedFeature {
+    fn unknown_type(path: &FieldPath, type_: &str) -> Self {
+        Self {
+            path: path.field("type"),
+            value: type_.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for UnsupportedFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: unsupported value {:?}", self.path, self.value)
+    }
+}
+
+fn items<T: UnsupportedFeatures>(
+    path: &FieldPath,
+    items: &Option<Vec<T>>,
+    features: &mut Vec<UnsupportedFeature>,
+) {
+    for (i, item) in items.iter().flatten().enumerate() {
+        item.unsupported_features_at(&path.index(i), features);
+    }
+}
+
+// === impl HttpRoute ===
+
+impl UnsupportedFeatures for HttpRoute {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        items(
+            &path.field("spec").field("rules"),
+            &self.spec.rules,
+            features,
+        );
+    }
+}
+
+impl UnsupportedFeatures for v1::HttpRoute {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        items(
+            &path.field("spec").field("rules"),
+            &self.spec.rules,
+            features,
+        );
+    }
+}
+
+impl UnsupportedFeatures for HttpRouteRule {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        items(&path.field("matches"), &self.matches, features);
+        items(&path.field("filters"), &self.filters, features);
+        items(&path.field("backendRefs"), &self.backend_refs, features);
+    }
+}
+
+impl UnsupportedFeatures for HttpRouteMatch {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        if let Some(HttpPathMatch::Unknown { type_, .. }) = &self.path {
+            features.push(UnsupportedFeature::unknown_type(&path.field("path"), type_));
+        }
+        for (i, header) in self.headers.iter().flatten().enumerate() {
+            if let HttpHeaderMatch::Unknown { type_, .. } = header {
+                let path = path.field("headers").index(i);
+                features.push(UnsupportedFeature::unknown_type(&path, type_));
+            }
+        }
+        for (i, param) in self.query_params.iter().flatten().enumerate() {
+            if let HttpQueryParamMatch::Unknown { type_, .. } = param {
+                let path = path.field("queryParams").index(i);
+                features.push(UnsupportedFeature::unknown_type(&path, type_));
+            }
+        }
+    }
+}
+
+impl UnsupportedFeatures for HttpRouteFilter {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        let (path, modifier) = match self {
+            Self::RequestRedirect { request_redirect } => {
+                (path.field("requestRedirect"), &request_redirect.path)
+            }
+            Self::URLRewrite { url_rewrite } => (path.field("urlRewrite"), &url_rewrite.path),
+            Self::Unknown { type_, .. } => {
+                features.push(UnsupportedFeature::unknown_type(path, type_));
+                return;
+            }
+            _ => return,
+        };
+        if let Some(HttpPathModifier::Unknown { type_, .. }) = modifier {
+            features.push(UnsupportedFeature::unknown_type(&path.field("path"), type_));
+        }
+    }
+}
+
+impl UnsupportedFeatures for HttpBackendRef {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        items(&path.field("filters"), &self.filters, features);
+    }
+}
+
+// === impl GrpcRoute ===
+
+#[cfg(feature = "experimental")]
+impl UnsupportedFeatures for GrpcRoute {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        items(
+            &path.field("spec").field("rules"),
+            &self.spec.rules,
+            features,
+        );
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl UnsupportedFeatures for GrpcRouteRule {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        items(&path.field("matches"), &self.matches, features);
+        items(&path.field("filters"), &self.filters, features);
+        items(&path.field("backendRefs"), &self.backend_refs, features);
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl UnsupportedFeatures for GrpcRouteMatch {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        if let Some(GrpcMethodMatch::Unknown { type_, .. }) = &self.method {
+            features.push(UnsupportedFeature::unknown_type(
+                &path.field("method"),
+                type_,
+            ));
+        }
+        for (i, header) in self.headers.iter().flatten().enumerate() {
+            if let GrpcHeaderMatch::Unknown { type_, .. } = header {
+                let path = path.field("headers").index(i);
+                features.push(UnsupportedFeature::unknown_type(&path, type_));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl UnsupportedFeatures for GrpcRouteFilter {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        if let Self::Unknown { type_, .. } = self {
+            features.push(UnsupportedFeature::unknown_type(path, type_));
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl UnsupportedFeatures for GrpcRouteBackendRef {
+    fn unsupported_features_at(&self, path: &FieldPath, features: &mut Vec<UnsupportedFeature>) {
+        items(&path.field("filters"), &self.filters, features);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_types() {
+        let manifest = serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "HTTPRoute",
+            "metadata": { "name": "example" },
+            "spec": {
+                "rules": [{
+                    "matches": [{
+                        "path": { "type": "Glob", "value": "/*.png" },
+                        "headers": [
+                            { "type": "Exact", "name": "x-a", "value": "a" },
+                            { "type": "Prefix", "name": "x-b", "value": "b" },
+                        ],
+                    }],
+                    "filters": [
+                        {
+                            "type": "URLRewrite",
+                            "urlRewrite": { "path": { "type": "ReplaceSuffix", "replaceSuffix": ".jpg" } },
+                        },
+                        { "type": "RateLimit", "rateLimit": { "requests": 10 } },
+                    ],
+                }],
+            },
+        });
+        let route = serde_json::from_value::<HttpRoute>(manifest.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&route).unwrap(), manifest);
+
+        let features = route
+            .unsupported_features()
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            features,
+            [
+                r#"spec.rules[0].matches[0].path.type: unsupported value "Glob""#,
+                r#"spec.rules[0].matches[0].headers[1].type: unsupported value "Prefix""#,
+                r#"spec.rules[0].filters[0].urlRewrite.path.type: unsupported value "ReplaceSuffix""#,
+                r#"spec.rules[0].filters[1].type: unsupported value "RateLimit""#,
+            ]
+        );
+
+        let condition = route.unsupported_condition(Some(3)).unwrap();
+        assert_eq!(condition.type_, "Accepted");
+        assert_eq!(condition.status, "False");
+        assert_eq!(condition.reason, "UnsupportedValue");
+        assert_eq!(condition.observed_generation, Some(3));
+
+        let v1 = v1::HttpRoute::from(route);
+        assert_eq!(v1.unsupported_features().len(), 4);
+
+        let supported = HttpRoute::builder("example")
+            .rule(|r| r.path_prefix("/"))
+            .build()
+            .unwrap();
+        assert!(!supported.has_unsupported_features());
+        assert_eq!(supported.unsupported_condition(Some(3)), None);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::*;
+
+/// Gateway represents an instance of a service-traffic handling infrastructure
+/// by binding Listeners to a set of IP addresses.
+#[derive(
+    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1",
+    kind = "Gateway",
+    status = "GatewayStatus",
+    namespaced
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewaySpec {
+    /// GatewayClassName used for this Gateway. This is the name of a
+    /// GatewayClass resource.
+    #[schemars(length(min = 1, max = 253))]
+    pub gateway_class_name: ObjectName,
+
+    /// Listeners associated with this Gateway. Listeners define logical
+    /// endpoints that are bound on this Gateway's addresses.  At least one
+    /// Listener MUST be specified.
+    ///
+    /// Each listener in a Gateway must have a unique combination of Hostname,
+    /// Port, and Protocol.
+    ///
+    /// An implementation MAY group Listeners by Port and then collapse each
+    /// group of Listeners into a single Listener if the implementation
+    /// determines that the Listeners in the group are "compatible". An
+    /// implementation MAY also group together and collapse compatible Listeners
+    /// belonging to different Gateways.
+    ///
+    /// For example, an implementation might consider Listeners to be compatible
+    /// with each other if all of the following conditions are met:
+    ///
+    /// 1. Either each Listener within the group specifies the "HTTP" Protocol or
+    ///    each Listener within the group specifies either the "HTTPS" or "TLS"
+    ///    Protocol.
+    /// 2. Each Listener within the group specifies a Hostname that is unique
+    ///    within the group.
+    /// 3. As a special case, one Listener within a group may omit Hostname, in
+    ///    which case this Listener matches when no other Listener matches.
+    ///
+    /// If the implementation does collapse compatible Listeners, the hostname
+    /// provided in the incoming client request MUST be matched to a Listener to
+    /// find the correct set of Routes.  The incoming hostname MUST be matched
+    /// using the Hostname field for each Listener in order of most to least
+    /// specific. That is, exact matches must be processed before wildcard
+    /// matches.
+    ///
+    /// If this field specifies multiple Listeners that have the same Port value
+    /// but are not compatible, the implementation must raise a "Conflicted"
+    /// condition in the Listener status.
+    ///
+    /// Support: Core
+    #[schemars(length(min = 1, max = 64))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['HTTPS', 'TLS'] ? has(l.tls) : true)").message("tls must be specified for protocols ['HTTPS', 'TLS']"))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['HTTP', 'TCP', 'UDP'] ? !has(l.tls) : true)").message("tls must not be specified for protocols ['HTTP', 'TCP', 'UDP']"))]
+    #[cel_validate(rule = Rule::new("self.all(l, l.protocol in ['TCP', 'UDP'] ? (!has(l.hostname) || l.hostname == '') : true)").message("hostname must not be specified for protocols ['TCP', 'UDP']"))]
+    #[cel_validate(rule = Rule::new("self.all(l1, self.exists_one(l2, l1.name == l2.name))").message("Listener name must be unique within the Gateway"))]
+    #[cel_validate(rule = Rule::new("self.all(l1, self.exists_one(l2, l1.port == l2.port && l1.protocol == l2.protocol && (has(l1.hostname) && has(l2.hostname) ? l1.hostname == l2.hostname : !has(l1.hostname) && !has(l2.hostname))))").message("Combination of port, protocol and hostname must be unique for each listener"))]
+    pub listeners: Vec<Listener>,
+
+    /// Addresses requested for this Gateway. This is optional and behavior can
+    /// depend on the implementation. If a value is set in the spec and the
+    /// requested address is invalid or unavailable, the implementation MUST
+    /// indicate this in the associated entry in GatewayStatus.Addresses.
+    ///
+    /// The Addresses field represents a request for the address(es) on the
+    /// "outside of the Gateway", that traffic bound for this Gateway will use.
+    /// This could be the IP address or hostname of an external load balancer or
+    /// other networking infrastructure, or some other address that traffic will
+    /// be sent to.
+    ///
+    /// The .listener.hostname field is used to route traffic that has already
+    /// arrived at the Gateway to the correct in-cluster destination.
+    ///
+    /// If no Addresses are specified, the implementation MAY schedule the
+    /// Gateway in an implementation-specific manner, assigning an appropriate
+    /// set of Addresses.
+    ///
+    /// The implementation MUST bind all Listeners to every GatewayAddress that
+    /// it assigns to the Gateway and add a corresponding entry in
+    /// GatewayStatus.Addresses.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
+    #[cel_validate(rule = Rule::new("self.all(a1, a1.type == 'IPAddress' ? self.exists_one(a2, a2.type == a1.type && a2.value == a1.value) : true )").message("IPAddress values must be unique"))]
+    #[cel_validate(rule = Rule::new("self.all(a1, a1.type == 'Hostname' ? self.exists_one(a2, a2.type == a1.type && a2.value == a1.value) : true )").message("Hostname values must be unique"))]
+    pub addresses: Option<Vec<GatewayAddress>>,
+
+    /// Infrastructure defines infrastructure level attributes about this
+    /// Gateway instance.
+    ///
+    /// Support: Extended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub infrastructure: Option<GatewayInfrastructure>,
+
+    /// BackendTLS configures TLS settings for when this Gateway is connecting
+    /// to backends with TLS.
+    ///
+    /// Support: Core
+    #[serde(
+        default,
+        rename = "backendTLS",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub backend_tls: Option<GatewayBackendTls>,
+
+    /// AllowedListeners defines which ListenerSets can be attached to this
+    /// Gateway. While this feature is experimental, the default value is to
+    /// allow no ListenerSets.
+    ///
+    /// gateway:experimental
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_listeners: Option<AllowedListeners>,
+}
+
+impl From<crate::GatewaySpec> for GatewaySpec {
+    fn from(spec: crate::GatewaySpec) -> Self {
+        let crate::GatewaySpec {
+            gateway_class_name,
+            listeners,
+            addresses,
+            infrastructure,
+            backend_tls,
+            allowed_listeners,
+        } = spec;
+        Self {
+            gateway_class_name,
+            listeners,
+            addresses,
+            infrastructure,
+            backend_tls,
+            allowed_listeners,
+        }
+    }
+}
+
+impl From<GatewaySpec> for crate::GatewaySpec {
+    fn from(spec: GatewaySpec) -> Self {
+        let GatewaySpec {
+            gateway_class_name,
+            listeners,
+            addresses,
+            infrastructure,
+            backend_tls,
+            allowed_listeners,
+        } = spec;
+        Self {
+            gateway_class_name,
+            listeners,
+            addresses,
+            infrastructure,
+            backend_tls,
+            allowed_listeners,
+        }
+    }
+}
+
+impl From<crate::Gateway> for Gateway {
+    fn from(obj: crate::Gateway) -> Self {
+        Self {
+            metadata: obj.metadata,
+            spec: obj.spec.into(),
+            status: obj.status,
+        }
+    }
+}
+
+impl From<Gateway> for crate::Gateway {
+    fn from(obj: Gateway) -> Self {
+        Self {
+            metadata: obj.metadata,
+            spec: obj.spec.into(),
+            status: obj.status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use kube::{core::crd::merge_crds, CustomResourceExt};
+
+    #[test]
+    fn serves_both_versions() {
+        let crd = merge_crds(vec![crate::Gateway::crd(), Gateway::crd()], "v1")
+            .expect("v1 and v1beta1 CRDs must be compatible");
+        let versions = crd
+            .spec
+            .versions
+            .iter()
+            .map(|v| (v.name.as_str(), v.storage))
+            .collect::<Vec<_>>();
+        assert_eq!(versions, [("v1", true), ("v1beta1", false)]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::*;
+
+/// GatewayClass describes a class of Gateways available to the user for creating
+/// Gateway resources.
+///
+/// It is recommended that this resource be used as a template for Gateways. This
+/// means that a Gateway is based on the state of the GatewayClass at the time it
+/// was created and changes to the GatewayClass or associated parameters are not
+/// propagated down to existing Gateways. This recommendation is intended to
+/// limit the blast radius of changes to GatewayClass or associated parameters.
+/// If implementations choose to propagate GatewayClass changes to existing
+/// Gateways, that MUST be clearly documented by the implementation.
+///
+/// Whenever one or more Gateways are using a GatewayClass, implementations MUST
+/// add the `gateway-exists-finalizer.gateway.networking.k8s.io` finalizer on the
+/// associated GatewayClass. This ensures that a GatewayClass associated with a
+/// Gateway is not deleted while in use.
+///
+/// GatewayClass is a Cluster level resource.
+#[derive(
+    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, kube::CELSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1",
+    kind = "GatewayClass",
+    status = "GatewayClassStatus"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayClassSpec {
+    /// ControllerName is the name of the controller that is managing Gateways
+    /// of this class. The value of this field MUST be a domain prefixed path.
+    ///
+    /// Example: "example.net/gateway-controller".
+    ///
+    /// This field is not mutable and cannot be empty.
+    #[schemars(
+        length(min = 1, max = 253),
+        regex = "crate::schema::GATEWAY_CONTROLLER"
+    )]
+    #[cel_validate(rule = Rule::new("self == oldSelf").message("Value is immutable"))]
+    pub controller_name: GatewayController,
+
+    /// ParametersRef is a reference to a resource that contains the
+    /// configuration parameters corresponding to the GatewayClass. This is
+    /// optional if the controller does not require any additional
+    /// configuration.
+    ///
+    /// ParametersRef can reference a standard Kubernetes resource, i.e.
+    /// ConfigMap, or an implementation-specific custom resource. The resource
+    /// can be cluster-scoped or namespace-scoped.
+    ///
+    /// If the referent cannot be found, the GatewayClass's "InvalidParameters"
+    /// status condition will be true.
+    ///
+    /// Support: Custom
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters_ref: Option<ParametersReference>,
+
+    /// Description helps describe a GatewayClass with more details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 64))]
+    pub description: Option<String>,
+}
+
+impl From<crate::GatewayClassSpec> for GatewayClassSpec {
+    fn from(spec: crate::GatewayClassSpec) -> Self {
+        let crate::GatewayClassSpec {
+            controller_name,
+            parameters_ref,
+            description,
+        } = spec;
+        Self {
+            controller_name,
+            parameters_ref,
+            description,
+        }
+    }
+}
+
+impl From<GatewayClassSpec> for crate::GatewayClassSpec {
+    fn from(spec: GatewayClassSpec) -> Self {
+        let GatewayClassSpec {
+            controller_name,
+            parameters_ref,
+            description,
+        } = spec;
+        Self {
+            controller_name,
+            parameters_ref,
+            description,
+        }
+    }
+}
+
+impl From<crate::GatewayClass> for GatewayClass {
+    fn from(obj: crate::GatewayClass) -> Self {
+        Self {
+            metadata: obj.metadata,
+            spec: obj.spec.into(),
+            status: obj.status,
+        }
+    }
+}
+
+impl From<GatewayClass> for crate::GatewayClass {
+    fn from(obj: GatewayClass) -> Self {
+        Self {
+            metadata: obj.metadata,
+            spec: obj.spec.into(),
+            status: obj.status,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::*;
+
+/// HTTPRoute provides a way to route HTTP requests. This includes the
+/// capability to match requests by hostname, path, header, or query param.
+/// Filters can be used to specify additional processing steps. Backends specify
+/// where matching requests should be routed.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    kube::CustomResource,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1",
+    kind = "HTTPRoute",
+    root = "HttpRoute",
+    status = "HttpRouteStatus",
+    namespaced
+)]
+pub struct HttpRouteSpec {
+    /// Common route information.
+    #[serde(flatten)]
+    pub inner: CommonRouteSpec,
+
+    /// Hostnames defines a set of hostname that should match against the HTTP
+    /// Host header to select a HTTPRoute to process the request. This matches
+    /// the RFC 1123 definition of a hostname with 2 notable exceptions:
+    ///
+    /// 1. IPs are not allowed.
+    /// 2. A hostname may be prefixed with a wildcard label (`*.`). The wildcard
+    ///    label must appear by itself as the first label.
+    ///
+    /// If a hostname is specified by both the Listener and HTTPRoute, there
+    /// must be at least one intersecting hostname for the HTTPRoute to be
+    /// attached to the Listener. For example:
+    ///
+    /// * A Listener with `test.example.com` as the hostname matches HTTPRoutes
+    ///   that have either not specified any hostnames, or have specified at
+    ///   least one of `test.example.com` or `*.example.com`.
+    /// * A Listener with `*.example.com` as the hostname matches HTTPRoutes
+    ///   that have either not specified any hostnames or have specified at least
+    ///   one hostname that matches the Listener hostname. For example,
+    ///   `test.example.com` and `*.example.com` would both match. On the other
+    ///   hand, `example.com` and `test.example.net` would not match.
+    ///
+    /// If both the Listener and HTTPRoute have specified hostnames, any
+    /// HTTPRoute hostnames that do not match the Listener hostname MUST be
+    /// ignored. For example, if a Listener specified `*.example.com`, and the
+    /// HTTPRoute specified `test.example.com` and `test.example.net`,
+    /// `test.example.net` must not be considered for a match.
+    ///
+    /// If both the Listener and HTTPRoute have specified hostnames, and none
+    /// match with the criteria above, then the HTTPRoute is not accepted. The
+    /// implementation must raise an 'Accepted' Condition with a status of
+    /// `False` in the corresponding RouteParentStatus.
+    ///
+    /// Support: Core
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        length(max = 16),
+        inner(length(min = 1, max = 253), regex = "crate::schema::HOSTNAME")
+    )]
+    pub hostnames: Option<Vec<Hostname>>,
+
+    /// Rules are a list of HTTP matchers, filters and actions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(length(max = 16))]
+    pub rules: Option<Vec<HttpRouteRule>>,
+}
+
+impl From<crate::HttpRouteSpec> for HttpRouteSpec {
+    fn from(spec: crate::HttpRouteSpec) -> Self {
+        let crate::HttpRouteSpec {
+            inner,
+            hostnames,
+            rules,
+        } = spec;
+        Self {
+            inner,
+            hostnames,
+            rules,
+        }
+    }
+}
+
+impl From<HttpRouteSpec> for crate::HttpRouteSpec {
+    fn from(spec: HttpRouteSpec) -> Self {
+        let HttpRouteSpec {
+            inner,
+            hostnames,
+            rules,
+        } = spec;
+        Self {
+            inner,
+            hostnames,
+            rules,
+        }
+    }
+}
+
+impl From<crate::HttpRoute> for HttpRoute {
+    fn from(obj: crate::HttpRoute) -> Self {
+        Self {
+            metadata: obj.metadata,
+            spec: obj.spec.into(),
+            status: obj.status,
+        }
+    }
+}
+
+impl From<HttpRoute> for crate::HttpRoute {
+    fn from(obj: HttpRoute) -> Self {
+        Self {
+            metadata: obj.metadata,
+            spec: obj.spec.into(),
+            status: obj.status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn converts_losslessly() {
+        let v1beta1: crate::HttpRoute = serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "HTTPRoute",
+            "metadata": { "name": "bar-route", "namespace": "bar" },
+            "spec": {
+                "parentRefs": [{ "name": "example-gateway", "sectionName": "http" }],
+                "hostnames": ["bar.example.com"],
+                "rules": [{
+                    "matches": [{ "path": { "type": "PathPrefix", "value": "/" } }],
+                    "backendRefs": [{ "name": "bar-svc", "port": 8080, "weight": 90 }]
+                }]
+            },
+            "status": { "parents": [] }
+        }))
+        .expect("route must deserialize");
+
+        let v1 = HttpRoute::from(v1beta1.clone());
+        let json = serde_json::to_value(&v1).unwrap();
+        assert_eq!(json["apiVersion"], "gateway.networking.k8s.io/v1");
+        assert_eq!(json["spec"], serde_json::to_value(&v1beta1.spec).unwrap());
+        assert_eq!(
+            json["status"],
+            serde_json::to_value(&v1beta1.status).unwrap()
+        );
+
+        let back = crate::HttpRoute::from(v1);
+        assert_eq!(
+            serde_json::to_value(back).unwrap(),
+            serde_json::to_value(v1beta1).unwrap()
+        );
+    }
+}
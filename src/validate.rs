@@ -0,0 +1,696 @@
+//! Client-side validation of Gateway API resources.
+//!
+//! The upstream CRDs constrain many fields with OpenAPI markers (`maxItems`,
+//! `pattern`, `minimum`, ...) and CEL rules (`x-kubernetes-validations`). The
+//! [`Validate`] trait enforces the same rules locally so that controllers can
+//! reject invalid objects before submitting them to the API server.
+
+mod gateway;
+mod gatewayclass;
+mod httproute;
+mod object_reference;
+mod shared;
+
+#[cfg(feature = "experimental")]
+mod exp {
+    mod backendtlspolicy;
+    mod grpcroute;
+    mod listenerset;
+    mod policy;
+    mod referencegrant;
+    mod tcproute;
+    mod tlsroute;
+    mod udproute;
+}
+
+use std::fmt;
+
+/// Validate checks a value against the validation constraints expressed by the
+/// upstream Gateway API CRDs.
+pub trait Validate {
+    /// Validates this value, returning every violation that was found.
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = Vec::new();
+        self.validate_at(&FieldPath::default(), &mut errors);
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(ValidationError { errors })
+    }
+
+    /// Records each violation in this value into `errors`. Violations are
+    /// reported relative to `path`, the location of this value within the
+    /// enclosing object.
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>);
+}
+
+/// FieldPath is the JSON path of a field within an object, e.g.
+/// `spec.rules[0].matches[1].path.value`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FieldPath(String);
+
+/// FieldError describes a single constraint violation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldError {
+    /// Path is the JSON path of the offending field.
+    pub path: FieldPath,
+
+    /// Message describes the violated constraint.
+    pub message: String,
+}
+
+/// ValidationError lists every constraint violation found in a value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationError {
+    errors: Vec<FieldError>,
+}
+
+// === impl FieldPath ===
+
+impl FieldPath {
+    /// Returns the path of the named field within this path.
+    pub fn field(&self, name: &str) -> Self {
+        if self.0.is_empty() {
+            return Self(name.to_string());
+        }
+        Self(format!("{}.{}", self.0, name))
+    }
+
+    /// Returns the path of the list item at `index` within this path.
+    pub fn index(&self, index: usize) -> Self {
+        Self(format!("{}[{}]", self.0, index))
+    }
+
+    /// Returns the path of the map entry for `key` within this path.
+    pub fn key(&self, key: &str) -> Self {
+        Self(format!("{}[{}]", self.0, key))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// === impl FieldError ===
+
+impl FieldError {
+    pub(crate) fn new(path: &FieldPath, message: impl Into<String>) -> Self {
+        Self {
+            path: path.clone(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.0.is_empty() {
+            return f.write_str(&self.message);
+        }
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+// === impl ValidationError ===
+
+impl ValidationError {
+    /// Returns the violations, in the order in which they were found.
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<FieldError> {
+        self.errors
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} validation error(s)", self.errors.len())?;
+        for (i, error) in self.errors.iter().enumerate() {
+            let sep = if i == 0 { ": " } else { "; " };
+            write!(f, "{sep}{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+// === helpers ===
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(value) = self {
+            value.validate_at(path, errors);
+        }
+    }
+}
+
+/// Validates each item of a list, enforcing its `minItems`/`maxItems` bounds.
+pub(crate) fn items<T: Validate>(
+    path: &FieldPath,
+    items: &[T],
+    min: usize,
+    max: usize,
+    errors: &mut Vec<FieldError>,
+) {
+    item_count(path, items.len(), min, max, errors);
+    for (i, item) in items.iter().enumerate() {
+        item.validate_at(&path.index(i), errors);
+    }
+}
+
+/// Like [`items`], for optional lists that need not satisfy `min` when unset.
+pub(crate) fn opt_items<T: Validate>(
+    path: &FieldPath,
+    list: &Option<Vec<T>>,
+    max: usize,
+    errors: &mut Vec<FieldError>,
+) {
+    if let Some(list) = list {
+        items(path, list, 0, max, errors);
+    }
+}
+
+pub(crate) fn item_count(
+    path: &FieldPath,
+    len: usize,
+    min: usize,
+    max: usize,
+    errors: &mut Vec<FieldError>,
+) {
+    if len < min {
+        errors.push(FieldError::new(
+            path,
+            format!("must have at least {min} item(s)"),
+        ));
+    }
+    if len > max {
+        errors.push(FieldError::new(
+            path,
+            format!("must have at most {max} item(s)"),
+        ));
+    }
+}
+
+pub(crate) fn length(
+    path: &FieldPath,
+    value: &str,
+    min: usize,
+    max: usize,
+    errors: &mut Vec<FieldError>,
+) -> bool {
+    if value.len() < min {
+        errors.push(FieldError::new(
+            path,
+            format!("must be at least {min} character(s) long"),
+        ));
+        return false;
+    }
+    if value.len() > max {
+        errors.push(FieldError::new(
+            path,
+            format!("must be at most {max} characters long"),
+        ));
+        return false;
+    }
+    true
+}
+
+/// Checks that `value` has a valid length and matches `pattern`. `matches`
+/// implements the upstream regular expression, which is reported verbatim in
+/// the error message.
+pub(crate) fn format(
+    path: &FieldPath,
+    value: &str,
+    (min, max): (usize, usize),
+    (matches, pattern): (fn(&str) -> bool, &str),
+    errors: &mut Vec<FieldError>,
+) {
+    if length(path, value, min, max, errors) && !matches(value) {
+        errors.push(FieldError::new(path, format!("must match {pattern}")));
+    }
+}
+
+pub(crate) fn one_of(
+    path: &FieldPath,
+    value: &str,
+    allowed: &[&str],
+    errors: &mut Vec<FieldError>,
+) {
+    if !allowed.contains(&value) {
+        errors.push(FieldError::new(
+            path,
+            format!(
+                "unsupported value {value:?}: supported values: {}",
+                allowed
+                    .iter()
+                    .map(|v| format!("{v:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ));
+    }
+}
+
+pub(crate) fn port(path: &FieldPath, port: crate::PortNumber, errors: &mut Vec<FieldError>) {
+    if port == 0 {
+        errors.push(FieldError::new(path, "must be between 1 and 65535"));
+    }
+}
+
+/// Reports items whose key appears more than once in a `listType=map` list.
+pub(crate) fn unique_keys<'a>(
+    path: &FieldPath,
+    keys: impl IntoIterator<Item = &'a str>,
+    errors: &mut Vec<FieldError>,
+) {
+    let mut seen = std::collections::HashSet::new();
+    for (i, key) in keys.into_iter().enumerate() {
+        if !seen.insert(key) {
+            errors.push(FieldError::new(
+                &path.index(i),
+                format!("duplicate value {key:?}"),
+            ));
+        }
+    }
+}
+
+/// Formats implements the regular expressions that upstream uses to constrain
+/// string fields.
+pub(crate) mod formats {
+    /// `^[a-z0-9]([-a-z0-9]*[a-z0-9])?$`
+    pub(crate) fn is_dns_label(s: &str) -> bool {
+        let bytes = s.as_bytes();
+        match (bytes.first(), bytes.last()) {
+            (Some(first), Some(last)) => {
+                is_lower_alnum(*first)
+                    && is_lower_alnum(*last)
+                    && bytes.iter().all(|b| is_lower_alnum(*b) || *b == b'-')
+            }
+            _ => false,
+        }
+    }
+
+    /// `^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$`
+    pub(crate) fn is_dns_subdomain(s: &str) -> bool {
+        s.split('.').all(is_dns_label)
+    }
+
+    /// `^(\*\.)?[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$`
+    pub(crate) fn is_hostname(s: &str) -> bool {
+        is_dns_subdomain(s.strip_prefix("*.").unwrap_or(s))
+    }
+
+    /// `^$|^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$`
+    pub(crate) fn is_group(s: &str) -> bool {
+        s.is_empty() || is_dns_subdomain(s)
+    }
+
+    /// `^[a-zA-Z]([-a-zA-Z0-9]*[a-zA-Z0-9])?$`
+    pub(crate) fn is_kind(s: &str) -> bool {
+        let bytes = s.as_bytes();
+        match (bytes.first(), bytes.last()) {
+            (Some(first), Some(last)) => {
+                first.is_ascii_alphabetic()
+                    && last.is_ascii_alphanumeric()
+                    && bytes
+                        .iter()
+                        .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+            }
+            _ => false,
+        }
+    }
+
+    /// `^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*\/[A-Za-z0-9\/\-._~%!$&'()*+,;=:]+$`
+    pub(crate) fn is_domain_prefixed_path(s: &str) -> bool {
+        match s.split_once('/') {
+            Some((domain, path)) => {
+                is_dns_subdomain(domain)
+                    && !path.is_empty()
+                    && path
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b"/-._~%!$&'()*+,;=:".contains(&b))
+            }
+            None => false,
+        }
+    }
+
+    /// `^[a-zA-Z0-9]([-a-zA-Z0-9]*[a-zA-Z0-9])?$|[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*\/[A-Za-z0-9]+$`
+    pub(crate) fn is_protocol(s: &str) -> bool {
+        if let Some((domain, name)) = s.split_once('/') {
+            return is_dns_subdomain(domain)
+                && !name.is_empty()
+                && name.bytes().all(|b| b.is_ascii_alphanumeric());
+        }
+        let bytes = s.as_bytes();
+        match (bytes.first(), bytes.last()) {
+            (Some(first), Some(last)) => {
+                first.is_ascii_alphanumeric()
+                    && last.is_ascii_alphanumeric()
+                    && bytes
+                        .iter()
+                        .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+            }
+            _ => false,
+        }
+    }
+
+    /// `^Hostname|IPAddress|NamedAddress|[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*\/[A-Za-z0-9\/\-._~%!$&'()*+,;=:]+$`
+    pub(crate) fn is_address_type(s: &str) -> bool {
+        matches!(s, "Hostname" | "IPAddress" | "NamedAddress") || is_domain_prefixed_path(s)
+    }
+
+    /// `^[A-Za-z0-9!#$%&'*+\-.^_\x60|~]+$`
+    pub(crate) fn is_header_name(s: &str) -> bool {
+        !s.is_empty()
+            && s.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+    }
+
+    /// `^(?:[-A-Za-z0-9/._~!$&'()*+,;=:@]|[%][0-9a-fA-F]{2})+$`
+    pub(crate) fn is_path(s: &str) -> bool {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if b == b'%' {
+                match bytes.get(i + 1..i + 3) {
+                    Some([hi, lo]) if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+                        i += 3;
+                        continue;
+                    }
+                    _ => return false,
+                }
+            }
+            if !(b.is_ascii_alphanumeric() || b"-/._~!$&'()*+,;=:@".contains(&b)) {
+                return false;
+            }
+            i += 1;
+        }
+        !bytes.is_empty()
+    }
+
+    /// `^(([A-Za-z0-9][-A-Za-z0-9_.]{0,251})?[A-Za-z0-9]\/)?([A-Za-z0-9][-A-Za-z0-9_.]{0,61})?[A-Za-z0-9]$`
+    pub(crate) fn is_annotation_key(s: &str) -> bool {
+        fn segment(s: &str, max: usize) -> bool {
+            let bytes = s.as_bytes();
+            match (bytes.first(), bytes.last()) {
+                (Some(first), Some(last)) => {
+                    bytes.len() <= max
+                        && first.is_ascii_alphanumeric()
+                        && last.is_ascii_alphanumeric()
+                        && bytes
+                            .iter()
+                            .all(|b| b.is_ascii_alphanumeric() || b"-_.".contains(b))
+                }
+                _ => false,
+            }
+        }
+        match s.split_once('/') {
+            Some((prefix, name)) => segment(prefix, 253) && segment(name, 63),
+            None => segment(s, 63),
+        }
+    }
+
+    /// `^([a-zA-Z0-9]([-a-zA-Z0-9]*[a-zA-Z0-9])?([.][a-zA-Z0-9]([-a-zA-Z0-9]*[a-zA-Z0-9])?)*/)?[A-Za-z0-9]([-A-Za-z0-9_.]{0,61}[A-Za-z0-9])?$`
+    pub(crate) fn is_label_key(s: &str) -> bool {
+        let (prefix, name) = match s.split_once('/') {
+            Some((prefix, name)) => (Some(prefix), name),
+            None => (None, s),
+        };
+        let prefix_ok = prefix.map_or(true, |p| {
+            p.split('.').all(|label| {
+                let bytes = label.as_bytes();
+                match (bytes.first(), bytes.last()) {
+                    (Some(first), Some(last)) => {
+                        first.is_ascii_alphanumeric()
+                            && last.is_ascii_alphanumeric()
+                            && bytes
+                                .iter()
+                                .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+                    }
+                    _ => false,
+                }
+            })
+        });
+        prefix_ok && name.len() <= 63 && is_label_value(name) && !name.is_empty()
+    }
+
+    /// `^(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9])?$`
+    pub(crate) fn is_label_value(s: &str) -> bool {
+        let bytes = s.as_bytes();
+        match (bytes.first(), bytes.last()) {
+            (Some(first), Some(last)) => {
+                first.is_ascii_alphanumeric()
+                    && last.is_ascii_alphanumeric()
+                    && bytes
+                        .iter()
+                        .all(|b| b.is_ascii_alphanumeric() || b"-_.".contains(b))
+            }
+            _ => true,
+        }
+    }
+
+    /// `^([0-9]{1,5}(h|m|s|ms)){1,4}$`
+    pub(crate) fn is_duration(s: &str) -> bool {
+        let mut rest = s;
+        let mut components = 0;
+        while !rest.is_empty() {
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            if !(1..=5).contains(&digits) {
+                return false;
+            }
+            rest = &rest[digits..];
+            rest = match rest.strip_prefix("ms") {
+                Some(rest) => rest,
+                None => match rest.as_bytes().first() {
+                    Some(b'h' | b'm' | b's') => &rest[1..],
+                    _ => return false,
+                },
+            };
+            components += 1;
+        }
+        (1..=4).contains(&components)
+    }
+
+    /// `^(([^:/?#]+):)(//([^/?#]*))([^?#]*)(\?([^#]*))?(#(.*))?`
+    #[cfg(feature = "experimental")]
+    pub(crate) fn is_absolute_uri(s: &str) -> bool {
+        match s.split_once(':') {
+            Some((scheme, rest)) => {
+                !scheme.is_empty() && !scheme.contains(['/', '?', '#']) && rest.starts_with("//")
+            }
+            None => false,
+        }
+    }
+
+    /// `^(\*|https?:\/\/(\*|(\*\.)?(([\w-]+\.?)+)?[\w-]+)(:\d{1,5})?)$`
+    pub(crate) fn is_cors_origin(s: &str) -> bool {
+        if s == "*" {
+            return true;
+        }
+        let Some(rest) = s
+            .strip_prefix("http://")
+            .or_else(|| s.strip_prefix("https://"))
+        else {
+            return false;
+        };
+        let (host, port) = match rest.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (rest, None),
+        };
+        if let Some(port) = port {
+            if !(1..=5).contains(&port.len()) || !port.bytes().all(|b| b.is_ascii_digit()) {
+                return false;
+            }
+        }
+        if host == "*" {
+            return true;
+        }
+        host.strip_prefix("*.")
+            .unwrap_or(host)
+            .split('.')
+            .all(|label| {
+                !label.is_empty()
+                    && label
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+            })
+    }
+
+    fn is_lower_alnum(b: u8) -> bool {
+        b.is_ascii_lowercase() || b.is_ascii_digit()
+    }
+}
+
+/// Validates the named-type string fields shared by most references.
+pub(crate) mod fields {
+    use super::{format, formats, FieldError, FieldPath};
+
+    pub(crate) fn group(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (0, 253),
+            (formats::is_group, crate::schema::GROUP),
+            errors,
+        );
+    }
+
+    pub(crate) fn kind(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (1, 63),
+            (formats::is_kind, crate::schema::KIND),
+            errors,
+        );
+    }
+
+    pub(crate) fn object_name(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        super::length(path, value, 1, 253, errors);
+    }
+
+    pub(crate) fn namespace(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (1, 63),
+            (formats::is_dns_label, crate::schema::NAMESPACE),
+            errors,
+        );
+    }
+
+    pub(crate) fn section_name(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (1, 253),
+            (formats::is_dns_subdomain, crate::schema::SECTION_NAME),
+            errors,
+        );
+    }
+
+    pub(crate) fn hostname(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (1, 253),
+            (formats::is_hostname, crate::schema::HOSTNAME),
+            errors,
+        );
+    }
+
+    pub(crate) fn precise_hostname(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (1, 253),
+            (formats::is_dns_subdomain, crate::schema::PRECISE_HOSTNAME),
+            errors,
+        );
+    }
+
+    pub(crate) fn controller_name(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (1, 253),
+            (
+                formats::is_domain_prefixed_path,
+                crate::schema::GATEWAY_CONTROLLER,
+            ),
+            errors,
+        );
+    }
+
+    pub(crate) fn duration(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (0, usize::MAX),
+            (formats::is_duration, crate::schema::DURATION),
+            errors,
+        );
+    }
+
+    pub(crate) fn header_name(path: &FieldPath, value: &str, errors: &mut Vec<FieldError>) {
+        format(
+            path,
+            value,
+            (1, 256),
+            (formats::is_header_name, crate::schema::HEADER_NAME),
+            errors,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::formats::*;
+
+    #[test]
+    fn hostnames() {
+        for valid in ["example.com", "*.example.com", "foo", "a-b.c1", "1.2.3.4"] {
+            assert!(is_hostname(valid), "{valid}");
+        }
+        for invalid in [
+            "",
+            "*",
+            "*.",
+            "foo.*.example.com",
+            "-foo.example.com",
+            "foo-.example.com",
+            "Example.com",
+            "example.com.",
+            "foo..example.com",
+            "foo_bar.example.com",
+        ] {
+            assert!(!is_hostname(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn protocols() {
+        for valid in ["HTTP", "HTTPS", "h2c", "example.com/Custom"] {
+            assert!(is_protocol(valid), "{valid}");
+        }
+        for invalid in ["", "-HTTP", "example.com/", "example.com/a-b", "HTTP/"] {
+            assert!(!is_protocol(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn paths() {
+        for valid in ["/", "/foo/bar", "/foo%2Fbar", "/a@b:c;d=e"] {
+            assert!(is_path(valid), "{valid}");
+        }
+        for invalid in ["", "/foo bar", "/foo%2", "/foo%zz", "/foo?bar", "/foo#bar"] {
+            assert!(!is_path(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn controller_names() {
+        assert!(is_domain_prefixed_path("example.com/gateway-controller"));
+        assert!(!is_domain_prefixed_path("example.com"));
+        assert!(!is_domain_prefixed_path("example.com/"));
+        assert!(!is_domain_prefixed_path("Example.com/foo"));
+    }
+
+    #[test]
+    fn annotation_keys() {
+        assert!(is_annotation_key("example"));
+        assert!(is_annotation_key("example.com/path.html"));
+        assert!(!is_annotation_key("example~"));
+        assert!(!is_annotation_key("example.com."));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::validate::{fields, format, formats, one_of, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for BackendTlsPolicy {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for BackendTlsPolicySpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        let target_refs = path.field("targetRefs");
+        crate::validate::items(&target_refs, &self.target_refs, 1, 16, errors);
+        super::policy::target_refs_with_section_names(&target_refs, &self.target_refs, errors);
+        self.validation
+            .validate_at(&path.field("validation"), errors);
+        if let Some(options) = &self.options {
+            crate::validate::gateway::tls_options(&path.field("options"), options, errors);
+        }
+    }
+}
+
+impl Validate for BackendTlsPolicyValidation {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        crate::validate::opt_items(
+            &path.field("caCertificateRefs"),
+            &self.ca_certificate_refs,
+            8,
+            errors,
+        );
+        if let Some(WellKnownCaCertificatesType::Other(value)) = &self.well_known_ca_certificates {
+            one_of(
+                &path.field("wellKnownCACertificates"),
+                value,
+                &["System"],
+                errors,
+            );
+        }
+        fields::precise_hostname(&path.field("hostname"), &self.hostname, errors);
+        crate::validate::opt_items(
+            &path.field("subjectAltNames"),
+            &self.subject_alt_names,
+            5,
+            errors,
+        );
+
+        let has_refs = self
+            .ca_certificate_refs
+            .as_ref()
+            .map_or(false, |r| !r.is_empty());
+        let has_well_known = self
+            .well_known_ca_certificates
+            .as_ref()
+            .map_or(false, |w| !w.as_str().is_empty());
+        if has_refs && has_well_known {
+            errors.push(FieldError::new(
+                path,
+                "must not contain both CACertificateRefs and WellKnownCACertificates",
+            ));
+        }
+        if !has_refs && !has_well_known {
+            errors.push(FieldError::new(
+                path,
+                "must specify either CACertificateRefs or WellKnownCACertificates",
+            ));
+        }
+    }
+}
+
+impl Validate for SubjectAltName {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let SubjectAltNameType::Other(type_) = &self.type_ {
+            one_of(&path.field("type"), type_, &["Hostname", "URI"], errors);
+        }
+        let hostname = self.hostname.as_ref().filter(|h| !h.is_empty());
+        if let Some(hostname) = hostname {
+            fields::hostname(&path.field("hostname"), hostname, errors);
+        }
+        let uri = self.uri.as_ref().filter(|u| !u.is_empty());
+        if let Some(uri) = uri {
+            format(
+                &path.field("uri"),
+                uri,
+                (1, 253),
+                (formats::is_absolute_uri, crate::schema::ABSOLUTE_URI),
+                errors,
+            );
+        }
+
+        let is_hostname = matches!(self.type_, SubjectAltNameType::Hostname);
+        let is_uri = matches!(self.type_, SubjectAltNameType::Uri);
+        let rules = [
+            (
+                is_hostname && hostname.is_none(),
+                "SubjectAltName element must contain Hostname, if Type is set to Hostname",
+            ),
+            (
+                !is_hostname && hostname.is_some(),
+                "SubjectAltName element must not contain Hostname, if Type is not set to Hostname",
+            ),
+            (
+                is_uri && uri.is_none(),
+                "SubjectAltName element must contain URI, if Type is set to URI",
+            ),
+            (
+                !is_uri && uri.is_some(),
+                "SubjectAltName element must not contain URI, if Type is not set to URI",
+            ),
+        ];
+        for (violated, message) in rules {
+            if violated {
+                errors.push(FieldError::new(path, message));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn requires_one_ca_source() {
+        let spec = serde_json::from_value::<BackendTlsPolicySpec>(serde_json::json!({
+            "targetRefs": [
+                { "group": "", "kind": "Service", "name": "web" },
+                { "group": "", "kind": "Service", "name": "web", "sectionName": "https" },
+            ],
+            "validation": {
+                "hostname": "web.example.com",
+                "subjectAltNames": [
+                    { "type": "Hostname", "uri": "spiffe://cluster.local/ns/default/sa/web" },
+                ],
+            },
+        }))
+        .unwrap();
+        let errors = spec.validate().expect_err("spec must be invalid");
+        assert_eq!(
+            errors.errors().iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "targetRefs: sectionName must be specified when targetRefs includes 2 or more references to the same target",
+                "validation.subjectAltNames[0]: SubjectAltName element must contain Hostname, if Type is set to Hostname",
+                "validation.subjectAltNames[0]: SubjectAltName element must not contain URI, if Type is not set to URI",
+                "validation: must specify either CACertificateRefs or WellKnownCACertificates",
+            ]
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use crate::validate::{fields, shared::weight_at, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for GrpcRoute {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for GrpcRouteSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        if let Some(hostnames) = &self.hostnames {
+            let path = path.field("hostnames");
+            crate::validate::item_count(&path, hostnames.len(), 0, 16, errors);
+            for (i, hostname) in hostnames.iter().enumerate() {
+                fields::hostname(&path.index(i), hostname, errors);
+            }
+        }
+        crate::validate::opt_items(&path.field("rules"), &self.rules, 16, errors);
+    }
+}
+
+impl Validate for GrpcRouteRule {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        crate::validate::opt_items(&path.field("matches"), &self.matches, 8, errors);
+        if let Some(filters) = &self.filters {
+            grpc_filters(&path.field("filters"), filters, errors);
+        }
+        crate::validate::opt_items(&path.field("backendRefs"), &self.backend_refs, 16, errors);
+    }
+}
+
+fn grpc_filters(path: &FieldPath, filters: &[GrpcRouteFilter], errors: &mut Vec<FieldError>) {
+    crate::validate::items(path, filters, 0, 16, errors);
+
+    let count = |f: fn(&GrpcRouteFilter) -> bool| filters.iter().filter(|x| f(x)).count();
+    let repeated = [
+        (
+            count(|f| matches!(f, GrpcRouteFilter::RequestHeaderModifier { .. })),
+            "RequestHeaderModifier filter cannot be repeated",
+        ),
+        (
+            count(|f| matches!(f, GrpcRouteFilter::ResponseHeaderModifier { .. })),
+            "ResponseHeaderModifier filter cannot be repeated",
+        ),
+    ];
+    for (n, message) in repeated {
+        if n > 1 {
+            errors.push(FieldError::new(path, message));
+        }
+    }
+}
+
+impl Validate for GrpcRouteMatch {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.method.validate_at(&path.field("method"), errors);
+        if let Some(headers) = &self.headers {
+            let path = path.field("headers");
+            crate::validate::items(&path, headers, 0, 16, errors);
+        }
+    }
+}
+
+impl Validate for GrpcMethodMatch {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        let (service, method, exact) = match self {
+            Self::Exact { service, method } => (service, method, true),
+            Self::RegularExpression { service, method } => (service, method, false),
+            Self::Unknown { type_, .. } => {
+                return crate::validate::one_of(
+                    &path.field("type"),
+                    type_,
+                    Self::KNOWN_TYPES,
+                    errors,
+                );
+            }
+        };
+        if service.is_none() && method.is_none() {
+            errors.push(FieldError::new(
+                path,
+                "One or both of 'service' or 'method' must be specified",
+            ));
+        }
+        if let Some(service) = service {
+            let path = path.field("service");
+            if crate::validate::length(&path, service, 0, 1024, errors)
+                && exact
+                && !is_grpc_service(service)
+            {
+                errors.push(FieldError::new(
+                    &path,
+                    r"service must only contain valid characters (matching ^(?i)\.?[a-z_][a-z_0-9]*(\.[a-z_][a-z_0-9]*)*$)",
+                ));
+            }
+        }
+        if let Some(method) = method {
+            let path = path.field("method");
+            if crate::validate::length(&path, method, 0, 1024, errors)
+                && exact
+                && !is_grpc_method(method)
+            {
+                errors.push(FieldError::new(
+                    &path,
+                    "method must only contain valid characters (matching ^[A-Za-z_][A-Za-z_0-9]*$)",
+                ));
+            }
+        }
+    }
+}
+
+/// `^(?i)\.?[a-z_][a-z_0-9]*(\.[a-z_][a-z_0-9]*)*$`
+fn is_grpc_service(s: &str) -> bool {
+    s.strip_prefix('.')
+        .unwrap_or(s)
+        .split('.')
+        .all(is_grpc_method)
+}
+
+/// `^[A-Za-z_][A-Za-z_0-9]*$`
+fn is_grpc_method(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    matches!(bytes.next(), Some(b) if b.is_ascii_alphabetic() || b == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+impl Validate for GrpcRouteFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        match self {
+            Self::ExtensionRef { extension_ref } => {
+                extension_ref.validate_at(&path.field("extensionRef"), errors)
+            }
+            Self::RequestMirror { request_mirror } => {
+                request_mirror.validate_at(&path.field("requestMirror"), errors)
+            }
+            Self::RequestHeaderModifier {
+                request_header_modifier,
+            } => request_header_modifier.validate_at(&path.field("requestHeaderModifier"), errors),
+            Self::ResponseHeaderModifier {
+                response_header_modifier,
+            } => {
+                response_header_modifier.validate_at(&path.field("responseHeaderModifier"), errors)
+            }
+            Self::Unknown { type_, .. } => {
+                crate::validate::one_of(&path.field("type"), type_, Self::KNOWN_TYPES, errors)
+            }
+        }
+    }
+}
+
+impl Validate for GrpcRouteBackendRef {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        if let Some(filters) = &self.filters {
+            grpc_filters(&path.field("filters"), filters, errors);
+        }
+        if let Some(weight) = self.weight {
+            weight_at(&path.field("weight"), weight, errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn method_names() {
+        assert!(is_grpc_service("com.example.User"));
+        assert!(is_grpc_service(".grpc_health.v1"));
+        assert!(!is_grpc_service("com..example"));
+        assert!(!is_grpc_service("com.1example"));
+        assert!(is_grpc_method("Login"));
+        assert!(!is_grpc_method("Log-in"));
+    }
+
+    #[test]
+    fn requires_service_or_method() {
+        let spec = GrpcRouteSpec {
+            rules: Some(vec![GrpcRouteRule {
+                matches: Some(vec![GrpcRouteMatch {
+                    method: Some(GrpcMethodMatch::Exact {
+                        method: None,
+                        service: None,
+                    }),
+                    headers: None,
+                }]),
+                ..GrpcRouteRule::default()
+            }]),
+            ..GrpcRouteSpec::default()
+        };
+        let errors = spec.validate().expect_err("spec must be invalid");
+        assert_eq!(
+            errors.to_string(),
+            "1 validation error(s): rules[0].matches[0].method: One or both of 'service' or 'method' must be specified"
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use crate::validate::{FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for ListenerSet {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for ListenerSetSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.parent_ref
+            .validate_at(&path.field("parentRef"), errors);
+        let path = path.field("listeners");
+        crate::validate::items(&path, &self.listeners, 1, 64, errors);
+        crate::validate::gateway::listeners(&path, &self.listeners, errors);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::validate::{fields, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for PolicyTargetReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        fields::object_name(&path.field("name"), &self.name, errors);
+        if let Some(ns) = &self.namespace {
+            fields::namespace(&path.field("namespace"), ns, errors);
+        }
+    }
+}
+
+impl Validate for PolicyTargetReferenceWithSectionName {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        if let Some(section) = &self.section_name {
+            fields::section_name(&path.field("sectionName"), section, errors);
+        }
+    }
+}
+
+impl Validate for LocalPolicyTargetReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        fields::object_name(&path.field("name"), &self.name, errors);
+    }
+}
+
+impl Validate for LocalPolicyTargetReferenceWithSectionName {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        if let Some(section) = &self.section_name {
+            fields::section_name(&path.field("sectionName"), section, errors);
+        }
+    }
+}
+
+/// Enforces the CEL rules that upstream applies to a list of target
+/// references with section names: references to the same target must all
+/// name distinct sections.
+pub(super) fn target_refs_with_section_names<T: PolicyTargetRef>(
+    path: &FieldPath,
+    target_refs: &[T],
+    errors: &mut Vec<FieldError>,
+) {
+    let same_target =
+        |a: &T, b: &T| a.group() == b.group() && a.kind() == b.kind() && a.name() == b.name();
+    let mut specified = true;
+    let mut unique = true;
+    for (i, a) in target_refs.iter().enumerate() {
+        for b in &target_refs[i + 1..] {
+            if same_target(a, b) {
+                specified &= a.section_name().is_some() == b.section_name().is_some();
+                unique &= a.section_name() != b.section_name();
+            }
+        }
+    }
+    if !specified {
+        errors.push(FieldError::new(
+            path,
+            "sectionName must be specified when targetRefs includes 2 or more references to the same target",
+        ));
+    }
+    if !unique {
+        errors.push(FieldError::new(
+            path,
+            "sectionName must be unique when targetRefs includes 2 or more references to the same target",
+        ));
+    }
+}
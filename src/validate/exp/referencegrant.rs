@@ -0,0 +1,33 @@
+use crate::validate::{fields, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for ReferenceGrant {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for ReferenceGrantSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        crate::validate::items(&path.field("from"), &self.from, 1, 16, errors);
+        crate::validate::items(&path.field("to"), &self.to, 1, 16, errors);
+    }
+}
+
+impl Validate for ReferenceGrantFrom {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        fields::namespace(&path.field("namespace"), &self.namespace, errors);
+    }
+}
+
+impl Validate for ReferenceGrantTo {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        if let Some(name) = &self.name {
+            fields::object_name(&path.field("name"), name, errors);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::validate::{FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for TcpRoute {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for TcpRouteSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        crate::validate::items(&path.field("rules"), &self.rules, 1, 16, errors);
+    }
+}
+
+impl Validate for TcpRouteRule {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        crate::validate::items(
+            &path.field("backendRefs"),
+            &self.backend_refs,
+            1,
+            16,
+            errors,
+        );
+    }
+}
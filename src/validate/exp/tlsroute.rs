@@ -0,0 +1,34 @@
+use crate::validate::{fields, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for TlsRoute {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for TlsRouteSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        if let Some(hostnames) = &self.hostnames {
+            let path = path.field("hostnames");
+            crate::validate::item_count(&path, hostnames.len(), 0, 16, errors);
+            for (i, hostname) in hostnames.iter().enumerate() {
+                fields::hostname(&path.index(i), hostname, errors);
+            }
+        }
+        crate::validate::items(&path.field("rules"), &self.rules, 1, 16, errors);
+    }
+}
+
+impl Validate for TlsRouteRule {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        crate::validate::items(
+            &path.field("backendRefs"),
+            &self.backend_refs,
+            1,
+            16,
+            errors,
+        );
+    }
+}
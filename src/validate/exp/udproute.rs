@@ -0,0 +1,27 @@
+use crate::validate::{FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for UdpRoute {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for UdpRouteSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        crate::validate::items(&path.field("rules"), &self.rules, 1, 16, errors);
+    }
+}
+
+impl Validate for UdpRouteRule {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        crate::validate::items(
+            &path.field("backendRefs"),
+            &self.backend_refs,
+            1,
+            16,
+            errors,
+        );
+    }
+}
@@ -0,0 +1,490 @@
+use super::{fields, format, formats, one_of, port, FieldError, FieldPath, Validate};
+use crate::*;
+use std::collections::{BTreeMap, HashSet};
+
+impl Validate for Gateway {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for GatewaySpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        gateway_spec(
+            path,
+            &self.gateway_class_name,
+            &self.listeners,
+            self.addresses.as_deref(),
+            errors,
+        );
+        self.infrastructure
+            .validate_at(&path.field("infrastructure"), errors);
+        self.backend_tls
+            .validate_at(&path.field("backendTLS"), errors);
+        self.allowed_listeners
+            .validate_at(&path.field("allowedListeners"), errors);
+    }
+}
+
+impl Validate for v1::Gateway {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for v1::GatewaySpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        gateway_spec(
+            path,
+            &self.gateway_class_name,
+            &self.listeners,
+            self.addresses.as_deref(),
+            errors,
+        );
+        self.infrastructure
+            .validate_at(&path.field("infrastructure"), errors);
+        self.backend_tls
+            .validate_at(&path.field("backendTLS"), errors);
+        self.allowed_listeners
+            .validate_at(&path.field("allowedListeners"), errors);
+    }
+}
+
+/// Validates the fields that the v1 and v1beta1 Gateway specs have in common.
+fn gateway_spec(
+    path: &FieldPath,
+    gateway_class_name: &str,
+    listeners: &[Listener],
+    addresses: Option<&[GatewayAddress]>,
+    errors: &mut Vec<FieldError>,
+) {
+    fields::object_name(&path.field("gatewayClassName"), gateway_class_name, errors);
+
+    let listeners_path = path.field("listeners");
+    super::items(&listeners_path, listeners, 1, 64, errors);
+    self::listeners(&listeners_path, listeners, errors);
+
+    if let Some(addresses) = addresses {
+        let path = path.field("addresses");
+        super::items(&path, addresses, 0, 16, errors);
+        for (ty, message) in [
+            ("IPAddress", "IPAddress values must be unique"),
+            ("Hostname", "Hostname values must be unique"),
+        ] {
+            let mut seen = HashSet::new();
+            let dup = addresses
+                .iter()
+                .filter(|a| a.r#type.as_ref().map_or("IPAddress", AddressType::as_str) == ty)
+                .any(|a| !seen.insert(a.value.as_str()));
+            if dup {
+                errors.push(FieldError::new(&path, message));
+            }
+        }
+    }
+}
+
+impl Validate for GatewayInfrastructure {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(labels) = &self.labels {
+            let path = path.field("labels");
+            infrastructure_map(
+                &path,
+                labels.keys(),
+                (formats::is_label_key, "Label keys must be in the form of an optional DNS subdomain prefix followed by a required name segment of up to 63 characters."),
+                "If specified, the label key's prefix must be a DNS subdomain not longer than 253 characters in total.",
+                errors,
+            );
+            for (key, value) in labels {
+                format(
+                    &path.key(key),
+                    value,
+                    (0, 63),
+                    (formats::is_label_value, crate::schema::LABEL_VALUE),
+                    errors,
+                );
+            }
+        }
+        if let Some(annotations) = &self.annotations {
+            let path = path.field("annotations");
+            infrastructure_map(
+                &path,
+                annotations.keys(),
+                (formats::is_annotation_key, "Annotation keys must be in the form of an optional DNS subdomain prefix followed by a required name segment of up to 63 characters."),
+                "If specified, the annotation key's prefix must be a DNS subdomain not longer than 253 characters in total.",
+                errors,
+            );
+            for (key, value) in annotations {
+                super::length(&path.key(key), value, 0, 4096, errors);
+            }
+        }
+        self.parameters_ref
+            .validate_at(&path.field("parametersRef"), errors);
+    }
+}
+
+/// Enforces the size and key rules that upstream applies to infrastructure
+/// labels and annotations.
+fn infrastructure_map<'a>(
+    path: &FieldPath,
+    keys: impl ExactSizeIterator<Item = &'a String> + Clone,
+    (is_key, key_message): (fn(&str) -> bool, &str),
+    prefix_message: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    if keys.len() > 8 {
+        errors.push(FieldError::new(path, "must have at most 8 properties"));
+    }
+    if !keys.clone().all(|k| is_key(k)) {
+        errors.push(FieldError::new(path, key_message));
+    }
+    if !keys
+        .clone()
+        .all(|k| k.split('/').next().map_or(0, str::len) < 253)
+    {
+        errors.push(FieldError::new(path, prefix_message));
+    }
+}
+
+impl Validate for LocalParametersReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        fields::object_name(&path.field("name"), &self.name, errors);
+    }
+}
+
+impl Validate for GatewayBackendTls {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.client_certificate_ref
+            .validate_at(&path.field("clientCertificateRef"), errors);
+    }
+}
+
+impl Validate for AllowedListeners {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.namespaces
+            .validate_at(&path.field("namespaces"), errors);
+    }
+}
+
+impl Validate for ListenerNamespaces {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(from) = &self.from {
+            one_of(
+                &path.field("from"),
+                from.as_str(),
+                &["All", "Selector", "Same", "None"],
+                errors,
+            );
+        }
+    }
+}
+
+/// Validates the implementation-specific TLS options of a listener or policy.
+pub(super) fn tls_options(
+    path: &FieldPath,
+    options: &BTreeMap<String, String>,
+    errors: &mut Vec<FieldError>,
+) {
+    if options.len() > 16 {
+        errors.push(FieldError::new(path, "must have at most 16 properties"));
+    }
+    for (key, value) in options {
+        let path = path.key(key);
+        format(
+            &path,
+            key,
+            (1, 253),
+            (formats::is_annotation_key, crate::schema::ANNOTATION_KEY),
+            errors,
+        );
+        super::length(&path, value, 0, 4096, errors);
+    }
+}
+
+/// A predicate that every listener must satisfy and the message reported when
+/// one does not.
+type ListenerRule = (fn(&Listener) -> bool, &'static str);
+
+/// Enforces the CEL rules that upstream applies across the listener list.
+pub(super) fn listeners(path: &FieldPath, listeners: &[Listener], errors: &mut Vec<FieldError>) {
+    let has_hostname = |l: &Listener| l.hostname.as_deref().map_or(false, |h| !h.is_empty());
+    let rules: [ListenerRule; 3] = [
+        (
+            |l| {
+                !(matches!(
+                    l.protocol,
+                    ProtocolType::Http | ProtocolType::Tcp | ProtocolType::Udp
+                ) && l.tls.is_some())
+            },
+            "tls must not be specified for protocols ['HTTP', 'TCP', 'UDP']",
+        ),
+        (
+            |l| !matches!(l.protocol, ProtocolType::Https | ProtocolType::Tls) || l.tls.is_some(),
+            "tls must be specified for protocols ['HTTPS', 'TLS']",
+        ),
+        (
+            |l| {
+                !matches!(l.protocol, ProtocolType::Tcp | ProtocolType::Udp)
+                    || l.hostname.as_deref().map_or(true, str::is_empty)
+            },
+            "hostname must not be specified for protocols ['TCP', 'UDP']",
+        ),
+    ];
+    for (rule, message) in rules {
+        if !listeners.iter().all(rule) {
+            errors.push(FieldError::new(path, message));
+        }
+    }
+
+    let mut names = HashSet::new();
+    if !listeners.iter().all(|l| names.insert(l.name.as_str())) {
+        errors.push(FieldError::new(
+            path,
+            "Listener name must be unique within the Gateway",
+        ));
+    }
+
+    let mut combinations = HashSet::new();
+    let unique = listeners.iter().all(|l| {
+        let hostname = if has_hostname(l) {
+            l.hostname.as_deref()
+        } else {
+            None
+        };
+        combinations.insert((l.port, l.protocol.as_str(), hostname))
+    });
+    if !unique {
+        errors.push(FieldError::new(
+            path,
+            "Combination of port, protocol and hostname must be unique for each listener",
+        ));
+    }
+}
+
+impl Validate for Listener {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::section_name(&path.field("name"), &self.name, errors);
+        if let Some(hostname) = &self.hostname {
+            fields::hostname(&path.field("hostname"), hostname, errors);
+        }
+        port(&path.field("port"), self.port, errors);
+        format(
+            &path.field("protocol"),
+            self.protocol.as_str(),
+            (1, 255),
+            (formats::is_protocol, crate::schema::PROTOCOL),
+            errors,
+        );
+        self.tls.validate_at(&path.field("tls"), errors);
+        self.allowed_routes
+            .validate_at(&path.field("allowedRoutes"), errors);
+    }
+}
+
+impl Validate for GatewayTlsConfig {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(TlsModeType::Other(mode)) = &self.mode {
+            one_of(
+                &path.field("mode"),
+                mode,
+                &["Terminate", "Passthrough"],
+                errors,
+            );
+        }
+        super::opt_items(
+            &path.field("certificateRefs"),
+            &self.certificate_refs,
+            64,
+            errors,
+        );
+        if let Some(options) = &self.options {
+            tls_options(&path.field("options"), options, errors);
+        }
+        self.frontend_validation
+            .validate_at(&path.field("frontendValidation"), errors);
+
+        let certs = self.certificate_refs.as_ref().map_or(0, Vec::len);
+        let options = self.options.as_ref().map_or(0, |o| o.len());
+        let terminate = matches!(self.mode, None | Some(TlsModeType::Terminate));
+        if terminate && certs == 0 && options == 0 {
+            errors.push(FieldError::new(
+                path,
+                "certificateRefs or options must be specified when mode is Terminate",
+            ));
+        }
+    }
+}
+
+impl Validate for FrontendTlsValidation {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(refs) = &self.ca_certificate_refs {
+            super::items(&path.field("caCertificateRefs"), refs, 1, 8, errors);
+        }
+    }
+}
+
+impl Validate for AllowedRoutes {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.namespaces
+            .validate_at(&path.field("namespaces"), errors);
+        super::opt_items(&path.field("kinds"), &self.kinds, 8, errors);
+    }
+}
+
+impl Validate for RouteNamespaces {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(from) = &self.from {
+            one_of(
+                &path.field("from"),
+                from.as_str(),
+                &["All", "Selector", "Same"],
+                errors,
+            );
+        }
+    }
+}
+
+impl Validate for RouteGroupKind {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(group) = &self.group {
+            fields::group(&path.field("group"), group, errors);
+        }
+        fields::kind(&path.field("kind"), &self.kind, errors);
+    }
+}
+
+impl Validate for GatewayAddress {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(ty) = &self.r#type {
+            format(
+                &path.field("type"),
+                ty.as_str(),
+                (1, 253),
+                (formats::is_address_type, crate::schema::ADDRESS_TYPE),
+                errors,
+            );
+        }
+        let value = path.field("value");
+        if super::length(&value, &self.value, 1, 253, errors)
+            && self.r#type == Some(AddressType::Hostname)
+            && !formats::is_hostname(&self.value)
+        {
+            errors.push(FieldError::new(
+                &value,
+                r"Hostname value must only contain valid characters (matching ^(\*\.)?[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$)",
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn listener(name: &str, protocol: &str, port: u16) -> Listener {
+        Listener {
+            name: name.to_string(),
+            hostname: None,
+            port,
+            protocol: protocol.into(),
+            tls: None,
+            allowed_routes: None,
+        }
+    }
+
+    #[test]
+    fn listener_rules() {
+        let mut https = listener("http", "HTTPS", 443);
+        https.tls = Some(GatewayTlsConfig::default());
+        let mut tcp = listener("tcp", "TCP", 0);
+        tcp.hostname = Some("example.com".parse().unwrap());
+        let spec = GatewaySpec {
+            gateway_class_name: "acme".to_string(),
+            listeners: vec![listener("http", "HTTP", 80), https, tcp],
+            addresses: None,
+            infrastructure: None,
+            backend_tls: None,
+            allowed_listeners: None,
+        };
+
+        let errors = spec.validate().expect_err("spec must be invalid");
+        let errors = errors
+            .errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "listeners[1].tls: certificateRefs or options must be specified when mode is Terminate",
+                "listeners[2].port: must be between 1 and 65535",
+                "listeners: hostname must not be specified for protocols ['TCP', 'UDP']",
+                "listeners: Listener name must be unique within the Gateway",
+            ]
+        );
+    }
+
+    #[test]
+    fn infrastructure() {
+        let gateway = Gateway::builder("gw", "acme")
+            .listener("http", ProtocolType::Http, 80, |l| l)
+            .infrastructure_label("app.kubernetes.io/name", "gw")
+            .infrastructure_label("tier", "-edge")
+            .infrastructure_label("bad~key", "x")
+            .infrastructure_annotation("example.com/owner", "team-a");
+        let errors = gateway
+            .build()
+            .expect_err("gateway must be invalid")
+            .errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "spec.infrastructure.labels: Label keys must be in the form of an optional DNS subdomain prefix followed by a required name segment of up to 63 characters.",
+                "spec.infrastructure.labels[tier]: must match ^(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9])?$",
+            ]
+        );
+
+        let gateway = Gateway::builder("gw", "acme")
+            .listener("http", ProtocolType::Http, 80, |l| l)
+            .infrastructure_label("app.kubernetes.io/name", "gw")
+            .infrastructure_annotation("example.com/owner", "team-a")
+            .build()
+            .expect("gateway must be valid");
+        let infrastructure = gateway.spec.infrastructure.as_ref().unwrap();
+        assert_eq!(
+            infrastructure.labels.as_ref().unwrap()["app.kubernetes.io/name"],
+            "gw"
+        );
+        assert_eq!(
+            serde_json::to_value(&gateway.spec).unwrap()["infrastructure"],
+            serde_json::json!({
+                "labels": { "app.kubernetes.io/name": "gw" },
+                "annotations": { "example.com/owner": "team-a" },
+            })
+        );
+
+        let mut spec = gateway.spec;
+        spec.allowed_listeners = Some(AllowedListeners {
+            namespaces: Some(ListenerNamespaces {
+                from: Some(FromNamespaces::None),
+                selector: None,
+            }),
+        });
+        spec.listeners[0].allowed_routes = Some(AllowedRoutes {
+            namespaces: Some(RouteNamespaces {
+                from: Some(FromNamespaces::None),
+                selector: None,
+            }),
+            kinds: None,
+        });
+        let errors = spec.validate().expect_err("spec must be invalid");
+        assert_eq!(
+            errors.to_string(),
+            r#"1 validation error(s): listeners[0].allowedRoutes.namespaces.from: unsupported value "None": supported values: "All", "Selector", "Same""#
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use super::{fields, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for GatewayClass {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for GatewayClassSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        gateway_class_spec(
+            path,
+            &self.controller_name,
+            self.parameters_ref.as_ref(),
+            self.description.as_deref(),
+            errors,
+        );
+    }
+}
+
+impl Validate for v1::GatewayClass {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for v1::GatewayClassSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        gateway_class_spec(
+            path,
+            &self.controller_name,
+            self.parameters_ref.as_ref(),
+            self.description.as_deref(),
+            errors,
+        );
+    }
+}
+
+/// Validates the fields that the v1 and v1beta1 GatewayClass specs have in
+/// common.
+fn gateway_class_spec(
+    path: &FieldPath,
+    controller_name: &str,
+    parameters_ref: Option<&ParametersReference>,
+    description: Option<&str>,
+    errors: &mut Vec<FieldError>,
+) {
+    fields::controller_name(&path.field("controllerName"), controller_name, errors);
+    if let Some(parameters_ref) = parameters_ref {
+        parameters_ref.validate_at(&path.field("parametersRef"), errors);
+    }
+    if let Some(description) = description {
+        super::length(&path.field("description"), description, 0, 64, errors);
+    }
+}
+
+impl Validate for ParametersReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        fields::object_name(&path.field("name"), &self.name, errors);
+        if let Some(ns) = &self.namespace {
+            fields::namespace(&path.field("namespace"), ns, errors);
+        }
+    }
+}
@@ -0,0 +1,784 @@
+use super::{fields, formats, one_of, port, unique_keys, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for HttpRoute {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for HttpRouteSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        http_route_spec(
+            path,
+            &self.inner,
+            self.hostnames.as_deref(),
+            &self.rules,
+            errors,
+        );
+    }
+}
+
+impl Validate for v1::HttpRoute {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.spec.validate_at(&path.field("spec"), errors);
+    }
+}
+
+impl Validate for v1::HttpRouteSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        http_route_spec(
+            path,
+            &self.inner,
+            self.hostnames.as_deref(),
+            &self.rules,
+            errors,
+        );
+    }
+}
+
+/// Validates the fields that the v1 and v1beta1 HTTPRoute specs have in
+/// common.
+fn http_route_spec(
+    path: &FieldPath,
+    inner: &CommonRouteSpec,
+    hostnames: Option<&[Hostname]>,
+    rules: &Option<Vec<HttpRouteRule>>,
+    errors: &mut Vec<FieldError>,
+) {
+    inner.validate_at(path, errors);
+    if let Some(hostnames) = hostnames {
+        let path = path.field("hostnames");
+        super::item_count(&path, hostnames.len(), 0, 16, errors);
+        for (i, hostname) in hostnames.iter().enumerate() {
+            fields::hostname(&path.index(i), hostname, errors);
+        }
+    }
+    super::opt_items(&path.field("rules"), rules, 16, errors);
+    if let Some(rules) = rules {
+        let mut seen = std::collections::HashSet::new();
+        let duplicate = rules
+            .iter()
+            .filter_map(|r| r.name.as_deref())
+            .any(|name| !seen.insert(name));
+        if duplicate {
+            errors.push(FieldError::new(
+                &path.field("rules"),
+                "Rule name must be unique within the route",
+            ));
+        }
+    }
+}
+
+impl Validate for HttpRouteRule {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(name) = &self.name {
+            fields::section_name(&path.field("name"), name, errors);
+        }
+        super::opt_items(&path.field("matches"), &self.matches, 8, errors);
+        if let Some(filters) = &self.filters {
+            http_filters(&path.field("filters"), filters, errors);
+        }
+        super::opt_items(&path.field("backendRefs"), &self.backend_refs, 16, errors);
+        self.timeouts.validate_at(&path.field("timeouts"), errors);
+        self.retry.validate_at(&path.field("retry"), errors);
+        self.session_persistence
+            .validate_at(&path.field("sessionPersistence"), errors);
+
+        let has_backends = self.backend_refs.as_ref().map_or(false, |b| !b.is_empty());
+        let filters = self.filters.as_deref().unwrap_or_default();
+        if has_backends && filters.iter().any(is_redirect) {
+            errors.push(FieldError::new(
+                path,
+                "RequestRedirect filter must not be used together with backendRefs",
+            ));
+        }
+
+        // Replacing a prefix match requires that the rule have exactly one
+        // PathPrefix match.
+        let single_prefix = match self.matches.as_deref() {
+            // Rules without matches default to a single PathPrefix match on `/`.
+            None => true,
+            Some([m]) => matches!(m.path, None | Some(HttpPathMatch::PathPrefix { .. })),
+            Some(_) => false,
+        };
+        if single_prefix {
+            return;
+        }
+        if filters.iter().any(is_redirect_prefix_replacement) {
+            errors.push(FieldError::new(
+                path,
+                "When using RequestRedirect filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified",
+            ));
+        }
+        if filters.iter().any(is_rewrite_prefix_replacement) {
+            errors.push(FieldError::new(
+                path,
+                "When using URLRewrite filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified",
+            ));
+        }
+        let backend_filters = self
+            .backend_refs
+            .iter()
+            .flatten()
+            .flat_map(|b| b.filters.iter().flatten());
+        for filter in backend_filters {
+            if is_redirect_prefix_replacement(filter) {
+                errors.push(FieldError::new(
+                    path,
+                    "Within backendRefs, when using RequestRedirect filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified",
+                ));
+            }
+            if is_rewrite_prefix_replacement(filter) {
+                errors.push(FieldError::new(
+                    path,
+                    "Within backendRefs, When using URLRewrite filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified",
+                ));
+            }
+        }
+    }
+}
+
+fn is_redirect(filter: &HttpRouteFilter) -> bool {
+    matches!(filter, HttpRouteFilter::RequestRedirect { .. })
+}
+
+fn is_redirect_prefix_replacement(filter: &HttpRouteFilter) -> bool {
+    matches!(
+        filter,
+        HttpRouteFilter::RequestRedirect {
+            request_redirect: HttpRequestRedirectFilter {
+                path: Some(HttpPathModifier::ReplacePrefixMatch { .. }),
+                ..
+            },
+        }
+    )
+}
+
+fn is_rewrite_prefix_replacement(filter: &HttpRouteFilter) -> bool {
+    matches!(
+        filter,
+        HttpRouteFilter::URLRewrite {
+            url_rewrite: HttpUrlRewriteFilter {
+                path: Some(HttpPathModifier::ReplacePrefixMatch { .. }),
+                ..
+            },
+        }
+    )
+}
+
+/// Validates a list of filters, enforcing the CEL rules that upstream applies
+/// to both rule- and backend-level filter lists.
+fn http_filters(path: &FieldPath, filters: &[HttpRouteFilter], errors: &mut Vec<FieldError>) {
+    super::items(path, filters, 0, 16, errors);
+
+    let count = |f: fn(&HttpRouteFilter) -> bool| filters.iter().filter(|x| f(x)).count();
+    let redirects = count(is_redirect);
+    let rewrites = count(|f| matches!(f, HttpRouteFilter::URLRewrite { .. }));
+    if redirects > 0 && rewrites > 0 {
+        errors.push(FieldError::new(
+            path,
+            "May specify either httpRouteFilterRequestRedirect or httpRouteFilterRequestRewrite, but not both",
+        ));
+    }
+    let repeated = [
+        (
+            count(|f| matches!(f, HttpRouteFilter::RequestHeaderModifier { .. })),
+            "RequestHeaderModifier filter cannot be repeated",
+        ),
+        (
+            count(|f| matches!(f, HttpRouteFilter::ResponseHeaderModifier { .. })),
+            "ResponseHeaderModifier filter cannot be repeated",
+        ),
+        (redirects, "RequestRedirect filter cannot be repeated"),
+        (rewrites, "URLRewrite filter cannot be repeated"),
+    ];
+    for (n, message) in repeated {
+        if n > 1 {
+            errors.push(FieldError::new(path, message));
+        }
+    }
+}
+
+impl Validate for HttpRouteTimeouts {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(request) = &self.request {
+            fields::duration(&path.field("request"), request.as_str(), errors);
+        }
+        if let Some(backend_request) = &self.backend_request {
+            fields::duration(
+                &path.field("backendRequest"),
+                backend_request.as_str(),
+                errors,
+            );
+        }
+
+        // A zero request timeout disables the timeout, so it does not bound
+        // the backend request timeout.
+        let (Some(request), Some(backend_request)) = (&self.request, &self.backend_request) else {
+            return;
+        };
+        if let (Ok(request), Ok(backend_request)) = (request.to_std(), backend_request.to_std()) {
+            if !request.is_zero() && backend_request > request {
+                errors.push(FieldError::new(
+                    path,
+                    "backendRequest timeout cannot be longer than request timeout",
+                ));
+            }
+        }
+    }
+}
+
+impl Validate for HttpRouteRetry {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        for (i, code) in self.codes.iter().flatten().enumerate() {
+            if !(400..=599).contains(code) {
+                errors.push(FieldError::new(
+                    &path.field("codes").index(i),
+                    "must be between 400 and 599",
+                ));
+            }
+        }
+        if let Some(backoff) = &self.backoff {
+            fields::duration(&path.field("backoff"), backoff.as_str(), errors);
+        }
+    }
+}
+
+impl Validate for HttpRouteMatch {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.path.validate_at(&path.field("path"), errors);
+        if let Some(headers) = &self.headers {
+            let path = path.field("headers");
+            super::items(&path, headers, 0, 16, errors);
+            unique_keys(&path, headers.iter().map(header_match_name), errors);
+        }
+        if let Some(params) = &self.query_params {
+            let path = path.field("queryParams");
+            super::items(&path, params, 0, 16, errors);
+            unique_keys(&path, params.iter().map(query_param_match_name), errors);
+        }
+        if let Some(method) = &self.method {
+            one_of(
+                &path.field("method"),
+                method,
+                &[
+                    "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH",
+                ],
+                errors,
+            );
+        }
+    }
+}
+
+fn header_match_name(m: &HttpHeaderMatch) -> &str {
+    match m {
+        HttpHeaderMatch::Exact { name, .. } | HttpHeaderMatch::RegularExpression { name, .. } => {
+            name
+        }
+        HttpHeaderMatch::Unknown { raw, .. } => unknown_name(raw),
+    }
+}
+
+fn query_param_match_name(m: &HttpQueryParamMatch) -> &str {
+    match m {
+        HttpQueryParamMatch::Exact { name, .. }
+        | HttpQueryParamMatch::RegularExpression { name, .. } => name,
+        HttpQueryParamMatch::Unknown { raw, .. } => unknown_name(raw),
+    }
+}
+
+/// Returns the `name` of a match whose type is not supported, so that it is
+/// still checked for uniqueness.
+fn unknown_name(raw: &serde_json::Value) -> &str {
+    raw.get("name")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default()
+}
+
+impl Validate for HttpPathMatch {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        let value = match self {
+            Self::Exact { value } | Self::PathPrefix { value } => value,
+            Self::Unknown { type_, .. } => {
+                super::one_of(&path.field("type"), type_, Self::KNOWN_TYPES, errors);
+                return;
+            }
+            Self::RegularExpression { value } => {
+                super::length(&path.field("value"), value, 0, 1024, errors);
+                return;
+            }
+        };
+        let path = path.field("value");
+        if !super::length(&path, value, 0, 1024, errors) {
+            return;
+        }
+
+        if !value.starts_with('/') {
+            errors.push(FieldError::new(
+                &path,
+                "value must be an absolute path and start with '/' when type one of ['Exact', 'PathPrefix']",
+            ));
+        }
+        for seq in ["//", "/./", "/../", "%2f", "%2F", "#"] {
+            if value.contains(seq) {
+                errors.push(FieldError::new(
+                    &path,
+                    format!("must not contain '{seq}' when type one of ['Exact', 'PathPrefix']"),
+                ));
+            }
+        }
+        for suffix in ["/..", "/."] {
+            if value.ends_with(suffix) {
+                errors.push(FieldError::new(
+                    &path,
+                    format!(
+                        "must not end with '{suffix}' when type one of ['Exact', 'PathPrefix']"
+                    ),
+                ));
+            }
+        }
+        if !formats::is_path(value) {
+            errors.push(FieldError::new(
+                &path,
+                "must only contain valid characters (matching ^(?:[-A-Za-z0-9/._~!$&'()*+,;=:@]|[%][0-9a-fA-F]{2})+$) for types ['Exact', 'PathPrefix']",
+            ));
+        }
+    }
+}
+
+impl Validate for HttpHeaderMatch {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        let (name, value) = match self {
+            Self::Exact { name, value } | Self::RegularExpression { name, value } => (name, value),
+            Self::Unknown { type_, .. } => {
+                return super::one_of(&path.field("type"), type_, Self::KNOWN_TYPES, errors);
+            }
+        };
+        fields::header_name(&path.field("name"), name, errors);
+        super::length(&path.field("value"), value, 1, 4096, errors);
+    }
+}
+
+impl Validate for HttpQueryParamMatch {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        let (name, value) = match self {
+            Self::Exact { name, value } | Self::RegularExpression { name, value } => (name, value),
+            Self::Unknown { type_, .. } => {
+                return super::one_of(&path.field("type"), type_, Self::KNOWN_TYPES, errors);
+            }
+        };
+        fields::header_name(&path.field("name"), name, errors);
+        super::length(&path.field("value"), value, 1, 1024, errors);
+    }
+}
+
+impl Validate for HttpRouteFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        match self {
+            Self::RequestHeaderModifier {
+                request_header_modifier,
+            } => request_header_modifier.validate_at(&path.field("requestHeaderModifier"), errors),
+            Self::ResponseHeaderModifier {
+                response_header_modifier,
+            } => {
+                response_header_modifier.validate_at(&path.field("responseHeaderModifier"), errors)
+            }
+            Self::RequestMirror { request_mirror } => {
+                request_mirror.validate_at(&path.field("requestMirror"), errors)
+            }
+            Self::RequestRedirect { request_redirect } => {
+                request_redirect.validate_at(&path.field("requestRedirect"), errors)
+            }
+            Self::URLRewrite { url_rewrite } => {
+                url_rewrite.validate_at(&path.field("urlRewrite"), errors)
+            }
+            Self::ExtensionRef { extension_ref } => {
+                extension_ref.validate_at(&path.field("extensionRef"), errors)
+            }
+            Self::CORS { cors } => cors.validate_at(&path.field("cors"), errors),
+            Self::ExternalAuth { external_auth } => {
+                external_auth.validate_at(&path.field("externalAuth"), errors)
+            }
+            Self::Unknown { type_, .. } => {
+                one_of(&path.field("type"), type_, Self::KNOWN_TYPES, errors)
+            }
+        }
+    }
+}
+
+impl Validate for HttpRequestHeaderFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        for (field, headers) in [("set", &self.set), ("add", &self.add)] {
+            if let Some(headers) = headers {
+                let path = path.field(field);
+                super::items(&path, headers, 0, 16, errors);
+                unique_keys(&path, headers.iter().map(|h| h.name.as_str()), errors);
+            }
+        }
+        if let Some(remove) = &self.remove {
+            let path = path.field("remove");
+            super::item_count(&path, remove.len(), 0, 16, errors);
+            unique_keys(&path, remove.iter().map(String::as_str), errors);
+        }
+    }
+}
+
+impl Validate for HttpHeader {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::header_name(&path.field("name"), &self.name, errors);
+        super::length(&path.field("value"), &self.value, 1, 4096, errors);
+    }
+}
+
+impl Validate for HttpPathModifier {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        match self {
+            Self::ReplaceFullPath { replace_full_path } => super::length(
+                &path.field("replaceFullPath"),
+                replace_full_path,
+                0,
+                1024,
+                errors,
+            ),
+            Self::ReplacePrefixMatch {
+                replace_prefix_match,
+            } => super::length(
+                &path.field("replacePrefixMatch"),
+                replace_prefix_match,
+                0,
+                1024,
+                errors,
+            ),
+            Self::Unknown { type_, .. } => {
+                super::one_of(&path.field("type"), type_, Self::KNOWN_TYPES, errors);
+                true
+            }
+        };
+    }
+}
+
+impl Validate for HttpRequestRedirectFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(scheme) = &self.scheme {
+            one_of(&path.field("scheme"), scheme, &["http", "https"], errors);
+        }
+        if let Some(hostname) = &self.hostname {
+            fields::precise_hostname(&path.field("hostname"), hostname, errors);
+        }
+        self.path.validate_at(&path.field("path"), errors);
+        if let Some(p) = self.port {
+            port(&path.field("port"), p, errors);
+        }
+        if let Some(code) = self.status_code {
+            one_of(
+                &path.field("statusCode"),
+                &code.to_string(),
+                &["301", "302"],
+                errors,
+            );
+        }
+    }
+}
+
+impl Validate for HttpUrlRewriteFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(hostname) = &self.hostname {
+            fields::precise_hostname(&path.field("hostname"), hostname, errors);
+        }
+        self.path.validate_at(&path.field("path"), errors);
+    }
+}
+
+impl Validate for HttpRequestMirrorFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.backend_ref
+            .validate_at(&path.field("backendRef"), errors);
+        if let Some(percent) = self.percent {
+            if !(0..=100).contains(&percent) {
+                errors.push(FieldError::new(
+                    &path.field("percent"),
+                    "must be between 0 and 100",
+                ));
+            }
+        }
+        self.fraction.validate_at(&path.field("fraction"), errors);
+        if self.percent.is_some() && self.fraction.is_some() {
+            errors.push(FieldError::new(
+                path,
+                "Only one of percent or fraction may be specified in HTTPRequestMirrorFilter",
+            ));
+        }
+    }
+}
+
+impl Validate for HttpCorsFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(origins) = &self.allow_origins {
+            let path = path.field("allowOrigins");
+            super::item_count(&path, origins.len(), 0, 64, errors);
+            for (i, origin) in origins.iter().enumerate() {
+                super::format(
+                    &path.index(i),
+                    origin,
+                    (1, 253),
+                    (formats::is_cors_origin, crate::schema::CORS_ORIGIN),
+                    errors,
+                );
+            }
+        }
+        if let Some(methods) = &self.allow_methods {
+            let path = path.field("allowMethods");
+            super::item_count(&path, methods.len(), 0, 9, errors);
+            for (i, method) in methods.iter().enumerate() {
+                one_of(
+                    &path.index(i),
+                    method,
+                    &[
+                        "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE",
+                        "PATCH", "*",
+                    ],
+                    errors,
+                );
+            }
+            if methods.len() > 1 && methods.iter().any(|m| m == "*") {
+                errors.push(FieldError::new(
+                    &path,
+                    "AllowMethods cannot contain '*' alongside other methods",
+                ));
+            }
+        }
+        for (field, headers) in [
+            ("allowHeaders", &self.allow_headers),
+            ("exposeHeaders", &self.expose_headers),
+        ] {
+            if let Some(headers) = headers {
+                let path = path.field(field);
+                super::item_count(&path, headers.len(), 0, 64, errors);
+                for (i, header) in headers.iter().enumerate() {
+                    fields::header_name(&path.index(i), header, errors);
+                }
+            }
+        }
+        if let Some(headers) = &self.allow_headers {
+            if headers.len() > 1 && headers.iter().any(|h| h == "*") {
+                errors.push(FieldError::new(
+                    &path.field("allowHeaders"),
+                    "AllowHeaders cannot contain '*' alongside other methods",
+                ));
+            }
+        }
+        if let Some(max_age) = self.max_age {
+            if max_age < 1 {
+                errors.push(FieldError::new(
+                    &path.field("maxAge"),
+                    "must be greater than or equal to 1",
+                ));
+            }
+        }
+    }
+}
+
+impl Validate for HttpExternalAuthFilter {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        one_of(
+            &path.field("protocol"),
+            self.protocol.as_str(),
+            &["HTTP", "GRPC"],
+            errors,
+        );
+        self.backend_ref
+            .validate_at(&path.field("backendRef"), errors);
+        if let Some(grpc) = &self.grpc_auth_config {
+            let path = path.field("grpc").field("allowedHeaders");
+            let headers = grpc.allowed_request_headers.as_deref().unwrap_or_default();
+            super::item_count(&path, headers.len(), 0, 64, errors);
+        }
+        if let Some(http) = &self.http_auth_config {
+            let path = path.field("http");
+            if let Some(prefix) = &http.path {
+                super::format(
+                    &path.field("path"),
+                    prefix,
+                    (0, 1024),
+                    (formats::is_path, crate::schema::PATH),
+                    errors,
+                );
+            }
+            for (field, headers) in [
+                ("allowedHeaders", &http.allowed_request_headers),
+                ("allowedResponseHeaders", &http.allowed_response_headers),
+            ] {
+                let headers = headers.as_deref().unwrap_or_default();
+                super::item_count(&path.field(field), headers.len(), 0, 64, errors);
+            }
+        }
+
+        let rules = [
+            (
+                HttpRouteExternalAuthProtocol::Grpc,
+                self.grpc_auth_config.is_some(),
+                "grpc",
+            ),
+            (
+                HttpRouteExternalAuthProtocol::Http,
+                self.http_auth_config.is_some(),
+                "http",
+            ),
+        ];
+        for (protocol, configured, field) in rules {
+            if self.protocol == protocol && !configured {
+                errors.push(FieldError::new(
+                    path,
+                    format!("{field} must be specified when protocol is set to '{protocol}'"),
+                ));
+            }
+            if configured && self.protocol != protocol {
+                errors.push(FieldError::new(
+                    path,
+                    format!("protocol must be '{protocol}' when {field} is set"),
+                ));
+            }
+        }
+    }
+}
+
+impl Validate for HttpBackendRef {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        match &self.backend_ref {
+            Some(backend_ref) => backend_ref.validate_at(path, errors),
+            None => errors.push(FieldError::new(&path.field("name"), "Required value")),
+        }
+        if let Some(filters) = &self.filters {
+            http_filters(&path.field("filters"), filters, errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn route(rules: serde_json::Value) -> HttpRoute {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "gateway.networking.k8s.io/v1beta1",
+            "kind": "HTTPRoute",
+            "metadata": { "name": "route" },
+            "spec": {
+                "parentRefs": [{ "name": "gw" }],
+                "hostnames": ["example.com"],
+                "rules": rules,
+            },
+        }))
+        .expect("route must deserialize")
+    }
+
+    fn messages(route: &HttpRoute) -> Vec<String> {
+        match route.validate() {
+            Ok(()) => vec![],
+            Err(e) => e.errors().iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn valid_route() {
+        let route = route(serde_json::json!([{
+            "matches": [{
+                "path": { "type": "PathPrefix", "value": "/foo" },
+                "headers": [{ "type": "Exact", "name": "x-env", "value": "canary" }],
+                "method": "GET",
+            }],
+            "filters": [{
+                "type": "URLRewrite",
+                "urlRewrite": { "path": { "type": "ReplacePrefixMatch", "replacePrefixMatch": "/" } },
+            }],
+            "backendRefs": [{ "name": "svc", "port": 8080, "weight": 1000 }],
+        }]));
+        assert_eq!(messages(&route), Vec::<String>::new());
+    }
+
+    #[test]
+    fn reports_every_violation_with_path() {
+        let mut route = route(serde_json::json!([{
+            "matches": [
+                { "path": { "type": "Exact", "value": "foo//bar" } },
+                { "method": "FETCH" },
+            ],
+            "filters": [{
+                "type": "RequestRedirect",
+                "requestRedirect": {
+                    "statusCode": 307,
+                    "path": { "type": "ReplacePrefixMatch", "replacePrefixMatch": "/" },
+                },
+            }],
+            "backendRefs": [{ "name": "svc", "weight": 2_000_000 }],
+        }]));
+        route.spec.hostnames = Some(vec![serde_json::from_str(r#""*.Example.com""#).unwrap()]);
+
+        assert_eq!(
+            messages(&route),
+            [
+                r"spec.hostnames[0]: must match ^(\*\.)?[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$",
+                "spec.rules[0].matches[0].path.value: value must be an absolute path and start with '/' when type one of ['Exact', 'PathPrefix']",
+                "spec.rules[0].matches[0].path.value: must not contain '//' when type one of ['Exact', 'PathPrefix']",
+                r#"spec.rules[0].matches[1].method: unsupported value "FETCH": supported values: "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH""#,
+                r#"spec.rules[0].filters[0].requestRedirect.statusCode: unsupported value "307": supported values: "301", "302""#,
+                "spec.rules[0].backendRefs[0]: Must have port for Service reference",
+                "spec.rules[0].backendRefs[0].weight: must be between 0 and 1000000",
+                "spec.rules[0]: RequestRedirect filter must not be used together with backendRefs",
+                "spec.rules[0]: When using RequestRedirect filter with path.replacePrefixMatch, exactly one PathPrefix match must be specified",
+            ]
+        );
+    }
+
+    #[test]
+    fn timeouts_retry_and_session_persistence() {
+        let route = route(serde_json::json!([
+            {
+                "name": "Rule",
+                "timeouts": { "request": "10s", "backendRequest": "1m" },
+                "retry": { "codes": [500, 302], "backoff": "1.5s" },
+            },
+            {
+                "name": "Rule",
+                "timeouts": { "request": "0s", "backendRequest": "1m" },
+                "sessionPersistence": {
+                    "sessionName": "session",
+                    "cookieConfig": { "lifetimeType": "Permanent" },
+                },
+            },
+        ]));
+        assert_eq!(
+            messages(&route),
+            [
+                r"spec.rules[0].name: must match ^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$",
+                "spec.rules[0].timeouts: backendRequest timeout cannot be longer than request timeout",
+                "spec.rules[0].retry.codes[1]: must be between 400 and 599",
+                r"spec.rules[0].retry.backoff: must match ^([0-9]{1,5}(h|m|s|ms)){1,4}$",
+                r"spec.rules[1].name: must match ^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$",
+                "spec.rules[1].sessionPersistence: AbsoluteTimeout must be specified when cookie lifetimeType is Permanent",
+                "spec.rules: Rule name must be unique within the route",
+            ]
+        );
+    }
+
+    #[test]
+    fn repeated_filters() {
+        let route = route(serde_json::json!([{
+            "filters": [
+                { "type": "RequestHeaderModifier", "requestHeaderModifier": { "remove": ["a", "a"] } },
+                { "type": "RequestHeaderModifier", "requestHeaderModifier": {} },
+                { "type": "URLRewrite", "urlRewrite": {} },
+                { "type": "RequestRedirect", "requestRedirect": {} },
+            ],
+        }]));
+        assert_eq!(
+            messages(&route),
+            [
+                r#"spec.rules[0].filters[0].requestHeaderModifier.remove[1]: duplicate value "a""#,
+                "spec.rules[0].filters: May specify either httpRouteFilterRequestRedirect or httpRouteFilterRequestRewrite, but not both",
+                "spec.rules[0].filters: RequestHeaderModifier filter cannot be repeated",
+            ]
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use super::{fields, port, FieldError, FieldPath, Validate};
+use crate::*;
+
+impl Validate for LocalObjectReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        fields::object_name(&path.field("name"), &self.name, errors);
+    }
+}
+
+impl Validate for ObjectReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        fields::group(&path.field("group"), &self.group, errors);
+        fields::kind(&path.field("kind"), &self.kind, errors);
+        fields::object_name(&path.field("name"), &self.name, errors);
+        if let Some(ns) = &self.namespace {
+            fields::namespace(&path.field("namespace"), ns, errors);
+        }
+    }
+}
+
+impl Validate for SecretObjectReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(group) = &self.group {
+            fields::group(&path.field("group"), group, errors);
+        }
+        if let Some(kind) = &self.kind {
+            fields::kind(&path.field("kind"), kind, errors);
+        }
+        fields::object_name(&path.field("name"), &self.name, errors);
+        if let Some(ns) = &self.namespace {
+            fields::namespace(&path.field("namespace"), ns, errors);
+        }
+    }
+}
+
+impl Validate for BackendObjectReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(group) = &self.group {
+            fields::group(&path.field("group"), group, errors);
+        }
+        if let Some(kind) = &self.kind {
+            fields::kind(&path.field("kind"), kind, errors);
+        }
+        fields::object_name(&path.field("name"), &self.name, errors);
+        if let Some(ns) = &self.namespace {
+            fields::namespace(&path.field("namespace"), ns, errors);
+        }
+        match self.port {
+            Some(p) => port(&path.field("port"), p, errors),
+            None => {
+                let group = self.group.as_deref().unwrap_or("");
+                let kind = self.kind.as_deref().unwrap_or("Service");
+                if group.is_empty() && kind == "Service" {
+                    errors.push(FieldError::new(
+                        path,
+                        "Must have port for Service reference",
+                    ));
+                }
+            }
+        }
+    }
+}
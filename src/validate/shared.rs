@@ -0,0 +1,138 @@
+use super::{fields, port, FieldError, FieldPath, Validate};
+use crate::*;
+
+/// BackendRef weights are bounded by the upstream `maximum` marker.
+const MAX_WEIGHT: u32 = 1_000_000;
+
+impl Validate for ParentReference {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(group) = &self.group {
+            fields::group(&path.field("group"), group, errors);
+        }
+        if let Some(kind) = &self.kind {
+            fields::kind(&path.field("kind"), kind, errors);
+        }
+        if let Some(ns) = &self.namespace {
+            fields::namespace(&path.field("namespace"), ns, errors);
+        }
+        fields::object_name(&path.field("name"), &self.name, errors);
+        if let Some(section_name) = &self.section_name {
+            fields::section_name(&path.field("sectionName"), section_name, errors);
+        }
+        if let Some(p) = self.port {
+            port(&path.field("port"), p, errors);
+        }
+    }
+}
+
+impl Validate for CommonRouteSpec {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        let Some(parent_refs) = &self.parent_refs else {
+            return;
+        };
+        let path = path.field("parentRefs");
+        super::items(&path, parent_refs, 0, 32, errors);
+
+        // Multiple references to the same parent must each select a distinct
+        // section of that parent.
+        let section = |p: &ParentReference| p.section_name.clone().filter(|s| !s.is_empty());
+        let mut specified = true;
+        let mut unique = true;
+        for (i, a) in parent_refs.iter().enumerate() {
+            for b in &parent_refs[i + 1..] {
+                if !same_parent(a, b) {
+                    continue;
+                }
+                match (section(a), section(b)) {
+                    (Some(a), Some(b)) => unique &= a != b,
+                    (None, None) => unique = false,
+                    _ => specified = false,
+                }
+            }
+        }
+        if !specified {
+            errors.push(FieldError::new(
+                &path,
+                "sectionName must be specified when parentRefs includes 2 or more references to the same parent",
+            ));
+        }
+        if !unique {
+            errors.push(FieldError::new(
+                &path,
+                "sectionName must be unique when parentRefs includes 2 or more references to the same parent",
+            ));
+        }
+    }
+}
+
+fn same_parent(a: &ParentReference, b: &ParentReference) -> bool {
+    let ns = |p: &ParentReference| p.namespace.clone().unwrap_or_default();
+    a.group == b.group && a.kind == b.kind && a.name == b.name && ns(a) == ns(b)
+}
+
+impl Validate for BackendRef {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        self.inner.validate_at(path, errors);
+        if let Some(weight) = self.weight {
+            weight_at(&path.field("weight"), weight, errors);
+        }
+    }
+}
+
+pub(super) fn weight_at(path: &FieldPath, weight: u32, errors: &mut Vec<FieldError>) {
+    if weight > MAX_WEIGHT {
+        errors.push(FieldError::new(
+            path,
+            format!("must be between 0 and {MAX_WEIGHT}"),
+        ));
+    }
+}
+
+impl Validate for SessionPersistence {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if let Some(name) = &self.session_name {
+            super::length(&path.field("sessionName"), name, 0, 128, errors);
+        }
+        if let Some(timeout) = &self.absolute_timeout {
+            fields::duration(&path.field("absoluteTimeout"), timeout.as_str(), errors);
+        }
+        if let Some(timeout) = &self.idle_timeout {
+            fields::duration(&path.field("idleTimeout"), timeout.as_str(), errors);
+        }
+
+        let lifetime = self
+            .cookie_config
+            .as_ref()
+            .and_then(|c| c.lifetime_type.as_ref());
+        if lifetime == Some(&CookieLifetimeType::Permanent) && self.absolute_timeout.is_none() {
+            errors.push(FieldError::new(
+                path,
+                "AbsoluteTimeout must be specified when cookie lifetimeType is Permanent",
+            ));
+        }
+    }
+}
+
+impl Validate for Fraction {
+    fn validate_at(&self, path: &FieldPath, errors: &mut Vec<FieldError>) {
+        if self.numerator < 0 {
+            errors.push(FieldError::new(
+                &path.field("numerator"),
+                "must be greater than or equal to 0",
+            ));
+        }
+        let denominator = self.denominator.unwrap_or(100);
+        if denominator < 1 {
+            errors.push(FieldError::new(
+                &path.field("denominator"),
+                "must be greater than or equal to 1",
+            ));
+        }
+        if self.numerator > denominator {
+            errors.push(FieldError::new(
+                path,
+                "numerator must be less than or equal to denominator",
+            ));
+        }
+    }
+}
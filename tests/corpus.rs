@@ -0,0 +1,235 @@
+//! Round-trips a corpus of upstream example and conformance manifests through
+//! the typed resources, so that schema drift (e.g. a field that these bindings
+//! do not model) is caught: every Gateway API document must be read and then
+//! written back as the same manifest, as both JSON and YAML, other than the
+//! differences listed in `EXPECTED_DIFFERENCES`.
+//!
+//! The corpus lives in `tests/corpus` and is vendored by its `vendor.sh`.
+//! Documents that are not resources the crate serves (e.g. the Namespaces and
+//! Services in conformance manifests) are skipped, as are experimental
+//! resources when the `experimental` feature is disabled. Every resource
+//! version that the crate serves must appear in the corpus.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+const GROUP: &str = "gateway.networking.k8s.io/";
+
+const EXPERIMENTAL_GROUP: &str = "gateway.networking.x-k8s.io/";
+
+/// The `(version, kind)` of every resource that the crate serves.
+const SERVED: &[(&str, &str)] = &[
+    ("v1beta1", "GatewayClass"),
+    ("v1beta1", "Gateway"),
+    ("v1beta1", "HTTPRoute"),
+    ("v1", "GatewayClass"),
+    ("v1", "Gateway"),
+    ("v1", "HTTPRoute"),
+    #[cfg(feature = "experimental")]
+    ("v1beta1", "ReferenceGrant"),
+    #[cfg(feature = "experimental")]
+    ("v1alpha2", "GRPCRoute"),
+    #[cfg(feature = "experimental")]
+    ("v1alpha2", "TCPRoute"),
+    #[cfg(feature = "experimental")]
+    ("v1alpha2", "TLSRoute"),
+    #[cfg(feature = "experimental")]
+    ("v1alpha2", "UDPRoute"),
+    #[cfg(feature = "experimental")]
+    ("v1alpha3", "BackendTLSPolicy"),
+    #[cfg(feature = "experimental")]
+    ("v1alpha1", "XListenerSet"),
+];
+
+#[test]
+fn round_trips_corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut paths = Vec::new();
+    find_manifests(&dir, &mut paths);
+    paths.sort();
+
+    let mut checked = 0;
+    let mut covered = BTreeSet::new();
+    let mut failures = Vec::new();
+    for path in &paths {
+        let yaml = std::fs::read_to_string(path).expect("manifest must be readable");
+        for (i, doc) in serde_yaml::Deserializer::from_str(&yaml).enumerate() {
+            let name = format!("{}[{i}]", path.strip_prefix(&dir).unwrap().display());
+            let manifest = match serde_json::Value::deserialize(doc) {
+                Ok(manifest) => manifest,
+                Err(error) => {
+                    failures.push(format!("{name}: invalid YAML: {error}"));
+                    continue;
+                }
+            };
+            match round_trip_manifest(&manifest) {
+                Ok(Some(resource)) => {
+                    checked += 1;
+                    covered.insert(resource);
+                }
+                Ok(None) => {}
+                Err(error) => failures.push(format!("{name}: {error}")),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} documents failed to round-trip:\n{}",
+        failures.len(),
+        checked + failures.len(),
+        failures.join("\n")
+    );
+    let missing = SERVED
+        .iter()
+        .filter(|resource| !covered.contains(*resource))
+        .map(|(version, kind)| format!("{version} {kind}"))
+        .collect::<Vec<_>>();
+    assert!(
+        missing.is_empty(),
+        "no manifests in {} for {}",
+        dir.display(),
+        missing.join(", ")
+    );
+}
+
+fn find_manifests(dir: &Path, paths: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).expect("corpus must be readable") {
+        let path = entry.expect("corpus must be readable").path();
+        if path.is_dir() {
+            find_manifests(&path, paths);
+        } else if path.extension().map_or(false, |e| e == "yaml") {
+            paths.push(path);
+        }
+    }
+}
+
+/// Round-trips a manifest through the type for its `apiVersion` and `kind`,
+/// returning the `(version, kind)` that was checked, or `None` if the manifest
+/// was skipped because the crate does not serve it.
+fn round_trip_manifest(
+    manifest: &serde_json::Value,
+) -> Result<Option<(&'static str, &'static str)>, String> {
+    use k8s_gateway_api as gw;
+
+    let api_version = manifest["apiVersion"].as_str().unwrap_or_default();
+    let kind = manifest["kind"].as_str().unwrap_or_default();
+    let Some(version) = api_version
+        .strip_prefix(GROUP)
+        .or_else(|| api_version.strip_prefix(EXPERIMENTAL_GROUP))
+    else {
+        return Ok(None);
+    };
+    let resource = SERVED
+        .iter()
+        .find(|(v, k)| *v == version && *k == kind)
+        .copied();
+    match (version, kind) {
+        ("v1beta1", "GatewayClass") => round_trip::<gw::GatewayClass>(manifest),
+        ("v1beta1", "Gateway") => round_trip::<gw::Gateway>(manifest),
+        ("v1beta1", "HTTPRoute") => round_trip::<gw::HttpRoute>(manifest),
+        ("v1", "GatewayClass") => round_trip::<gw::v1::GatewayClass>(manifest),
+        ("v1", "Gateway") => round_trip::<gw::v1::Gateway>(manifest),
+        ("v1", "HTTPRoute") => round_trip::<gw::v1::HttpRoute>(manifest),
+        #[cfg(feature = "experimental")]
+        ("v1beta1", "ReferenceGrant") => round_trip::<gw::ReferenceGrant>(manifest),
+        #[cfg(feature = "experimental")]
+        ("v1alpha2", "GRPCRoute") => round_trip::<gw::GrpcRoute>(manifest),
+        #[cfg(feature = "experimental")]
+        ("v1alpha2", "TCPRoute") => round_trip::<gw::TcpRoute>(manifest),
+        #[cfg(feature = "experimental")]
+        ("v1alpha2", "TLSRoute") => round_trip::<gw::TlsRoute>(manifest),
+        #[cfg(feature = "experimental")]
+        ("v1alpha2", "UDPRoute") => round_trip::<gw::UdpRoute>(manifest),
+        #[cfg(feature = "experimental")]
+        ("v1alpha3", "BackendTLSPolicy") => round_trip::<gw::BackendTlsPolicy>(manifest),
+        #[cfg(feature = "experimental")]
+        ("v1alpha1", "XListenerSet") => round_trip::<gw::ListenerSet>(manifest),
+        _ => return Ok(None),
+    }?;
+    Ok(resource)
+}
+
+/// Differences that are expected when a manifest is written back: a match
+/// without a `type` is read as the type that the CRD defaults it to, as the API
+/// server would, and that type is then written. Each entry names the field of a
+/// route match and its default type.
+const EXPECTED_DIFFERENCES: &[(&str, &str)] = &[
+    ("path", "PathPrefix"),
+    ("headers", "Exact"),
+    ("queryParams", "Exact"),
+    ("method", "Exact"),
+];
+
+/// Reads a manifest as a `T` and checks that writing it, as both JSON and
+/// YAML, produces the same manifest.
+fn round_trip<T: DeserializeOwned + Serialize>(manifest: &serde_json::Value) -> Result<(), String> {
+    let resource = serde_json::from_value::<T>(manifest.clone())
+        .map_err(|error| format!("failed to read: {error}"))?;
+
+    let json = serde_json::to_value(&resource).expect("resource must serialize");
+    let differences = diff(manifest, &json);
+    if !differences.is_empty() {
+        return Err(format!("JSON round trip differs:\n{differences}"));
+    }
+
+    let yaml = serde_yaml::to_string(&resource).expect("resource must serialize");
+    let yaml = serde_yaml::from_str::<serde_json::Value>(&yaml).expect("YAML must parse");
+    let differences = diff(manifest, &yaml);
+    if !differences.is_empty() {
+        return Err(format!("YAML round trip differs:\n{differences}"));
+    }
+
+    Ok(())
+}
+
+/// Describes the paths at which two values differ, other than those listed in
+/// `EXPECTED_DIFFERENCES`.
+fn diff(expected: &serde_json::Value, actual: &serde_json::Value) -> String {
+    fn walk(path: &str, a: &serde_json::Value, b: &serde_json::Value, out: &mut Vec<String>) {
+        use serde_json::Value;
+        match (a, b) {
+            (Value::Object(a), Value::Object(b)) => {
+                for (k, v) in a {
+                    match b.get(k) {
+                        Some(w) => walk(&format!("{path}.{k}"), v, w, out),
+                        None => out.push(format!("  {path}.{k}: dropped {v}")),
+                    }
+                }
+                for (k, w) in b {
+                    if !a.contains_key(k) && !is_expected(path, k, w) {
+                        out.push(format!("  {path}.{k}: added {w}"));
+                    }
+                }
+            }
+            (Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+                for (i, (v, w)) in a.iter().zip(b).enumerate() {
+                    walk(&format!("{path}[{i}]"), v, w, out);
+                }
+            }
+            (a, b) if a != b => out.push(format!("  {path}: {a} became {b}")),
+            _ => {}
+        }
+    }
+
+    let mut out = Vec::new();
+    walk("", expected, actual, &mut out);
+    out.join("\n")
+}
+
+/// Returns true if adding `key: value` to the object at `path` is listed in
+/// `EXPECTED_DIFFERENCES`.
+fn is_expected(path: &str, key: &str, value: &serde_json::Value) -> bool {
+    let Some((route_match, field)) = path.rsplit_once('.') else {
+        return false;
+    };
+    let field = field.split('[').next().unwrap_or_default();
+    key == "type"
+        && route_match.contains(".matches[")
+        && EXPECTED_DIFFERENCES
+            .iter()
+            .any(|(f, default)| *f == field && value == default)
+}
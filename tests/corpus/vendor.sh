@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+# Vendors the upstream gateway-api manifests into the corpus: for each release
+# below, the complete `examples/` and `conformance/` manifest directories are
+# fetched, unmodified, into `<release>/`, and the commit that the release's tag
+# points to is recorded in README.md.
+
+set -euo pipefail
+
+REPO=kubernetes-sigs/gateway-api
+
+# Each release is kept for the resource versions that it uses:
+#
+# * v0.8.1: v1beta1 GatewayClass, Gateway and HTTPRoute;
+# * v1.0.0: v1alpha2 GRPCRoute, TCPRoute, TLSRoute and UDPRoute;
+# * v1.2.1: v1 GatewayClass, Gateway and HTTPRoute, v1beta1 ReferenceGrant and
+#   v1alpha3 BackendTLSPolicy;
+# * v1.3.0: v1alpha1 XListenerSet.
+RELEASES=(v0.8.1 v1.0.0 v1.2.1 v1.3.0)
+
+DIRS=(examples conformance/base conformance/tests)
+
+cd "$(dirname "$0")"
+
+rows=""
+for release in "${RELEASES[@]}"; do
+    sha=$(git ls-remote "https://github.com/$REPO.git" "refs/tags/$release^{}" | cut -f1)
+    if [ -z "$sha" ]; then
+        sha=$(git ls-remote "https://github.com/$REPO.git" "refs/tags/$release" | cut -f1)
+    fi
+    if [ -z "$sha" ]; then
+        echo "no tag $release in $REPO" >&2
+        exit 1
+    fi
+    echo "$release $sha" >&2
+
+    rm -rf "$release"
+    mkdir "$release"
+    patterns=()
+    for dir in "${DIRS[@]}"; do
+        patterns+=("*/$dir/*.yaml")
+    done
+    curl -fsSL "https://codeload.github.com/$REPO/tar.gz/$sha" |
+        tar -xz -C "$release" --strip-components=1 --wildcards --wildcards-match-slash "${patterns[@]}"
+
+    rows+="| \`$release\` | \`$sha\` |"$'\n'
+done
+
+# Replace the table of releases in the README.
+awk -v rows="$rows" '
+    /^<!-- releases -->$/ { print; print "| Release | Commit |"; print "| --- | --- |"; printf "%s", rows; skip = 1; next }
+    /^<!-- \/releases -->$/ { skip = 0 }
+    !skip { print }
+' README.md > README.md.tmp
+mv README.md.tmp README.md